@@ -0,0 +1,121 @@
+//! Пакетный расчет вариантов загрузки одного судна
+use crate::{
+    computer::Computer,
+    data::{
+        parse_input::{
+            ParsedFramesData, ParsedInputData, ParsedLoadsData, ParsedShipData, ParsedTanksData,
+        },
+        serialize_out::{OutData, OutMismatch},
+        source::IDataSource,
+    },
+    error::Error,
+    hydrostatics::MISMATCH_TOLERANCE,
+};
+
+/// Данные по корпусу судна, общие для всех вариантов загрузки
+struct Hull {
+    /// название проекта судна
+    project_name: String,
+    /// имя судна
+    ship_name: String,
+    /// данные по корпусу судна
+    ship: ParsedShipData,
+    /// шпангоуты судна
+    frames: ParsedFramesData,
+    /// расхождения кривых элементов теоретического чертежа
+    /// с вычисленными по шпангоутам
+    hydrostatics_mismatch: Vec<OutMismatch>,
+}
+
+/// Пакетный расчет вариантов загрузки одного судна.  
+/// Данные по корпусу и шпангоутам загружаются из источника
+/// один раз при расчете первого варианта загрузки, грузы и цистерны
+/// берутся из варианта загрузки, а если не заданы - из источника.
+/// Сверка элементов теоретического чертежа выполняется один раз при
+/// загрузке данных по корпусу и добавляется к результату каждого варианта.
+pub struct Batch {
+    /// ускорение свободного падения
+    gravity_g: f64,
+    /// источник данных по судну
+    source: Box<dyn IDataSource>,
+    /// данные по корпусу судна
+    hull: Option<Hull>,
+    /// твердые грузы судна из источника
+    loads: Option<ParsedLoadsData>,
+    /// цистерны судна из источника
+    tanks: Option<ParsedTanksData>,
+}
+///
+impl Batch {
+    /// Основной конструктор. Аргументы:
+    /// - gravity_g: ускорение свободного падения
+    /// - source: источник данных по судну
+    pub fn new(gravity_g: f64, source: Box<dyn IDataSource>) -> Self {
+        Self {
+            gravity_g,
+            source,
+            hull: None,
+            loads: None,
+            tanks: None,
+        }
+    }
+    /// Расчет варианта загрузки
+    /// - input: данные варианта загрузки
+    pub fn compute(&mut self, input: ParsedInputData) -> Result<OutData, Error> {
+        let (project_name, ship_name) = (input.project_name.as_str(), input.ship_name.as_str());
+        let hull = match self.hull.take() {
+            Some(hull) => hull,
+            None => {
+                let ship = self.source.ship(project_name, ship_name)?;
+                let frames = self.source.frames(project_name, ship_name)?;
+                // ошибка сверки не мешает расчету вариантов загрузки
+                let hydrostatics_mismatch = Computer::hydrostatics_mismatch(&ship, &frames, MISMATCH_TOLERANCE)
+                    .unwrap_or_else(|err| {
+                        log::warn!("Batch.compute | hydrostatics_mismatch: {err}");
+                        Vec::new()
+                    });
+                Hull {
+                    project_name: project_name.to_owned(),
+                    ship_name: ship_name.to_owned(),
+                    ship,
+                    frames,
+                    hydrostatics_mismatch,
+                }
+            }
+        };
+        let hull = self.hull.insert(hull);
+        if hull.project_name != project_name || hull.ship_name != ship_name {
+            return Err(Error::DataSource(format!(
+                "condition references ship {project_name:?} {ship_name:?}, expected {:?} {:?}",
+                hull.project_name, hull.ship_name,
+            )));
+        }
+        let loads = match (&input.load_space, &input.grain, &input.bulk) {
+            (None, None, None) => match &self.loads {
+                Some(loads) => loads.clone(),
+                None => self.loads.insert(self.source.loads(project_name, ship_name)?).clone(),
+            },
+            (load_space, grain, bulk) => ParsedLoadsData {
+                load_space: load_space.clone().unwrap_or_default(),
+                grain: grain.clone().unwrap_or_default(),
+                bulk: bulk.clone().unwrap_or_default(),
+            }
+            .check()
+            .map_err(|err| Error::DataSource(format!("ParsedLoadsData::check: {err}")))?,
+        };
+        let tanks = match &input.tanks {
+            Some(tanks) => ParsedTanksData { tanks: tanks.clone() }
+                .check()
+                .map_err(|err| Error::DataSource(format!("ParsedTanksData::check: {err}")))?,
+            None => match &self.tanks {
+                Some(tanks) => tanks.clone(),
+                None => self.tanks.insert(self.source.tanks(project_name, ship_name)?).clone(),
+            },
+        };
+        let (ship, frames) = (hull.ship.clone(), hull.frames.clone());
+        Ok(OutData {
+            hydrostatics_mismatch: hull.hydrostatics_mismatch.clone(),
+            ..Computer::new(self.gravity_g, input, ship, frames, loads, tanks).out_data()?
+        })
+    }
+}
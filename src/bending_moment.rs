@@ -1,5 +1,5 @@
 //! Изгибающий момент
-use crate::{math::vec::integral_sum::IntegralSum, shear_force::IShearForce};
+use crate::{error::Error, math::vec::integral_sum::IntegralSum, shear_force::IShearForce};
 
 /// Изгибающий момент, интегриральная сумма срезающей  
 /// силы, $M_i = M_{i-1} + SF_{i-1} + SF_i, M_0 = 0$
@@ -14,9 +14,9 @@ impl<'a> BendingMoment<'a> {
         Self { shear_force }
     }
     ///
-    pub fn values(&self) -> Vec<f64>  {
-        let result = self.shear_force.values().integral_sum();
+    pub fn values(&self) -> Result<Vec<f64>, Error>  {
+        let result = self.shear_force.values()?.integral_sum();
         log::debug!("\t BendingMoment result:{:?}", result);
-        result
+        Ok(result)
     }
 }
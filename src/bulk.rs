@@ -0,0 +1,75 @@
+//! Груз - навалочный груз в трюме
+use crate::{
+    error::Error,
+    hold::Hold,
+    load::ILoad,
+    math::{
+        bound::Bound, curve::{Curve, ICurve},
+        pos_shift::{IPosShift, PosShift}, position::Position,
+    },
+};
+
+/// Груз - навалочный груз (руда, уголь, зерно) в трюме, реализует интерфейс ILoad.
+/// Задается массой и удельным погрузочным объемом, объем груза $V = m \cdot SF$.
+/// Центр масс и уровень поверхности груза берутся по кривым трюма от объема груза,
+/// вместимость трюма - наибольший объем кривой уровня, см. [Hold].
+pub struct Bulk {
+    /// масса груза
+    mass: f64,
+    /// груз в трюме
+    hold: Hold,
+    /// уровень поверхности груза
+    level: f64,
+}
+///
+impl Bulk {
+    /// Основной конструктор. Аргументы:
+    /// - mass: масса груза
+    /// - stowage_factor: удельный погрузочный объем, м^3/т
+    /// - bound: границы трюма
+    /// - center: кривая координат центра масс груза по объему груза
+    /// - level: кривая уровня поверхности груза по объему груза [(объем, уровень)]
+    pub fn new(
+        mass: f64,
+        stowage_factor: f64,
+        bound: Bound,
+        center: PosShift,
+        level: Vec<(f64, f64)>,
+    ) -> Result<Self, Error> {
+        if mass < 0. {
+            return Err(Error::Bulk(format!("mass {mass} must be greater or equal to 0")));
+        }
+        if stowage_factor <= 0. {
+            return Err(Error::Bulk(format!("stowage_factor {stowage_factor} must be greater than 0")));
+        }
+        if level.len() <= 1 {
+            return Err(Error::Bulk(format!("level.len() {} must be greater or equal to 2", level.len())));
+        }
+        let capacity = level.iter().map(|v| v.0).fold(f64::MIN, f64::max);
+        let volume = mass * stowage_factor;
+        let hold = Hold::new(volume, capacity, bound, center.value(volume))?;
+        Ok(Self { mass, hold, level: Curve::new(level)?.value(volume) })
+    }
+    /// Объем груза в трюме
+    pub fn volume(&self) -> f64 {
+        self.hold.volume()
+    }
+    /// Уровень поверхности груза
+    pub fn level(&self) -> f64 {
+        self.level
+    }
+    /// Трюм заполнен
+    pub fn filled(&self) -> bool {
+        self.hold.filled()
+    }
+}
+///
+impl ILoad for Bulk {
+    fn mass(&self, bound: Option<Bound>) -> f64 {
+        self.hold.mass(self.mass, bound)
+    }
+
+    fn center(&self) -> Position {
+        self.hold.center()
+    }
+}
@@ -0,0 +1,139 @@
+//! Калибровочная таблица цистерны
+use crate::{data::parse_input::CurveFamily, error::Error, math::curve::{Curve, ICurve}};
+
+/// Заполнение цистерны, в том виде, в котором его измеряет экипаж
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fill {
+    /// уровень жидкости (высота наполнения) при дифференте, м, и крене, градусы
+    Sounding { value: f64, trim: f64, heel: f64 },
+    /// пустота (расстояние от верха цистерны до уровня жидкости)
+    /// при дифференте, м, и крене, градусы
+    Ullage { value: f64, trim: f64, heel: f64 },
+    /// объем жидкости
+    Volume(f64),
+    /// заполнение в процентах от вместимости цистерны
+    Percent(f64),
+    /// масса жидкости
+    Mass(f64),
+}
+/// Таблица поправок к уровню жидкости по дифференту или крену
+struct Correction {
+    /// кривые поправки по уровню жидкости для значений дифферента или крена
+    rows: Vec<(f64, Curve)>,
+}
+///
+impl Correction {
+    /// Основной конструктор. Аргументы:
+    /// - rows: кривые поправки по уровню жидкости (дифферент или крен, [(уровень, поправка)])
+    fn new(mut rows: CurveFamily) -> Result<Self, Error> {
+        if rows.is_empty() {
+            return Err(Error::Calibration("correction table must not be empty".to_owned()));
+        }
+        rows.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(Self {
+            rows: rows
+                .into_iter()
+                .map(|(key, curve)| Ok((key, Curve::new(curve)?)))
+                .collect::<Result<_, Error>>()?,
+        })
+    }
+    /// Поправка к уровню жидкости, линейная интерполяция между кривыми,
+    /// за пределами таблицы - по крайней кривой
+    /// - key: дифферент или крен
+    /// - sounding: уровень жидкости
+    fn value(&self, key: f64, sounding: f64) -> f64 {
+        let (first, last) = (&self.rows[0], &self.rows[self.rows.len() - 1]);
+        if key <= first.0 {
+            return first.1.value(sounding);
+        }
+        if key >= last.0 {
+            return last.1.value(sounding);
+        }
+        let i = self.rows.iter().position(|v| v.0 >= key).unwrap_or(self.rows.len() - 1);
+        let ((k0, c0), (k1, c1)) = (&self.rows[i - 1], &self.rows[i]);
+        let (v0, v1) = (c0.value(sounding), c1.value(sounding));
+        v0 + (v1 - v0) * (key - k0) / (k1 - k0)
+    }
+}
+/// Калибровочная таблица цистерны: объем жидкости по уровню с поправками
+/// уровня на дифферент и крен судна. Уровень по пустоте: $h = H - u$.
+pub struct Calibration {
+    /// высота цистерны, от которой отсчитывается пустота
+    height: f64,
+    /// кривая объема жидкости по уровню
+    volume: Curve,
+    /// наибольший уровень таблицы
+    sounding_max: f64,
+    /// вместимость цистерны, наибольший объем таблицы
+    capacity: f64,
+    /// поправки уровня на дифферент
+    trim: Option<Correction>,
+    /// поправки уровня на крен
+    heel: Option<Correction>,
+}
+///
+impl Calibration {
+    /// Основной конструктор. Аргументы:
+    /// - height: высота цистерны, от которой отсчитывается пустота
+    /// - volume: объем жидкости по уровню [(уровень, объем)]
+    /// - trim: поправки уровня на дифферент [(дифферент, [(уровень, поправка)])], необязательно
+    /// - heel: поправки уровня на крен [(крен, [(уровень, поправка)])], необязательно
+    pub fn new(
+        height: f64,
+        volume: Vec<(f64, f64)>,
+        trim: Option<CurveFamily>,
+        heel: Option<CurveFamily>,
+    ) -> Result<Self, Error> {
+        if height <= 0. {
+            return Err(Error::Calibration(format!("height {height} must be greater than 0")));
+        }
+        if volume.windows(2).any(|v| v[1].0 <= v[0].0 || v[1].1 < v[0].1) {
+            return Err(Error::Calibration(format!("volume {:?} must be ascending by sounding", volume)));
+        }
+        let sounding_max = volume.iter().map(|v| v.0).fold(f64::MIN, f64::max);
+        let capacity = volume.iter().map(|v| v.1).fold(f64::MIN, f64::max);
+        Ok(Self {
+            height,
+            volume: Curve::new(volume)?,
+            sounding_max,
+            capacity,
+            trim: trim.map(Correction::new).transpose()?,
+            heel: heel.map(Correction::new).transpose()?,
+        })
+    }
+    /// Вместимость цистерны
+    pub fn capacity(&self) -> f64 {
+        self.capacity
+    }
+    /// Объем жидкости по уровню с поправками на дифферент и крен
+    fn sounding_volume(&self, sounding: f64, trim: f64, heel: f64) -> Result<f64, Error> {
+        let corrected = sounding
+            + self.trim.as_ref().map(|t| t.value(trim, sounding)).unwrap_or(0.)
+            + self.heel.as_ref().map(|t| t.value(heel, sounding)).unwrap_or(0.);
+        if corrected < 0. || corrected > self.sounding_max {
+            return Err(Error::Calibration(format!(
+                "corrected sounding {corrected} must be in range 0..={}", self.sounding_max
+            )));
+        }
+        Ok(self.volume.value(corrected))
+    }
+    /// Объем жидкости по заполнению цистерны
+    /// - fill: заполнение цистерны
+    /// - density: плотность жидкости
+    pub fn value(&self, fill: Fill, density: f64) -> Result<f64, Error> {
+        let result = match fill {
+            Fill::Sounding { value, trim, heel } => self.sounding_volume(value, trim, heel)?,
+            Fill::Ullage { value, trim, heel } => self.sounding_volume(self.height - value, trim, heel)?,
+            Fill::Volume(value) => value,
+            Fill::Percent(value) => self.capacity * value / 100.,
+            Fill::Mass(value) => value / density,
+        };
+        if result > self.capacity {
+            return Err(Error::Calibration(format!(
+                "volume {result} of {:?} exceeds tank capacity {}", fill, self.capacity
+            )));
+        }
+        log::debug!("\t Calibration value result:{:?}", result);
+        Ok(result)
+    }
+}
@@ -0,0 +1,1071 @@
+//! Сборка расчета из входных данных
+use std::{cell::OnceCell, rc::Rc};
+
+use crate::{
+    bending_moment::BendingMoment,
+    calibration::{Calibration, Fill},
+    bulk::Bulk,
+    data::{
+        parse_input::{
+            Condition, FillKind, FreeSurfaceKind, Icing, LoadCategory, ParsedFramesData, ParsedInputData, ParsedLoadsData, ParsedShipData,
+            IceAreaData, ParsedTanksData, TankData,
+        },
+        serialize_out::{OutBulk, OutCriteria, OutCriterion, OutDamage, OutData, OutEquilibrium, OutGrain, OutIce, OutMismatch, OutRightingLever, OutStability, OutStack, OutStowage, OutVoyage, OutVoyageCondition, OutSummary, OutCategory, OutLoad, OutUtilisation, OutWave, OutWeather},
+    },
+    criteria::Criteria,
+    cross_curves::CrossCurves,
+    displacement::Displacement,
+    equilibrium::{Convergence, Equilibrium, FloatingPosition},
+    flooding::{Compartment, Flooding},
+    error::Error,
+    frame::Frame,
+    grain::Grain,
+    grain_heel::GrainHeel,
+    heel::Heel,
+    hydrostatics::Hydrostatics,
+    ice::Ice,
+    lightship::Lightship,
+    load::{Category, FreeSurface, ILoad, LoadSpace, NamedLoad},
+    mass::{IMass, Mass},
+    metacentric_height::MetacentricHeight,
+    righting_lever::RightingLever,
+    math::{
+        bound::Bound, curve::{Curve, ICurve}, inertia_shift::inertia_shift::InertiaShift, mass_moment::MassMoment,
+        pos_shift::{IPosShift, PosShift}, position::Position, surface_moment::SurfaceMoment,
+    },
+    shear_force::{IShearForce, ShearForce},
+    stowage::Stowage,
+    summary::Summary,
+    tank::Tank,
+    total_force::TotalForce,
+    trim::Trim,
+    utilisation::Utilisation,
+    voyage::Voyage,
+    wave::WaveLoad,
+    weather::{HullForm, Roll, Weather, WeatherCriterion},
+};
+
+/// Расчет прочности корпуса судна. Собирает классы расчета
+/// из разобранных данных запроса, корпуса, шпангоутов и грузов.
+/// Грузы и нагрузка на корпус собираются один раз, при первом обращении.
+pub struct Computer {
+    /// ускорение свободного падения
+    gravity_g: f64,
+    /// данные запроса на расчет
+    input: ParsedInputData,
+    /// данные по корпусу судна
+    ship: Rc<ParsedShipData>,
+    /// шпангоуты судна
+    frames: Rc<ParsedFramesData>,
+    /// твердые грузы судна
+    loads: Rc<ParsedLoadsData>,
+    /// цистерны судна
+    tanks: ParsedTanksData,
+    /// все грузы судна кроме цистерн
+    fixed_loads: OnceCell<Vec<Rc<Box<dyn ILoad>>>>,
+    /// все грузы судна
+    all_loads: OnceCell<Vec<Rc<Box<dyn ILoad>>>>,
+    /// нагрузка на корпус судна
+    total_mass: OnceCell<Rc<dyn IMass>>,
+}
+///
+impl Computer {
+    /// Основной конструктор. Аргументы:
+    /// - gravity_g: ускорение свободного падения
+    /// - input: данные запроса на расчет
+    /// - ship: данные по корпусу судна
+    /// - frames: шпангоуты судна
+    /// - loads: твердые грузы судна
+    /// - tanks: цистерны судна
+    pub fn new(
+        gravity_g: f64,
+        input: ParsedInputData,
+        ship: ParsedShipData,
+        frames: ParsedFramesData,
+        loads: ParsedLoadsData,
+        tanks: ParsedTanksData,
+    ) -> Self {
+        Self {
+            gravity_g,
+            input,
+            ship: Rc::new(ship),
+            frames: Rc::new(frames),
+            loads: Rc::new(loads),
+            tanks,
+            fixed_loads: OnceCell::new(),
+            all_loads: OnceCell::new(),
+            total_mass: OnceCell::new(),
+        }
+    }
+    /// Расчет того же судна с теми же твердыми грузами и другим
+    /// заполнением цистерн, твердые грузы не собираются повторно
+    /// - input: данные запроса на расчет
+    /// - tanks: цистерны судна
+    fn with_tanks(&self, input: ParsedInputData, tanks: ParsedTanksData) -> Result<Self, Error> {
+        self.fixed_loads()?;
+        Ok(Self {
+            gravity_g: self.gravity_g,
+            input,
+            ship: Rc::clone(&self.ship),
+            frames: Rc::clone(&self.frames),
+            loads: Rc::clone(&self.loads),
+            tanks,
+            fixed_loads: self.fixed_loads.clone(),
+            all_loads: OnceCell::new(),
+            total_mass: OnceCell::new(),
+        })
+    }
+    /// Значение из ячейки, при первом обращении вычисляется
+    /// - cell: ячейка
+    /// - init: вычисление значения
+    fn cached<T: Clone>(cell: &OnceCell<T>, init: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+        if let Some(value) = cell.get() {
+            return Ok(value.clone());
+        }
+        let value = init()?;
+        Ok(cell.get_or_init(|| value).clone())
+    }
+    /// Вектор разбиения судна на отрезки от кормы до носа
+    pub fn bounds(&self) -> Result<Vec<Bound>, Error> {
+        let n = self.input.n_parts;
+        let delta_x = self.ship.ship_length / n as f64;
+        let start_x = -self.ship.ship_length / 2.;
+        (0..n as usize)
+            .map(|v| {
+                Bound::new(
+                    start_x + delta_x * v as f64,
+                    start_x + delta_x * (v as f64 + 1.),
+                )
+            })
+            .collect()
+    }
+    /// Груз с наименованием и категорией
+    /// - name: наименование груза, если не задано - default_name
+    /// - category: категория груза, если не задана - default_category
+    fn named(
+        name: &Option<String>,
+        default_name: String,
+        category: Option<LoadCategory>,
+        default_category: Category,
+        load: impl ILoad + 'static,
+    ) -> Rc<Box<dyn ILoad>> {
+        let category = match category {
+            Some(LoadCategory::Cargo) => Category::Cargo,
+            Some(LoadCategory::Ballast) => Category::Ballast,
+            Some(LoadCategory::Fuel) => Category::Fuel,
+            Some(LoadCategory::LubricatingOil) => Category::LubricatingOil,
+            Some(LoadCategory::FreshWater) => Category::FreshWater,
+            Some(LoadCategory::Stores) => Category::Stores,
+            Some(LoadCategory::Crew) => Category::Crew,
+            Some(LoadCategory::Other) => Category::Other,
+            None => default_category,
+        };
+        Rc::new(Box::new(NamedLoad::new(
+            name.clone().unwrap_or(default_name),
+            category,
+            Box::new(load),
+        )))
+    }
+    /// Все грузы судна: твердые грузы и цистерны. Грузы без наименования
+    /// получают наименование по виду груза и порядковому номеру, категория
+    /// по умолчанию - груз, для цистерн - прочее
+    pub fn loads(&self) -> Result<Vec<Rc<Box<dyn ILoad>>>, Error> {
+        Self::cached(&self.all_loads, || {
+            let mut loads = self.fixed_loads()?;
+            for (i, (tank, data)) in self.tanks()?.into_iter().zip(self.tanks.tanks.iter()).enumerate() {
+                loads.push(Self::named(&data.name, format!("tank {}", i + 1), data.category, Category::Other, tank));
+            }
+            Ok(loads)
+        })
+    }
+    /// Твердые грузы судна, все грузы кроме цистерн
+    fn fixed_loads(&self) -> Result<Vec<Rc<Box<dyn ILoad>>>, Error> {
+        Self::cached(&self.fixed_loads, || self.build_fixed_loads())
+    }
+    /// Сборка твердых грузов судна из данных
+    fn build_fixed_loads(&self) -> Result<Vec<Rc<Box<dyn ILoad>>>, Error> {
+        let mut loads: Vec<Rc<Box<dyn ILoad>>> = Vec::new();
+        if let Some(lightship) = &self.ship.lightship {
+            let ship_length = self.ship.ship_length;
+            loads.push(Self::named(&None, "lightship".to_owned(), None, Category::Lightship, Lightship::new(
+                lightship.mass,
+                Position::new(lightship.center.0, lightship.center.1, lightship.center.2),
+                Bound::new(-ship_length / 2., ship_length / 2.)?,
+                lightship.distribution.clone(),
+            )?));
+        }
+        for (i, space) in self.loads.load_space.iter().enumerate() {
+            loads.push(Self::named(&space.name, format!("load_space {}", i + 1), space.category, Category::Cargo, LoadSpace::new(
+                space.mass,
+                Bound::new(space.bound.0, space.bound.1)?,
+                Position::new(space.center.0, space.center.1, space.center.2),
+            )?));
+        }
+        for (i, grain) in self.loads.grain.iter().enumerate() {
+            loads.push(Self::named(&grain.name, format!("grain {}", i + 1), grain.category, Category::Cargo, Grain::new(
+                grain.stowage_factor,
+                grain.volume,
+                grain.capacity,
+                Bound::new(grain.bound.0, grain.bound.1)?,
+                Position::new(grain.center.0, grain.center.1, grain.center.2),
+                Curve::new(grain.moment.clone())?,
+            )?));
+        }
+        for (i, (bulk, data)) in self.bulk()?.into_iter().zip(self.loads.bulk.iter()).enumerate() {
+            loads.push(Self::named(&data.name, format!("bulk {}", i + 1), data.category, Category::Cargo, bulk));
+        }
+        if let Some(stowage) = self.stowage()? {
+            let containers = self.containers();
+            for (load, (bay, row, tier, _)) in stowage.loads(&containers)?.into_iter().zip(containers) {
+                loads.push(Self::named(&None, format!("container {bay:02}{row:02}{tier:02}"), None, Category::Cargo, load));
+            }
+        }
+        if let Some(ice) = self.ice()? {
+            for (i, load) in ice.deck()?.into_iter().enumerate() {
+                loads.push(Self::named(&None, format!("ice deck {}", i + 1), None, Category::Ice, load));
+            }
+            for (i, load) in ice.windage()?.into_iter().enumerate() {
+                loads.push(Self::named(&None, format!("ice windage {}", i + 1), None, Category::Ice, load));
+            }
+        }
+        Ok(loads)
+    }
+    /// Сводка нагрузки судна по категориям грузов
+    pub fn summary(&self) -> Result<OutSummary, Error> {
+        let loads = self.loads()?;
+        let summary = Summary::new(loads.clone());
+        Ok(OutSummary {
+            loads: loads
+                .iter()
+                .map(|load| {
+                    let center = load.center();
+                    OutLoad {
+                        name: load.name(),
+                        category: load.category().name().to_owned(),
+                        mass: load.mass(None),
+                        center: (center.x(), center.y(), center.z()),
+                    }
+                })
+                .collect(),
+            categories: summary
+                .categories()
+                .into_iter()
+                .map(|total| OutCategory {
+                    category: total.category.name().to_owned(),
+                    mass: total.mass,
+                    center: (total.center.x(), total.center.y(), total.center.z()),
+                    moment_surface: (total.moment_surface.x(), total.moment_surface.y()),
+                })
+                .collect(),
+            deadweight: summary.deadweight(),
+            displacement: summary.displacement(),
+        })
+    }
+    /// Цистерны судна, заполнение цистерн приведено к объему жидкости
+    pub fn tanks(&self) -> Result<Vec<Tank>, Error> {
+        self.tanks
+            .tanks
+            .iter()
+            .map(|tank| {
+                let fill = match &tank.fill {
+                    Some(fill) => match fill.kind {
+                        FillKind::Sounding => Fill::Sounding { value: fill.value, trim: fill.trim, heel: fill.heel },
+                        FillKind::Ullage => Fill::Ullage { value: fill.value, trim: fill.trim, heel: fill.heel },
+                        FillKind::Volume => Fill::Volume(fill.value),
+                        FillKind::Percent => Fill::Percent(fill.value),
+                        FillKind::Mass => Fill::Mass(fill.value),
+                    },
+                    None => match tank.volume {
+                        Some(volume) => Fill::Volume(volume),
+                        None => return Err(Error::Tank("volume or fill of tank must be given".to_owned())),
+                    },
+                };
+                let calibration = match &tank.calibration {
+                    Some(calibration) => Some(Calibration::new(
+                        calibration.height,
+                        calibration.volume.clone(),
+                        calibration.trim.clone(),
+                        calibration.heel.clone(),
+                    )?),
+                    None => None,
+                };
+                let free_surface = match &tank.free_surface {
+                    Some(free_surface) => match free_surface.kind {
+                        FreeSurfaceKind::Actual => FreeSurface::Actual,
+                        FreeSurfaceKind::Max => FreeSurface::Max,
+                        FreeSurfaceKind::Fixed => {
+                            let (x, y) = free_surface.moment.unwrap_or((0., 0.));
+                            FreeSurface::Fixed(SurfaceMoment::new(x, y))
+                        }
+                        FreeSurfaceKind::None => FreeSurface::None,
+                    },
+                    None => FreeSurface::Actual,
+                };
+                Tank::from_fill(
+                    tank.density,
+                    fill,
+                    calibration,
+                    Bound::new(tank.bound.0, tank.bound.1)?,
+                    PosShift::new(
+                        Curve::new(tank.center.iter().map(|v| (v.0, v.1)).collect())?,
+                        Curve::new(tank.center.iter().map(|v| (v.0, v.2)).collect())?,
+                        Curve::new(tank.center.iter().map(|v| (v.0, v.3)).collect())?,
+                    ),
+                    InertiaShift::new(
+                        Curve::new(tank.free_surf_inertia.iter().map(|v| (v.0, v.1)).collect())?,
+                        Curve::new(tank.free_surf_inertia.iter().map(|v| (v.0, v.2)).collect())?,
+                    ),
+                    free_surface,
+                )
+            })
+            .collect()
+    }
+    /// Нагрузка на корпус судна, распределенная по вектору разбиения
+    pub fn mass(&self) -> Result<Rc<dyn IMass>, Error> {
+        Self::cached(&self.total_mass, || {
+            let mass: Rc<dyn IMass> = Rc::new(Mass::new(self.loads()?, self.bounds()?)?);
+            Ok(mass)
+        })
+    }
+    /// Шпангоуты судна, упорядоченные по порядковому номеру от кормы
+    pub fn frames(&self) -> Result<Vec<Frame>, Error> {
+        Self::hull_frames(&self.frames)
+    }
+    /// Шпангоуты судна по данным, упорядоченные по порядковому номеру от кормы
+    fn hull_frames(frames: &ParsedFramesData) -> Result<Vec<Frame>, Error> {
+        let mut frames = frames.frames.clone();
+        frames.sort_by_key(|f| f.index);
+        frames
+            .into_iter()
+            .map(|f| Ok(Frame::new(Curve::new(f.immersion_area)?)))
+            .collect()
+    }
+    /// Отстояние центра величины погруженной части судна
+    pub fn center_draught_shift(&self) -> Result<PosShift, Error> {
+        Ok(PosShift::new(
+            Curve::new(self.ship.center_shift.iter().map(|v| (v.0, v.1)).collect())?,
+            Curve::new(self.ship.center_shift.iter().map(|v| (v.0, v.2)).collect())?,
+            Curve::new(self.ship.center_shift.iter().map(|v| (v.0, v.3)).collect())?,
+        ))
+    }
+    /// Поперечная метацентрическая высота, None если для судна
+    /// не задана кривая поперечного метацентрического радиуса
+    pub fn metacentric_height(&self) -> Result<Option<MetacentricHeight>, Error> {
+        let Some(rad_trans) = &self.ship.rad_trans else {
+            return Ok(None);
+        };
+        Ok(Some(MetacentricHeight::new(
+            self.input.water_density,
+            self.center_draught_shift()?,
+            Curve::new(rad_trans.clone())?,
+            self.mass()?,
+        )?))
+    }
+    /// Поперечная остойчивость судна, None если для судна
+    /// не задана кривая поперечного метацентрического радиуса
+    pub fn stability(&self) -> Result<Option<OutStability>, Error> {
+        let Some(metacentric_height) = self.metacentric_height()? else {
+            return Ok(None);
+        };
+        let h_0 = metacentric_height.h_0()?;
+        let h = metacentric_height.h()?;
+        Ok(Some(OutStability {
+            z_m: metacentric_height.z_m()?,
+            z_g: self.mass()?.shift().z(),
+            h_0,
+            delta_m_h: h_0 - h,
+            h,
+        }))
+    }
+    /// Пантокарены, None если для судна они не заданы
+    pub fn cross_curves(&self) -> Result<Option<CrossCurves>, Error> {
+        self.ship
+            .cross_curves
+            .as_ref()
+            .map(|cross_curves| CrossCurves::new(cross_curves.clone()))
+            .transpose()
+    }
+    /// Диаграмма статической остойчивости, None если для судна не заданы пантокарены
+    pub fn righting_lever(&self) -> Result<Option<RightingLever>, Error> {
+        let Some(cross_curves) = self.cross_curves()? else {
+            return Ok(None);
+        };
+        Ok(Some(RightingLever::new(self.input.water_density, cross_curves, self.mass()?)?))
+    }
+    /// Диаграммы статической и динамической остойчивости,
+    /// None если для судна не заданы пантокарены
+    pub fn stability_diagram(&self) -> Result<Option<OutRightingLever>, Error> {
+        let Some(righting_lever) = self.righting_lever()? else {
+            return Ok(None);
+        };
+        let angles = match &self.input.heel_angles {
+            Some(angles) => angles.clone(),
+            None => (0..)
+                .map(|i| i as f64 * 10.)
+                .take_while(|a| *a <= righting_lever.angle_max())
+                .collect(),
+        };
+        Ok(Some(OutRightingLever {
+            values: righting_lever.values(&angles),
+            dynamic: righting_lever.dynamic(&angles),
+            max: righting_lever.max(),
+            angle_vanishing: righting_lever.angle_vanishing(),
+        }))
+    }
+    /// Исправленная начальная метацентрическая высота по кривой поперечного
+    /// метацентрического радиуса, если она задана, иначе по наклону диаграммы
+    /// - righting_lever: диаграмма статической остойчивости
+    fn initial_metacentric_height(&self, righting_lever: &RightingLever) -> Result<f64, Error> {
+        Ok(match self.metacentric_height()? {
+            Some(metacentric_height) => metacentric_height.h()?,
+            None => righting_lever.metacentric_height(),
+        })
+    }
+    /// Критерий погоды, None если для судна не заданы пантокарены
+    /// или данные для проверки критерия погоды. При неположительной
+    /// метацентрической высоте качка не вычисляется, критерий не выполнен
+    pub fn weather(&self) -> Result<Option<WeatherCriterion>, Error> {
+        let (Some(data), Some(righting_lever)) = (&self.ship.weather, self.righting_lever()?) else {
+            return Ok(None);
+        };
+        let mass = self.mass()?;
+        let draught = Curve::new(self.ship.mean_draught.clone())?.value(mass.sum() / self.input.water_density);
+        let windage_area = Curve::new(data.windage.iter().map(|v| (v.0, v.1)).collect())?.value(draught);
+        let windage_lever = Curve::new(data.windage.iter().map(|v| (v.0, v.2)).collect())?.value(draught);
+        let metacentric_height = self.initial_metacentric_height(&righting_lever)?;
+        if metacentric_height <= 0. {
+            log::warn!("Computer.weather | metacentric_height {metacentric_height} is not positive, criterion fails");
+            return Ok(Some(WeatherCriterion::Unstable));
+        }
+        let hull = HullForm {
+            ship_length: self.ship.ship_length,
+            breadth: data.breadth,
+            block_coefficient: data.block_coefficient,
+            bilge_keel_area: data.bilge_keel_area,
+            hard_chine: data.hard_chine,
+        };
+        let roll = Roll::new(hull, draught, mass.shift().z(), metacentric_height)?;
+        Ok(Some(WeatherCriterion::Computed(Weather::new(
+            righting_lever,
+            windage_area,
+            windage_lever,
+            mass.sum(),
+            roll.angle()?,
+            self.ship.flooding_angle,
+        )?)))
+    }
+    /// Критерий погоды для вывода, None если он не проверяется
+    /// или не вычисляется из-за неположительной метацентрической высоты
+    /// - weather: критерий погоды, см. [Computer::weather]
+    pub fn out_weather(&self, weather: Option<&WeatherCriterion>) -> Option<OutWeather> {
+        let Some(WeatherCriterion::Computed(weather)) = weather else {
+            return None;
+        };
+        Some(OutWeather {
+            lever_1: weather.lever_1(),
+            lever_2: weather.lever_2(),
+            angle_0: weather.angle_0(),
+            angle_1: weather.roll_angle(),
+            angle_2: weather.angle_2(),
+            area_a: weather.area_a(),
+            area_b: weather.area_b(),
+        })
+    }
+    /// Промежуточные состояния судна на переходе, None если в запросе
+    /// не задан расход запасов. Для каждого состояния выполняется полный
+    /// расчет с объемами жидкости в расходных цистернах на момент состояния.
+    /// Наихудшее состояние - с наибольшим использованием допустимых значений,
+    /// если они заданы, иначе с наибольшим по модулю изгибающим моментом
+    pub fn voyage(&self) -> Result<Option<OutVoyage>, Error> {
+        let Some(data) = &self.input.voyage else {
+            return Ok(None);
+        };
+        let voyage = Voyage::new(
+            data.duration,
+            data.step,
+            data.levels.clone(),
+            data.consumption.iter().map(|c| (c.tank, c.rate)).collect(),
+        )?;
+        let tanks: Vec<(f64, f64, f64)> = self
+            .tanks()?
+            .iter()
+            .zip(self.tanks.tanks.iter())
+            .map(|(tank, data)| (tank.volume(), data.density, tank.capacity()))
+            .collect();
+        let mut conditions = Vec::new();
+        for time in voyage.times(&tanks)? {
+            let volumes = voyage.volumes(&tanks, time)?;
+            let consumed = tanks.iter().zip(volumes.iter()).map(|(t, v)| (t.0 - v) * t.1).sum();
+            let tanks = ParsedTanksData {
+                tanks: self
+                    .tanks
+                    .tanks
+                    .iter()
+                    .zip(volumes)
+                    .map(|(tank, volume)| TankData { volume: Some(volume), fill: None, ..tank.clone() })
+                    .collect(),
+            };
+            let input = ParsedInputData { voyage: None, ..self.input.clone() };
+            let data = self.with_tanks(input, tanks)?.out_data()?;
+            conditions.push(OutVoyageCondition { time, consumed, data });
+        }
+        let severity = |data: &OutData| match &data.utilisation {
+            Some(utilisation) => utilisation.shear_force_max.1.max(utilisation.bending_moment_max.1),
+            None => data.bending_moment.iter().map(|v| v.1.abs()).fold(0., f64::max),
+        };
+        let worst = conditions
+            .iter()
+            .enumerate()
+            .fold((0, f64::MIN), |worst, (i, c)| {
+                let value = severity(&c.data);
+                if value > worst.1 { (i, value) } else { worst }
+            })
+            .0;
+        Ok(Some(OutVoyage { conditions, worst }))
+    }
+    /// Навалочные грузы в трюмах
+    pub fn bulk(&self) -> Result<Vec<Bulk>, Error> {
+        self.loads
+            .bulk
+            .iter()
+            .map(|bulk| {
+                Bulk::new(
+                    bulk.mass,
+                    bulk.stowage_factor(),
+                    Bound::new(bulk.bound.0, bulk.bound.1)?,
+                    PosShift::new(
+                        Curve::new(bulk.center.iter().map(|v| (v.0, v.1)).collect())?,
+                        Curve::new(bulk.center.iter().map(|v| (v.0, v.2)).collect())?,
+                        Curve::new(bulk.center.iter().map(|v| (v.0, v.3)).collect())?,
+                    ),
+                    bulk.level.clone(),
+                )
+            })
+            .collect()
+    }
+    /// Навалочные грузы в трюмах для вывода
+    pub fn out_bulk(&self) -> Result<Vec<OutBulk>, Error> {
+        Ok(self
+            .bulk()?
+            .iter()
+            .map(|bulk| {
+                let center = bulk.center();
+                OutBulk {
+                    volume: bulk.volume(),
+                    level: bulk.level(),
+                    center: (center.x(), center.y(), center.z()),
+                    filled: bulk.filled(),
+                }
+            })
+            .collect())
+    }
+    /// Контейнеры варианта загрузки (бей, ряд, ярус, масса)
+    fn containers(&self) -> Vec<(usize, usize, usize, f64)> {
+        self.input
+            .containers
+            .iter()
+            .flatten()
+            .map(|c| (c.bay, c.row, c.tier, c.mass))
+            .collect()
+    }
+    /// Грузовой план контейнеров, None если в варианте загрузки
+    /// нет контейнеров, ошибка если для судна он не задан
+    pub fn stowage(&self) -> Result<Option<Stowage>, Error> {
+        if self.input.containers.iter().flatten().next().is_none() {
+            return Ok(None);
+        }
+        let Some(stowage) = &self.ship.stowage else {
+            return Err(Error::Stowage("containers require stowage data of the ship".to_owned()));
+        };
+        Ok(Some(Stowage::new(
+            stowage.bays.clone(),
+            stowage.rows.clone(),
+            stowage.tiers.clone(),
+            stowage.stack_limits.clone(),
+        )?))
+    }
+    /// Обледенение судна, None если в варианте загрузки обледенение
+    /// не задано, ошибка если для судна не заданы данные по обледенению
+    pub fn ice(&self) -> Result<Option<Ice>, Error> {
+        let Some(icing) = self.input.icing else {
+            return Ok(None);
+        };
+        let Some(ice) = &self.ship.ice else {
+            return Err(Error::Ice("icing requires ice data of the ship".to_owned()));
+        };
+        let areas = |areas: &Vec<IceAreaData>| -> Result<Vec<(f64, Bound, Position)>, Error> {
+            areas
+                .iter()
+                .map(|a| Ok((a.area, Bound::new(a.bound.0, a.bound.1)?, Position::new(a.center.0, a.center.1, a.center.2))))
+                .collect()
+        };
+        let ratio = match icing {
+            Icing::Full => 1.,
+            Icing::Half => 0.5,
+        };
+        Ok(Some(Ice::new(ratio, areas(&ice.deck)?, areas(&ice.windage)?)?))
+    }
+    /// Нагрузка от обледенения для вывода, None если обледенение не задано
+    pub fn out_ice(&self) -> Result<Option<OutIce>, Error> {
+        let Some(ice) = self.ice()? else {
+            return Ok(None);
+        };
+        let (deck, windage) = (ice.deck()?, ice.windage()?);
+        let mass = ice.mass();
+        let center = deck.iter().chain(windage.iter()).map(|l| l.moment_mass()).sum::<MassMoment>().to_pos(mass);
+        Ok(Some(OutIce {
+            deck: deck.iter().map(|l| l.mass(None)).sum(),
+            windage: windage.iter().map(|l| l.mass(None)).sum(),
+            mass,
+            center: (center.x(), center.y(), center.z()),
+        }))
+    }
+    /// Массы штабелей контейнеров для вывода, None если контейнеров нет
+    pub fn out_stowage(&self) -> Result<Option<OutStowage>, Error> {
+        let Some(stowage) = self.stowage()? else {
+            return Ok(None);
+        };
+        let containers = self.containers();
+        let stacks: Vec<OutStack> = stowage
+            .stacks(&containers)
+            .into_iter()
+            .map(|stack| OutStack {
+                bay: stack.bay,
+                row: stack.row,
+                mass: stack.mass,
+                limit: stack.limit,
+                pass: stack.pass(),
+            })
+            .collect();
+        Ok(Some(OutStowage {
+            count: containers.len(),
+            mass: containers.iter().map(|c| c.3).sum(),
+            pass: stacks.iter().all(|s| s.pass),
+            stacks,
+        }))
+    }
+    /// Крен от смещения зерна, None если для судна не заданы
+    /// пантокарены или в трюмах нет зерна
+    pub fn grain_heel(&self) -> Result<Option<GrainHeel>, Error> {
+        if self.loads.grain.is_empty() {
+            return Ok(None);
+        }
+        let Some(righting_lever) = self.righting_lever()? else {
+            return Ok(None);
+        };
+        let moment = self.loads()?.iter().map(|load| load.moment_grain()).sum();
+        Ok(Some(GrainHeel::new(
+            righting_lever,
+            moment,
+            self.mass()?.sum(),
+            self.ship.flooding_angle,
+        )?))
+    }
+    /// Крен от смещения зерна для вывода, None если он не вычисляется
+    pub fn out_grain(&self) -> Result<Option<OutGrain>, Error> {
+        let Some(grain_heel) = self.grain_heel()? else {
+            return Ok(None);
+        };
+        Ok(Some(OutGrain {
+            moment: self.loads()?.iter().map(|load| load.moment_grain()).sum(),
+            lever_0: grain_heel.lever(0.),
+            lever_40: grain_heel.lever(40.),
+            angle: grain_heel.angle(),
+            angle_limit: grain_heel.angle_limit(),
+            area: grain_heel.area(),
+        }))
+    }
+    /// Проверка критериев остойчивости, None если для судна не заданы пантокарены.
+    /// Начальная метацентрическая высота берется по кривой поперечного
+    /// метацентрического радиуса, если она задана, иначе по наклону диаграммы
+    /// - weather: критерий погоды, см. [Computer::weather]
+    pub fn criteria(&self, weather: Option<WeatherCriterion>) -> Result<Option<OutCriteria>, Error> {
+        let Some(righting_lever) = self.righting_lever()? else {
+            return Ok(None);
+        };
+        let metacentric_height = self.initial_metacentric_height(&righting_lever)?;
+        let values: Vec<OutCriterion> = Criteria::new(
+            righting_lever,
+            metacentric_height,
+            self.ship.flooding_angle,
+            weather,
+            self.grain_heel()?,
+        )?
+        .values()
+        .into_iter()
+        .map(|v| OutCriterion {
+            pass: v.pass(),
+            name: v.name,
+            actual: v.actual,
+            required: v.required,
+        })
+        .collect();
+        Ok(Some(OutCriteria {
+            pass: values.iter().all(|v| v.pass),
+            values,
+        }))
+    }
+    /// Крен судна, None если для судна не заданы ни кривая
+    /// поперечного метацентрического радиуса, ни пантокарены
+    pub fn heel(&self) -> Result<Option<Heel>, Error> {
+        let metacentric_height = self.metacentric_height()?;
+        let cross_curves = self.cross_curves()?;
+        if metacentric_height.is_none() && cross_curves.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(Heel::new(
+            self.input.water_density,
+            self.center_draught_shift()?,
+            self.mass()?,
+            metacentric_height,
+            cross_curves,
+        )?))
+    }
+    /// Угол крена судна, градусы. None если крен не задан данными по судну
+    /// или положение равновесия не найдено (например при отрицательной
+    /// метацентрической высоте), что не мешает расчету прочности
+    pub fn heel_angle(&self) -> Result<Option<f64>, Error> {
+        let Some(heel) = self.heel()? else {
+            return Ok(None);
+        };
+        Ok(heel
+            .value()
+            .map_err(|err| log::warn!("Computer.heel_angle | no upright equilibrium: {err}"))
+            .ok())
+    }
+    /// Элементы теоретического чертежа, вычисленные по кривым погруженной
+    /// площади шпангоутов для осадок из ключей этих кривых и между ними
+    /// - ship: данные по корпусу судна
+    /// - frames: шпангоуты судна
+    fn hydrostatics(ship: &ParsedShipData, frames: &ParsedFramesData) -> Result<Hydrostatics, Error> {
+        let mut draughts: Vec<f64> = frames
+            .frames
+            .iter()
+            .flat_map(|f| f.immersion_area.iter().map(|v| v.0))
+            .filter(|v| *v > 0.)
+            .chain(std::iter::once(0.))
+            .collect();
+        draughts.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        draughts.dedup();
+        // промежутки между ключами делятся на равные части
+        let parts = 10;
+        let draughts = draughts
+            .windows(2)
+            .flat_map(|v| (0..parts).map(move |i| v[0] + (v[1] - v[0]) * i as f64 / parts as f64))
+            .chain(draughts.last().copied())
+            .collect();
+        Hydrostatics::new(Self::hull_frames(frames)?, ship.ship_length, draughts)
+    }
+    /// Сверка кривых элементов теоретического чертежа из данных по судну
+    /// с вычисленными по кривым погруженной площади шпангоутов. Возвращает
+    /// точки кривых, в которых относительное расхождение больше tolerance.
+    /// Расхождение по отстояниям по длине отнесено к длинне судна.
+    /// Площадь ватерлинии из данных по судну - производная объема по осадке
+    /// кривой mean_draught, отнесенная к середине каждого ее отрезка.
+    /// Сверка не зависит от варианта загрузки и выполняется один раз
+    /// для судна, см. [crate::batch::Batch]
+    /// - ship: данные по корпусу судна
+    /// - frames: шпангоуты судна
+    /// - tolerance: допустимое относительное расхождение
+    pub fn hydrostatics_mismatch(
+        ship: &ParsedShipData,
+        frames: &ParsedFramesData,
+        tolerance: f64,
+    ) -> Result<Vec<OutMismatch>, Error> {
+        let hydrostatics = Self::hydrostatics(ship, frames)?;
+        let volume_max = hydrostatics.values().last().map(|v| v.volume).unwrap_or(0.);
+        let draught = hydrostatics.draught()?;
+        let ship_length = ship.ship_length;
+        let shift = |v: &(f64, f64, f64, f64)| (v.0, v.1);
+        let center_z = |v: &(f64, f64, f64, f64)| (v.0, v.3);
+        let waterplane_area = ship
+            .mean_draught
+            .windows(2)
+            .filter(|v| v[1].1 > v[0].1)
+            .map(|v| ((v[0].0 + v[1].0) / 2., (v[1].0 - v[0].0) / (v[1].1 - v[0].1)))
+            .collect();
+        // название кривой, кривая из данных по судну, вычисленная кривая, масштаб отстояния
+        type Check<'a> = (&'a str, Vec<(f64, f64)>, Curve, Option<f64>);
+        let checks: [Check; 6] = [
+            ("mean_draught", ship.mean_draught.clone(), draught.clone(), None),
+            ("waterplane_area", waterplane_area, hydrostatics.waterplane_area()?, None),
+            ("center_waterline", ship.center_waterline.clone(), hydrostatics.center_waterline()?, Some(ship_length)),
+            ("rad_long", ship.rad_long.clone(), hydrostatics.rad_long()?, None),
+            ("center_shift.x", ship.center_shift.iter().map(shift).collect(), hydrostatics.center_x()?, Some(ship_length)),
+            ("center_shift.z", ship.center_shift.iter().map(center_z).collect(), hydrostatics.center_z()?, None),
+        ];
+        let mut result = Vec::new();
+        for (name, supplied, derived, scale) in checks {
+            for (volume, supplied) in supplied.into_iter().filter(|v| v.0 > 0. && v.0 <= volume_max) {
+                // кривая осадки задана по объему, остальные - по осадке
+                let derived = match name {
+                    "mean_draught" => derived.value(volume),
+                    _ => derived.value(draught.value(volume)),
+                };
+                let scale = scale.unwrap_or(supplied.abs().max(derived.abs()));
+                if scale > 0. && (derived - supplied).abs() / scale > tolerance {
+                    result.push(OutMismatch { curve: name.to_owned(), volume, supplied, derived });
+                }
+            }
+        }
+        if !result.is_empty() {
+            log::warn!("Computer.hydrostatics_mismatch | {:?}", result);
+        }
+        Ok(result)
+    }
+    /// Посадка судна и распределение массы вытесненной воды по шпациям
+    pub fn equilibrium(&self) -> Result<Equilibrium, Error> {
+        self.equilibrium_displacement(Displacement::new(self.frames()?, self.ship.ship_length)?)
+    }
+    /// Посадка судна с затопленными отсеками
+    /// - flooded: затопленные отсеки
+    pub fn equilibrium_flooded(&self, flooded: Vec<Compartment>) -> Result<Equilibrium, Error> {
+        self.equilibrium_displacement(Displacement::new_flooded(self.frames()?, self.ship.ship_length, flooded)?)
+    }
+    /// Посадка судна для заданного водоизмещения
+    /// - displacement: водоизмещение судна
+    fn equilibrium_displacement(&self, displacement: Displacement) -> Result<Equilibrium, Error> {
+        let ship_length = self.ship.ship_length;
+        let water_density = self.input.water_density;
+        let mass = self.mass()?;
+        Equilibrium::new(
+            self.bounds()?,
+            Rc::clone(&mass),
+            Curve::new(self.ship.center_waterline.clone())?,
+            Curve::new(self.ship.mean_draught.clone())?,
+            displacement,
+            Trim::new(
+                water_density,
+                ship_length,
+                self.center_draught_shift()?,
+                Curve::new(self.ship.rad_long.clone())?,
+                Rc::clone(&mass),
+            )?,
+            self.convergence(),
+        )
+    }
+    /// Условия сходимости расчета посадки судна из запроса,
+    /// незаданные значения берутся по умолчанию
+    fn convergence(&self) -> Convergence {
+        let default = Convergence::default();
+        let data = self.input.convergence.clone().unwrap_or_default();
+        Convergence {
+            mass_tolerance: data.mass_tolerance.unwrap_or(default.mass_tolerance),
+            shift_tolerance: data.shift_tolerance.unwrap_or(default.shift_tolerance),
+            max_iterations: data.max_iterations.unwrap_or(default.max_iterations),
+        }
+    }
+    /// Срезающая сила, содержит всю цепочку расчета:
+    /// Mass -> Trim -> Displacement -> Equilibrium -> TotalForce -> ShearForce.
+    /// Посадка решается один раз и используется также для вывода
+    /// - equilibrium: посадка судна
+    pub fn shear_force(&self, equilibrium: Rc<Equilibrium>) -> Result<ShearForce, Error> {
+        Ok(ShearForce::new(TotalForce::new(self.mass()?, equilibrium, self.gravity_g)?))
+    }
+    /// Затопленные отсеки из запроса, ошибка если отсек шире корпуса
+    pub fn compartments(&self) -> Result<Vec<Compartment>, Error> {
+        let breadth = self.hull_breadth();
+        self.input
+            .flooded
+            .iter()
+            .flatten()
+            .map(|c| {
+                Compartment::new(
+                    Bound::new(c.bound.0, c.bound.1)?,
+                    Bound::new(c.bound.2, c.bound.3)?,
+                    Bound::new(c.bound_z.0, c.bound_z.1)?,
+                    c.permeability,
+                    breadth,
+                )
+            })
+            .collect()
+    }
+    /// Наибольшая ширина корпуса по наклону кривых погруженной
+    /// площади шпангоутов между соседними точками, $b = dS/dz$
+    fn hull_breadth(&self) -> f64 {
+        self.frames
+            .frames
+            .iter()
+            .flat_map(|f| {
+                let mut area = f.immersion_area.clone();
+                area.sort_by(|a, b| a.0.total_cmp(&b.0));
+                area.windows(2)
+                    .filter(|v| v[1].0 > v[0].0)
+                    .map(|v| (v[1].1 - v[0].1) / (v[1].0 - v[0].0))
+                    .collect::<Vec<_>>()
+            })
+            .fold(0., f64::max)
+    }
+    /// Посадка судна для вывода
+    /// - position: посадка судна
+    /// - heel: угол крена, градусы
+    fn out_equilibrium(&self, position: &FloatingPosition, heel: Option<f64>) -> OutEquilibrium {
+        let ship_length = self.ship.ship_length;
+        OutEquilibrium {
+            draught: position.draught,
+            draught_stern: position.draught - position.trim / 2.,
+            draught_bow: position.draught + position.trim / 2.,
+            trim: position.trim,
+            trim_angle: (position.trim / ship_length).atan().to_degrees(),
+            heel,
+            iterations: position.iterations,
+            mass_residual: position.mass_residual,
+            shift_residual: position.shift_residual,
+        }
+    }
+    /// Посадка и прочность судна после затопления, None если
+    /// в запросе не заданы затопленные отсеки. Крен и метацентрическая высота
+    /// после затопления вычисляются, если задана кривая поперечного
+    /// метацентрического радиуса; при неположительной метацентрической
+    /// высоте крен не выводится, посадка и прочность вычисляются
+    /// - x: координаты по х точек эпюров
+    pub fn damage(&self, x: &[f64]) -> Result<Option<OutDamage>, Error> {
+        let compartments = self.compartments()?;
+        if compartments.is_empty() {
+            return Ok(None);
+        }
+        let equilibrium = Rc::new(self.equilibrium_flooded(compartments.clone())?);
+        let position = equilibrium.solve()?;
+        let shear_force = self.shear_force(equilibrium)?;
+        let bending_moment = BendingMoment::new(&shear_force).values()?;
+        let shear_force = shear_force.values()?;
+        let flooding = Flooding::new(compartments, self.ship.ship_length)?;
+        let (heel, metacentric_height) = match self.metacentric_height()? {
+            Some(metacentric_height) => {
+                let volume = metacentric_height.volume()?;
+                let shift_y = self.mass()?.shift().y() - self.center_draught_shift()?.value(volume).y();
+                (
+                    flooding.heel(&position, shift_y, &metacentric_height)?,
+                    Some(flooding.metacentric_height(&position, &metacentric_height)?),
+                )
+            }
+            None => (None, None),
+        };
+        Ok(Some(OutDamage {
+            lost_volume: flooding.volume(&position),
+            equilibrium: self.out_equilibrium(&position, heel),
+            metacentric_height,
+            utilisation: self.utilisation(x, &shear_force, &bending_moment)?,
+            shear_force: x.iter().copied().zip(shear_force).collect(),
+            bending_moment: x.iter().copied().zip(bending_moment).collect(),
+        }))
+    }
+    /// Результат расчета: эпюры срезающих сил и изгибающих моментов,
+    /// значения сопоставлены с координатами по х границ отрезков разбиения
+    pub fn out_data(&self) -> Result<OutData, Error> {
+        let bounds = self.bounds()?;
+        let x = bounds
+            .first()
+            .map(|b| b.start())
+            .into_iter()
+            .chain(bounds.iter().map(|b| b.end()))
+            .collect::<Vec<_>>();
+        let equilibrium = Rc::new(self.equilibrium()?);
+        let position = equilibrium.solve()?;
+        let shear_force = self.shear_force(equilibrium)?;
+        let bending_moment = BendingMoment::new(&shear_force).values()?;
+        let shear_force = shear_force.values()?;
+        let weather = self.weather()?;
+        Ok(OutData {
+            stability: self.stability()?,
+            righting_lever: self.stability_diagram()?,
+            weather: self.out_weather(weather.as_ref()),
+            criteria: self.criteria(weather)?,
+            grain: self.out_grain()?,
+            bulk: self.out_bulk()?,
+            voyage: self.voyage()?,
+            summary: Some(self.summary()?),
+            stowage: self.out_stowage()?,
+            ice: self.out_ice()?,
+            hydrostatics_mismatch: Vec::new(),
+            equilibrium: Some(self.out_equilibrium(&position, self.heel_angle()?)),
+            damage: self.damage(&x)?,
+            utilisation: self.utilisation(&x, &shear_force, &bending_moment)?,
+            wave: self.wave(&x, &shear_force, &bending_moment)?,
+            shear_force: x.iter().copied().zip(shear_force).collect(),
+            bending_moment: x.iter().copied().zip(bending_moment).collect(),
+        })
+    }
+    /// Использование допустимых значений срезающих сил и изгибающих
+    /// моментов для условия эксплуатации из запроса, None если
+    /// для судна не заданы допустимые значения. Аргументы:
+    /// - x: координаты по х точек эпюров
+    /// - shear_force: эпюр срезающих сил
+    /// - bending_moment: эпюр изгибающего момента
+    pub fn utilisation(
+        &self,
+        x: &[f64],
+        shear_force: &[f64],
+        bending_moment: &[f64],
+    ) -> Result<Option<OutUtilisation>, Error> {
+        let Some(limits) = &self.ship.strength_limits else {
+            return Ok(None);
+        };
+        let limits = match self.input.condition {
+            Condition::Sea => &limits.sea,
+            Condition::Harbor => &limits.harbor,
+        };
+        let shear_force = Utilisation::new(
+            x.to_vec(),
+            shear_force.to_vec(),
+            Curve::new(limits.sf_positive.clone())?,
+            Curve::new(limits.sf_negative.clone())?,
+        )?;
+        let bending_moment = Utilisation::new(
+            x.to_vec(),
+            bending_moment.to_vec(),
+            Curve::new(limits.bm_hogging.clone())?,
+            Curve::new(limits.bm_sagging.clone())?,
+        )?;
+        let shear_force_max = shear_force.max();
+        let bending_moment_max = bending_moment.max();
+        Ok(Some(OutUtilisation {
+            shear_force: shear_force.values(),
+            bending_moment: bending_moment.values(),
+            shear_force_max,
+            bending_moment_max,
+            pass: shear_force_max.1 <= 100. && bending_moment_max.1 <= 100.,
+        }))
+    }
+    /// Волновые нагрузки и огибающие суммарных эпюров, None если
+    /// для судна не заданы данные для вычисления волновых нагрузок. Аргументы:
+    /// - x: координаты по х точек эпюров
+    /// - shear_force: эпюр срезающих сил на тихой воде
+    /// - bending_moment: эпюр изгибающего момента на тихой воде
+    pub fn wave(
+        &self,
+        x: &[f64],
+        shear_force: &[f64],
+        bending_moment: &[f64],
+    ) -> Result<Option<OutWave>, Error> {
+        let Some(data) = &self.ship.wave else {
+            return Ok(None);
+        };
+        let factor = |curve: &Option<Vec<(f64, f64)>>, rule: Result<Curve, Error>| match curve {
+            Some(curve) => Curve::new(curve.clone()),
+            None => rule,
+        };
+        let wave = WaveLoad::new(
+            self.ship.ship_length,
+            data.breadth,
+            data.block_coefficient,
+            factor(&data.m, WaveLoad::rule_m())?,
+            factor(&data.f1, WaveLoad::rule_f1(data.block_coefficient))?,
+            factor(&data.f2, WaveLoad::rule_f2(data.block_coefficient))?,
+        )?;
+        let (hogging, sagging) = wave.bending_moment(x);
+        let (positive, negative) = wave.shear_force(x);
+        let points = |values: &[f64]| x.iter().copied().zip(values.iter().copied()).collect::<Vec<_>>();
+        let total = |still: &[f64], wave: &[f64]| still.iter().zip(wave).map(|(s, w)| s + w).collect::<Vec<_>>();
+        Ok(Some(OutWave {
+            total_bending_moment_max: points(&total(bending_moment, &hogging)),
+            total_bending_moment_min: points(&total(bending_moment, &sagging)),
+            total_shear_force_max: points(&total(shear_force, &positive)),
+            total_shear_force_min: points(&total(shear_force, &negative)),
+            bending_moment_hogging: points(&hogging),
+            bending_moment_sagging: points(&sagging),
+            shear_force_positive: points(&positive),
+            shear_force_negative: points(&negative),
+        }))
+    }
+}
@@ -0,0 +1,138 @@
+//! Критерии остойчивости Международного кодекса остойчивости судов в неповрежденном состоянии 2008 г.
+use crate::{error::Error, grain_heel::GrainHeel, righting_lever::RightingLever, weather::WeatherCriterion};
+
+/// Результат проверки одного критерия
+#[derive(Debug, Clone, PartialEq)]
+pub struct Criterion {
+    /// наименование критерия
+    pub name: String,
+    /// фактическое значение, None если оно не вычисляется
+    pub actual: Option<f64>,
+    /// допустимое значение, None если оно не вычисляется
+    pub required: Option<f64>,
+    /// допустимое значение является наибольшим, иначе наименьшим
+    pub maximum: bool,
+}
+///
+impl Criterion {
+    /// Основной конструктор. Аргументы:
+    /// - name: наименование критерия
+    /// - actual: фактическое значение
+    /// - required: наименьшее допустимое значение
+    pub fn new(name: &str, actual: f64, required: f64) -> Self {
+        Self { name: name.to_owned(), actual: Some(actual), required: Some(required), maximum: false }
+    }
+    /// Конструктор критерия с наибольшим допустимым значением. Аргументы:
+    /// - name: наименование критерия
+    /// - actual: фактическое значение
+    /// - required: наибольшее допустимое значение
+    pub fn new_maximum(name: &str, actual: f64, required: f64) -> Self {
+        Self { name: name.to_owned(), actual: Some(actual), required: Some(required), maximum: true }
+    }
+    /// Конструктор критерия, фактическое значение которого не вычисляется,
+    /// такой критерий не выполнен. Аргументы:
+    /// - name: наименование критерия
+    /// - required: допустимое значение, если оно известно
+    /// - maximum: допустимое значение является наибольшим, иначе наименьшим
+    pub fn new_unknown(name: &str, required: Option<f64>, maximum: bool) -> Self {
+        Self { name: name.to_owned(), actual: None, required, maximum }
+    }
+    /// Критерий выполнен
+    pub fn pass(&self) -> bool {
+        match (self.actual, self.required) {
+            (Some(actual), Some(required)) if self.maximum => actual <= required,
+            (Some(actual), Some(required)) => actual >= required,
+            _ => false,
+        }
+    }
+}
+/// Общие критерии остойчивости IS Code 2008, часть A, 2.2:
+/// - площадь под диаграммой статической остойчивости до 30° не менее 0.055 м·рад,
+/// - до 40° или угла заливания, если он меньше, не менее 0.09 м·рад,
+/// - между 30° и 40° или углом заливания не менее 0.03 м·рад,
+/// - плечо статической остойчивости при угле крена 30° и более не менее 0.2 м,
+/// - угол максимума диаграммы статической остойчивости не менее 25°,
+/// - начальная метацентрическая высота не менее 0.15 м,
+/// - критерий погоды [Weather], если он задан: угол крена от постоянного
+///   ветра не более 16°, площадь b не менее площади a; при неположительной
+///   метацентрической высоте оба критерия не выполнены, их значения не определены,
+/// - критерии остойчивости при перевозке зерна [GrainHeel], если в трюмах
+///   есть зерно: угол крена от смещения зерна не более 12°, остаточная площадь
+///   не менее 0.075 м·рад, начальная метацентрическая высота не менее 0.3 м.
+pub struct Criteria {
+    /// диаграмма статической остойчивости
+    righting_lever: RightingLever,
+    /// исправленная начальная метацентрическая высота
+    metacentric_height: f64,
+    /// угол заливания, градусы
+    flooding_angle: Option<f64>,
+    /// критерий погоды
+    weather: Option<WeatherCriterion>,
+    /// крен от смещения зерна
+    grain: Option<GrainHeel>,
+}
+///
+impl Criteria {
+    /// Основной конструктор. Аргументы:
+    /// - righting_lever: диаграмма статической остойчивости
+    /// - metacentric_height: исправленная начальная метацентрическая высота
+    /// - flooding_angle: угол заливания, градусы, необязательно
+    /// - weather: критерий погоды, необязательно
+    /// - grain: крен от смещения зерна, необязательно
+    pub fn new(
+        righting_lever: RightingLever,
+        metacentric_height: f64,
+        flooding_angle: Option<f64>,
+        weather: Option<WeatherCriterion>,
+        grain: Option<GrainHeel>,
+    ) -> Result<Self, Error> {
+        if let Some(angle) = flooding_angle {
+            if angle <= 0. {
+                return Err(Error::Criteria(format!("flooding_angle {angle} must be greater than 0")));
+            }
+        }
+        let angle_max = righting_lever.angle_max();
+        if angle_max < 40f64.min(flooding_angle.unwrap_or(40.)).max(30.) {
+            return Err(Error::Criteria(format!(
+                "cross curves angle_max {angle_max} must be greater or equal to 40 or flooding angle"
+            )));
+        }
+        Ok(Self { righting_lever, metacentric_height, flooding_angle, weather, grain })
+    }
+    /// Результаты проверки всех критериев
+    pub fn values(&self) -> Vec<Criterion> {
+        let angle_40 = 40f64.min(self.flooding_angle.unwrap_or(40.));
+        let lever_30 = self
+            .righting_lever
+            .values(&[30., self.righting_lever.max().0.max(30.)])
+            .into_iter()
+            .map(|v| v.1)
+            .fold(f64::MIN, f64::max);
+        let mut result = vec![
+            Criterion::new("area_0_30", self.righting_lever.area(0., 30.), 0.055),
+            Criterion::new("area_0_40", self.righting_lever.area(0., angle_40), 0.09),
+            Criterion::new("area_30_40", self.righting_lever.area(30., angle_40), 0.03),
+            Criterion::new("lever_30", lever_30, 0.2),
+            Criterion::new("angle_max_lever", self.righting_lever.max().0, 25.),
+            Criterion::new("metacentric_height", self.metacentric_height, 0.15),
+        ];
+        match &self.weather {
+            Some(WeatherCriterion::Computed(weather)) => {
+                result.push(Criterion::new_maximum("weather_angle_0", weather.angle_0(), 16.));
+                result.push(Criterion::new("weather_area_b", weather.area_b(), weather.area_a()));
+            }
+            Some(WeatherCriterion::Unstable) => {
+                result.push(Criterion::new_unknown("weather_angle_0", Some(16.), true));
+                result.push(Criterion::new_unknown("weather_area_b", None, false));
+            }
+            None => {}
+        }
+        if let Some(grain) = &self.grain {
+            result.push(Criterion::new_maximum("grain_angle", grain.angle(), 12.));
+            result.push(Criterion::new("grain_area", grain.area(), 0.075));
+            result.push(Criterion::new("grain_metacentric_height", self.metacentric_height, 0.3));
+        }
+        log::debug!("\t Criteria result:{:?}", result);
+        result
+    }
+}
@@ -0,0 +1,56 @@
+//! Пантокарены - плечи остойчивости формы
+use crate::{
+    data::parse_input::CurveFamily,
+    error::Error,
+    math::curve::{Curve, ICurve},
+};
+
+/// Пантокарены: кривые плеча остойчивости формы KN по углу крена
+/// для ряда значений объемного водоизмещения. Промежуточные значения
+/// по водоизмещению определяются линейной интерполяцией между кривыми.
+pub struct CrossCurves {
+    /// кривые KN по углу крена в градусах, по возрастанию водоизмещения
+    curves: Vec<(f64, Curve)>,
+    /// наибольший угол крена, для которого заданы кривые
+    angle_max: f64,
+}
+///
+impl CrossCurves {
+    /// Основной конструктор
+    /// - values: кривые (объемное водоизмещение, [(угол крена в градусах, KN)])
+    pub fn new(values: CurveFamily) -> Result<Self, Error> {
+        if values.is_empty() {
+            return Err(Error::Curve("cross curves must not be empty".to_owned()));
+        }
+        let angle_max = values
+            .iter()
+            .map(|v| v.1.iter().map(|p| p.0).fold(f64::MIN, f64::max))
+            .fold(f64::MAX, f64::min);
+        let mut curves = values
+            .into_iter()
+            .map(|(volume, kn)| Ok((volume, Curve::new(kn)?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        curves.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(Self { curves, angle_max })
+    }
+    /// Наибольший угол крена, для которого заданы все кривые, градусы
+    pub fn angle_max(&self) -> f64 {
+        self.angle_max
+    }
+    /// Плечо остойчивости формы
+    /// - volume: объемное водоизмещение
+    /// - angle: угол крена, градусы
+    pub fn value(&self, volume: f64, angle: f64) -> f64 {
+        let upper = self.curves.iter().position(|v| v.0 >= volume);
+        match upper {
+            Some(0) => self.curves[0].1.value(angle),
+            None => self.curves[self.curves.len() - 1].1.value(angle),
+            Some(i) => {
+                let (v0, c0) = &self.curves[i - 1];
+                let (v1, c1) = &self.curves[i];
+                let k = (volume - v0) / (v1 - v0);
+                c0.value(angle) * (1. - k) + c1.value(angle) * k
+            }
+        }
+    }
+}
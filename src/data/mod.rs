@@ -1,3 +1,4 @@
 //! Структуры для ввода/вывода данных
 pub mod parse_input;
-pub mod serialize_out;
\ No newline at end of file
+pub mod serialize_out;
+pub mod source;
\ No newline at end of file
@@ -2,6 +2,9 @@
 use serde::{de::Error, de::Unexpected, Deserialize, Serialize};
 
 pub type Result<T> = serde_json::Result<T>;
+/// Семейство кривых, заданных для ряда значений параметра:
+/// [(значение параметра, [(x, y)])]
+pub type CurveFamily = Vec<(f64, Vec<(f64, f64)>)>;
 
 /// Данные запроса на расчет
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -14,13 +17,183 @@ pub struct ParsedInputData {
     pub n_parts: u64,
     /// плотность воды
     pub water_density: f64,
+    /// твердые грузы варианта загрузки,
+    /// если не заданы - берутся из данных по судну
+    #[serde(default)]
+    pub load_space: Option<Vec<LoadSpaceData>>,
+    /// зерно навалом варианта загрузки, вместе с load_space и bulk
+    /// заменяет грузы из данных по судну
+    #[serde(default)]
+    pub grain: Option<Vec<GrainData>>,
+    /// навалочные грузы в трюмах варианта загрузки, вместе с load_space
+    /// и grain заменяют грузы из данных по судну
+    #[serde(default)]
+    pub bulk: Option<Vec<BulkData>>,
+    /// цистерны варианта загрузки,
+    /// если не заданы - берутся из данных по судну
+    #[serde(default)]
+    pub tanks: Option<Vec<TankData>>,
+    /// условие эксплуатации, определяет допустимые значения
+    /// срезающих сил и изгибающих моментов, по умолчанию - в море
+    #[serde(default)]
+    pub condition: Condition,
+    /// углы крена для диаграммы статической остойчивости, градусы,
+    /// по умолчанию через 10 градусов до наибольшего угла пантокарен
+    #[serde(default)]
+    pub heel_angles: Option<Vec<f64>>,
+    /// затопленные отсеки, если заданы - вычисляется
+    /// посадка и прочность судна после затопления
+    #[serde(default)]
+    pub flooded: Option<Vec<CompartmentData>>,
+    /// контейнеры варианта загрузки по ячейкам грузового плана судна
+    #[serde(default)]
+    pub containers: Option<Vec<ContainerData>>,
+    /// расход запасов на переходе, если задан - вычисляются
+    /// промежуточные состояния от отхода до прихода
+    #[serde(default)]
+    pub voyage: Option<VoyageData>,
+    /// обледенение, если задано - лед добавляется к грузам,
+    /// необходимы данные по обледенению судна
+    #[serde(default)]
+    pub icing: Option<Icing>,
+    /// условия сходимости расчета посадки судна,
+    /// незаданные значения берутся по умолчанию
+    #[serde(default)]
+    pub convergence: Option<ConvergenceData>,
+}
+/// Условия сходимости расчета посадки судна
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ConvergenceData {
+    /// допустимая невязка массы, доля от массы судна
+    #[serde(default)]
+    pub mass_tolerance: Option<f64>,
+    /// допустимая невязка отстояния центра величины по длине, м
+    #[serde(default)]
+    pub shift_tolerance: Option<f64>,
+    /// наибольшее количество итераций
+    #[serde(default)]
+    pub max_iterations: Option<usize>,
+}
+///
+impl ConvergenceData {
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        if let Some(tolerance) = self.mass_tolerance.into_iter().chain(self.shift_tolerance).find(|v| *v <= 0.) {
+            return Err(Error::invalid_value(
+                Unexpected::Float(tolerance),
+                &"positive value of convergence tolerance",
+            ));
+        }
+        if self.max_iterations == Some(0) {
+            return Err(Error::invalid_value(
+                Unexpected::Unsigned(0),
+                &"max_iterations greater to 0",
+            ));
+        }
+        Ok(())
+    }
+}
+/// Обледенение судна
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Icing {
+    /// полное обледенение
+    Full,
+    /// половинное обледенение
+    Half,
+}
+/// Расход запасов на переходе
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VoyageData {
+    /// продолжительность перехода, ч
+    pub duration: f64,
+    /// шаг по времени между промежуточными состояниями, ч, по умолчанию 24
+    #[serde(default = "VoyageData::default_step")]
+    pub step: f64,
+    /// уровни заполнения расходных цистерн, % от вместимости,
+    /// при достижении которых вычисляется промежуточное состояние
+    #[serde(default)]
+    pub levels: Vec<f64>,
+    /// расход запасов из цистерн
+    pub consumption: Vec<ConsumptionData>,
+}
+///
+impl VoyageData {
+    /// Шаг по времени по умолчанию, ч
+    fn default_step() -> f64 {
+        24.
+    }
+}
+/// Расход запасов из цистерны
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConsumptionData {
+    /// индекс цистерны в массиве цистерн варианта загрузки или судна
+    pub tank: usize,
+    /// расход, т/ч
+    pub rate: f64,
+}
+/// Контейнер в ячейке грузового плана
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContainerData {
+    /// номер бея
+    pub bay: usize,
+    /// номер ряда
+    pub row: usize,
+    /// номер яруса
+    pub tier: usize,
+    /// масса контейнера
+    pub mass: f64,
+}
+/// Категория груза
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadCategory {
+    /// груз
+    Cargo,
+    /// балласт
+    Ballast,
+    /// топливо
+    Fuel,
+    /// смазочное масло
+    LubricatingOil,
+    /// пресная вода
+    FreshWater,
+    /// снабжение
+    Stores,
+    /// экипаж
+    Crew,
+    /// прочее
+    Other,
+}
+/// Затопленный отсек
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompartmentData {
+    /// границы отсека (x1, x2, y1, y2)
+    pub bound: (f64, f64, f64, f64),
+    /// границы отсека по высоте от ОП (z1, z2)
+    pub bound_z: (f64, f64),
+    /// коэффициент проницаемости
+    pub permeability: f64,
+}
+/// Условие эксплуатации судна
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Condition {
+    /// в море
+    #[default]
+    Sea,
+    /// в порту
+    Harbor,
 }
 ///
-#[allow(dead_code)]
 impl ParsedInputData {
     ///
     pub fn parse(src: &str) -> Result<Self> {
-        let result: ParsedInputData = serde_json::from_str(src)?;
+        serde_json::from_str::<ParsedInputData>(src)?.check()
+    }
+    /// Проверка данных на корректность
+    pub fn check(self) -> Result<Self> {
+        let result = self;
         if result.project_name.len() == 0 {
             return Err(Error::invalid_value(
                 Unexpected::Str(&result.project_name),
@@ -45,6 +218,48 @@ impl ParsedInputData {
                 &"positive value of water density",
             ));
         }
+        if let Some(compartment) = result
+            .flooded
+            .iter()
+            .flatten()
+            .find(|c| !(0. ..=1.).contains(&c.permeability))
+        {
+            return Err(Error::invalid_value(
+                Unexpected::Float(compartment.permeability),
+                &"permeability of flooded compartment in range 0..=1",
+            ));
+        }
+        if let Some(container) = result.containers.iter().flatten().find(|c| c.mass <= 0.) {
+            return Err(Error::invalid_value(
+                Unexpected::Float(container.mass),
+                &"positive value of container mass",
+            ));
+        }
+        if let Some(voyage) = &result.voyage {
+            if voyage.duration <= 0. || voyage.step <= 0. {
+                return Err(Error::invalid_value(
+                    Unexpected::Float(voyage.duration.min(voyage.step)),
+                    &"positive values of voyage duration and step",
+                ));
+            }
+            if let Some(consumption) = voyage.consumption.iter().find(|c| c.rate < 0.) {
+                return Err(Error::invalid_value(
+                    Unexpected::Float(consumption.rate),
+                    &"consumption rate greater or equal to 0",
+                ));
+            }
+        }
+        if let Some(angles) = &result.heel_angles {
+            if let Some(angle) = angles.iter().find(|a| **a < 0.) {
+                return Err(Error::invalid_value(
+                    Unexpected::Float(*angle),
+                    &"heel angle greater or equal to 0",
+                ));
+            }
+        }
+        if let Some(convergence) = &result.convergence {
+            convergence.check()?;
+        }
         Ok(result)
     }
 }
@@ -62,13 +277,49 @@ pub struct ParsedShipData {
     pub mean_draught: Vec<(f64, f64)>,
     /// кривая отстояния центра величины погруженной части судна
     pub center_shift: Vec<(f64, f64, f64, f64)>,
+    /// кривая поперечного метацентрического радиуса,
+    /// если не задана - остойчивость не вычисляется
+    #[serde(default)]
+    pub rad_trans: Option<Vec<(f64, f64)>>,
+    /// пантокарены: кривые плеча остойчивости формы по углу крена
+    /// (объемное водоизмещение, [(угол крена в градусах, KN)]), необязательно
+    #[serde(default)]
+    pub cross_curves: Option<CurveFamily>,
+    /// угол заливания в градусах для критериев остойчивости, необязательно
+    #[serde(default)]
+    pub flooding_angle: Option<f64>,
+    /// допустимые значения срезающих сил и изгибающих моментов,
+    /// если не заданы - использование не вычисляется
+    #[serde(default)]
+    pub strength_limits: Option<StrengthLimitsData>,
+    /// данные для вычисления волновых нагрузок,
+    /// если не заданы - волновые нагрузки не вычисляются
+    #[serde(default)]
+    pub wave: Option<WaveData>,
+    /// данные для проверки критерия погоды,
+    /// если не заданы - критерий погоды не проверяется
+    #[serde(default)]
+    pub weather: Option<WeatherData>,
+    /// масса судна порожнем, если задана - добавляется к грузам
+    #[serde(default)]
+    pub lightship: Option<LightshipData>,
+    /// грузовой план контейнеров, необходим если в варианте загрузки заданы контейнеры
+    #[serde(default)]
+    pub stowage: Option<StowageData>,
+    /// открытые палубы и площади парусности для расчета обледенения,
+    /// необходимы если в варианте загрузки задано обледенение
+    #[serde(default)]
+    pub ice: Option<IceData>,
 }
 ///
-#[allow(dead_code)]
 impl ParsedShipData {
     ///
     pub fn parse(src: &str) -> Result<Self> {
-        let result: ParsedShipData = serde_json::from_str(src)?;
+        serde_json::from_str::<ParsedShipData>(src)?.check()
+    }
+    /// Проверка данных на корректность
+    pub fn check(self) -> Result<Self> {
+        let result = self;
         if result.ship_length <= 0. {
             return Err(Error::invalid_value(
                 Unexpected::Float(result.ship_length),
@@ -93,9 +344,155 @@ impl ParsedShipData {
                 &"number of center_shift's points greater or equal to 2",
             ));
         }
+        if let Some(rad_trans) = &result.rad_trans {
+            if rad_trans.len() <= 1 {
+                return Err(Error::invalid_value(
+                    Unexpected::Unsigned(rad_trans.len() as u64),
+                    &"number of rad_trans's points greater or equal to 2",
+                ));
+            }
+        }
+        if let Some(cross_curves) = &result.cross_curves {
+            if cross_curves.is_empty() {
+                return Err(Error::invalid_value(
+                    Unexpected::Unsigned(0),
+                    &"number of cross_curves greater to 0",
+                ));
+            }
+            if let Some(curve) = cross_curves.iter().find(|v| v.1.len() <= 1) {
+                return Err(Error::invalid_value(
+                    Unexpected::Unsigned(curve.1.len() as u64),
+                    &"number of cross_curve's points greater or equal to 2",
+                ));
+            }
+        }
+        if let Some(angle) = result.flooding_angle {
+            if angle <= 0. {
+                return Err(Error::invalid_value(
+                    Unexpected::Float(angle),
+                    &"positive value of flooding angle",
+                ));
+            }
+        }
+        if let Some(limits) = &result.strength_limits {
+            limits.sea.check()?;
+            limits.harbor.check()?;
+        }
+        if let Some(wave) = &result.wave {
+            wave.check()?;
+            if !(90. ..=500.).contains(&result.ship_length) {
+                return Err(Error::invalid_value(
+                    Unexpected::Float(result.ship_length),
+                    &"ship's length in range 90..=500 for wave loads by IACS UR S11",
+                ));
+            }
+        }
+        if let Some(weather) = &result.weather {
+            weather.check()?;
+        }
+        if let Some(lightship) = &result.lightship {
+            lightship.check()?;
+        }
+        if let Some(stowage) = &result.stowage {
+            stowage.check()?;
+        }
+        if let Some(ice) = &result.ice {
+            ice.check()?;
+        }
         Ok(result)
     }
 }
+/// Допустимые значения срезающих сил и изгибающих моментов
+/// на тихой воде для условий эксплуатации судна
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StrengthLimitsData {
+    /// допустимые значения в море
+    pub sea: LimitsData,
+    /// допустимые значения в порту
+    pub harbor: LimitsData,
+}
+/// Кривые допустимых значений по длине судна (координата по х, значение),
+/// значения задаются по модулю. Положительный изгибающий момент
+/// соответствует перегибу, отрицательный - прогибу
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LimitsData {
+    /// допустимая положительная срезающая сила
+    pub sf_positive: Vec<(f64, f64)>,
+    /// допустимая отрицательная срезающая сила
+    pub sf_negative: Vec<(f64, f64)>,
+    /// допустимый изгибающий момент на перегиб
+    pub bm_hogging: Vec<(f64, f64)>,
+    /// допустимый изгибающий момент на прогиб
+    pub bm_sagging: Vec<(f64, f64)>,
+}
+///
+impl LimitsData {
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        for curve in [&self.sf_positive, &self.sf_negative, &self.bm_hogging, &self.bm_sagging] {
+            if curve.len() <= 1 {
+                return Err(Error::invalid_value(
+                    Unexpected::Unsigned(curve.len() as u64),
+                    &"number of limit's points greater or equal to 2",
+                ));
+            }
+            if let Some(point) = curve.iter().find(|p| p.1 <= 0.) {
+                return Err(Error::invalid_value(
+                    Unexpected::Float(point.1),
+                    &"positive value of limit",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+/// Данные для вычисления волновых нагрузок по IACS UR S11
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WaveData {
+    /// ширина корпуса судна
+    pub breadth: f64,
+    /// коэффициент общей полноты
+    pub block_coefficient: f64,
+    /// коэффициент распределения изгибающего момента M
+    /// (x/L от кормы, значение), если не задан - по правилам
+    #[serde(default)]
+    pub m: Option<Vec<(f64, f64)>>,
+    /// коэффициент распределения положительной срезающей силы F1
+    /// (x/L от кормы, значение), если не задан - по правилам
+    #[serde(default)]
+    pub f1: Option<Vec<(f64, f64)>>,
+    /// коэффициент распределения отрицательной срезающей силы F2
+    /// (x/L от кормы, значение), если не задан - по правилам
+    #[serde(default)]
+    pub f2: Option<Vec<(f64, f64)>>,
+}
+///
+impl WaveData {
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        if self.breadth <= 0. {
+            return Err(Error::invalid_value(
+                Unexpected::Float(self.breadth),
+                &"positive value of ship's breadth",
+            ));
+        }
+        if self.block_coefficient <= 0. || self.block_coefficient > 1. {
+            return Err(Error::invalid_value(
+                Unexpected::Float(self.block_coefficient),
+                &"block_coefficient in range 0..=1",
+            ));
+        }
+        for curve in [&self.m, &self.f1, &self.f2].into_iter().flatten() {
+            if curve.len() <= 1 {
+                return Err(Error::invalid_value(
+                    Unexpected::Unsigned(curve.len() as u64),
+                    &"number of distribution factor's points greater or equal to 2",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
 /// Шпангоут
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct FrameData {
@@ -111,11 +508,14 @@ pub struct ParsedFramesData {
     pub frames: Vec<FrameData>,
 }
 ///
-#[allow(dead_code)]
 impl ParsedFramesData {
     ///
     pub fn parse(src: &str) -> Result<Self> {
-        let result: ParsedFramesData = serde_json::from_str(src)?;
+        serde_json::from_str::<ParsedFramesData>(src)?.check()
+    }
+    /// Проверка данных на корректность
+    pub fn check(self) -> Result<Self> {
+        let result = self;
         if result.frames.len() <= 1 {
             return Err(Error::invalid_value(
                 Unexpected::Unsigned(result.frames.len() as u64),
@@ -146,34 +546,179 @@ pub struct LoadSpaceData {
     pub bound: (f64, f64, f64, f64),
     /// центер масс
     pub center: (f64, f64, f64),
+    /// наименование груза, необязательно
+    #[serde(default)]
+    pub name: Option<String>,
+    /// категория груза, необязательно
+    #[serde(default)]
+    pub category: Option<LoadCategory>,
 }
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ParsedLoadsData {
     pub load_space: Vec<LoadSpaceData>,
+    /// зерно навалом в трюмах
+    #[serde(default)]
+    pub grain: Vec<GrainData>,
+    /// навалочные грузы в трюмах
+    #[serde(default)]
+    pub bulk: Vec<BulkData>,
 }
 ///
-#[allow(dead_code)]
 impl ParsedLoadsData {
     ///
     pub fn parse(src: &str) -> Result<Self> {
-        let result: ParsedLoadsData = serde_json::from_str(src)?;
+        serde_json::from_str::<ParsedLoadsData>(src)?.check()
+    }
+    /// Проверка данных на корректность
+    pub fn check(self) -> Result<Self> {
+        let result = self;
         if let Some(space) = result.load_space.iter().find(|s| s.mass < 0.) {
             return Err(Error::invalid_value(
                 Unexpected::Float(space.mass),
                 &"mass of load_space greater or equal to 0",
             ));
         }
+        for grain in result.grain.iter() {
+            grain.check()?;
+        }
+        for bulk in result.bulk.iter() {
+            bulk.check()?;
+        }
         Ok(result)
     }
 }
+/// Зерно навалом в трюме
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GrainData {
+    /// удельный погрузочный объем, м^3/т
+    pub stowage_factor: f64,
+    /// объем зерна в трюме
+    pub volume: f64,
+    /// вместимость трюма
+    pub capacity: f64,
+    /// границы трюма
+    pub bound: (f64, f64, f64, f64),
+    /// центер масс
+    pub center: (f64, f64, f64),
+    /// кривая объемного кренящего момента по объему зерна в трюме, м^4
+    pub moment: Vec<(f64, f64)>,
+    /// наименование груза, необязательно
+    #[serde(default)]
+    pub name: Option<String>,
+    /// категория груза, необязательно
+    #[serde(default)]
+    pub category: Option<LoadCategory>,
+}
+///
+impl GrainData {
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        if self.stowage_factor <= 0. {
+            return Err(Error::invalid_value(
+                Unexpected::Float(self.stowage_factor),
+                &"positive value of grain stowage_factor",
+            ));
+        }
+        if self.volume < 0. || self.volume > self.capacity {
+            return Err(Error::invalid_value(
+                Unexpected::Float(self.volume),
+                &"grain volume in range 0..=capacity",
+            ));
+        }
+        if self.moment.len() <= 1 {
+            return Err(Error::invalid_value(
+                Unexpected::Unsigned(self.moment.len() as u64),
+                &"number of grain moment's points greater or equal to 2",
+            ));
+        }
+        Ok(())
+    }
+}
+/// Навалочный груз в трюме
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BulkData {
+    /// масса груза
+    pub mass: f64,
+    /// удельный погрузочный объем, м^3/т, задается вместо density
+    #[serde(default)]
+    pub stowage_factor: Option<f64>,
+    /// плотность груза навалом, т/м^3, задается вместо stowage_factor
+    #[serde(default)]
+    pub density: Option<f64>,
+    /// границы трюма, (x1, x2, y1, y2)
+    pub bound: (f64, f64, f64, f64),
+    /// кривая координат центра масс груза по объему груза (volume, x, y, z)
+    pub center: Vec<(f64, f64, f64, f64)>,
+    /// кривая уровня поверхности груза по объему груза (volume, z),
+    /// наибольший объем - вместимость трюма
+    pub level: Vec<(f64, f64)>,
+    /// наименование груза, необязательно
+    #[serde(default)]
+    pub name: Option<String>,
+    /// категория груза, необязательно
+    #[serde(default)]
+    pub category: Option<LoadCategory>,
+}
+///
+impl BulkData {
+    /// Удельный погрузочный объем груза
+    pub fn stowage_factor(&self) -> f64 {
+        self.stowage_factor.or(self.density.map(|density| 1. / density)).unwrap_or(0.)
+    }
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        if self.mass < 0. {
+            return Err(Error::invalid_value(
+                Unexpected::Float(self.mass),
+                &"mass of bulk cargo greater or equal to 0",
+            ));
+        }
+        match (self.stowage_factor, self.density) {
+            (Some(value), None) | (None, Some(value)) if value > 0. => {}
+            (Some(_), Some(_)) | (None, None) => {
+                return Err(Error::invalid_value(
+                    Unexpected::Other("stowage_factor and density"),
+                    &"either stowage_factor or density of bulk cargo",
+                ))
+            }
+            (Some(value), None) | (None, Some(value)) => {
+                return Err(Error::invalid_value(
+                    Unexpected::Float(value),
+                    &"positive value of bulk cargo stowage_factor or density",
+                ))
+            }
+        }
+        if self.center.is_empty() {
+            return Err(Error::invalid_value(
+                Unexpected::Unsigned(0),
+                &"number of bulk center's points greater to 0",
+            ));
+        }
+        if self.level.len() <= 1 {
+            return Err(Error::invalid_value(
+                Unexpected::Unsigned(self.level.len() as u64),
+                &"number of bulk level's points greater or equal to 2",
+            ));
+        }
+        Ok(())
+    }
+}
 /// Цистерна
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TankData {
     /// плотность жидкости в цистерне
     pub density: f64,
-    /// объем жидкости в цистерне
-    pub volume: f64,
+    /// объем жидкости в цистерне, используется если не задано заполнение fill,
+    /// должен быть задан объем или заполнение
+    #[serde(default)]
+    pub volume: Option<f64>,
+    /// заполнение цистерны, приводится к объему жидкости
+    #[serde(default)]
+    pub fill: Option<FillData>,
+    /// калибровочная таблица цистерны
+    #[serde(default)]
+    pub calibration: Option<CalibrationData>,
     /// границы цистерны, (x1, x2, y1, y2)
     pub bound: (f64, f64, f64, f64),
     /// кривая координат центра объема жидкости в цистерне в системе координат судна
@@ -182,6 +727,136 @@ pub struct TankData {
     /// кривая момента инерции площади свободной поверхности жидкости
     /// (volume, x - поперечный, y - продольный)
     pub free_surf_inertia: Vec<(f64, f64, f64)>,
+    /// учет момента свободной поверхности жидкости, по умолчанию по фактическому объему
+    #[serde(default)]
+    pub free_surface: Option<FreeSurfaceData>,
+    /// наименование груза, необязательно
+    #[serde(default)]
+    pub name: Option<String>,
+    /// категория груза, необязательно
+    #[serde(default)]
+    pub category: Option<LoadCategory>,
+}
+/// Вид заполнения цистерны
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FillKind {
+    /// уровень жидкости
+    Sounding,
+    /// пустота
+    Ullage,
+    /// объем жидкости
+    Volume,
+    /// процент от вместимости цистерны
+    Percent,
+    /// масса жидкости
+    Mass,
+}
+/// Заполнение цистерны
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FillData {
+    /// вид заполнения
+    pub kind: FillKind,
+    /// значение заполнения
+    pub value: f64,
+    /// дифферент судна при замере уровня, м
+    #[serde(default)]
+    pub trim: f64,
+    /// крен судна при замере уровня, градусы
+    #[serde(default)]
+    pub heel: f64,
+}
+///
+impl FillData {
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        if self.value < 0. {
+            return Err(Error::invalid_value(
+                Unexpected::Float(self.value),
+                &"tank fill value greater or equal to 0",
+            ));
+        }
+        Ok(())
+    }
+}
+/// Способ учета момента свободной поверхности жидкости в цистерне
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FreeSurfaceKind {
+    /// по фактическому объему жидкости
+    Actual,
+    /// наибольший по кривой момента инерции
+    Max,
+    /// заданный момент свободной поверхности
+    Fixed,
+    /// не учитывается
+    None,
+}
+/// Учет момента свободной поверхности жидкости в цистерне
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FreeSurfaceData {
+    /// способ учета
+    pub kind: FreeSurfaceKind,
+    /// заданный момент свободной поверхности (x - поперечный, y - продольный), т·м,
+    /// только для способа fixed
+    #[serde(default)]
+    pub moment: Option<(f64, f64)>,
+}
+///
+impl FreeSurfaceData {
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        match (self.kind, self.moment) {
+            (FreeSurfaceKind::Fixed, None) => Err(Error::missing_field("free_surface moment")),
+            (FreeSurfaceKind::Fixed, Some((x, y))) if x < 0. || y < 0. => Err(Error::invalid_value(
+                Unexpected::Float(x.min(y)),
+                &"fixed free surface moment greater or equal to 0",
+            )),
+            (FreeSurfaceKind::Fixed, Some(_)) | (_, None) => Ok(()),
+            (_, Some(_)) => Err(Error::custom("free_surface moment is allowed only for kind fixed")),
+        }
+    }
+}
+/// Калибровочная таблица цистерны
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalibrationData {
+    /// высота цистерны, от которой отсчитывается пустота
+    pub height: f64,
+    /// объем жидкости по уровню (sounding, volume)
+    pub volume: Vec<(f64, f64)>,
+    /// поправки уровня на дифферент (trim, [(sounding, correction)])
+    #[serde(default)]
+    pub trim: Option<CurveFamily>,
+    /// поправки уровня на крен (heel, [(sounding, correction)])
+    #[serde(default)]
+    pub heel: Option<CurveFamily>,
+}
+///
+impl CalibrationData {
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        if self.height <= 0. {
+            return Err(Error::invalid_value(
+                Unexpected::Float(self.height),
+                &"positive value of calibration height",
+            ));
+        }
+        if self.volume.len() <= 1 {
+            return Err(Error::invalid_value(
+                Unexpected::Unsigned(self.volume.len() as u64),
+                &"number of calibration volume's points greater or equal to 2",
+            ));
+        }
+        for (_, curve) in self.trim.iter().chain(self.heel.iter()).flatten() {
+            if curve.len() <= 1 {
+                return Err(Error::invalid_value(
+                    Unexpected::Unsigned(curve.len() as u64),
+                    &"number of calibration correction's points greater or equal to 2",
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -189,23 +864,43 @@ pub struct ParsedTanksData {
     pub tanks: Vec<TankData>,
 }
 ///
-#[allow(dead_code)]
 impl ParsedTanksData {
     ///
     pub fn parse(src: &str) -> Result<Self> {
-        let result: ParsedTanksData = serde_json::from_str(src)?;
+        serde_json::from_str::<ParsedTanksData>(src)?.check()
+    }
+    /// Проверка данных на корректность
+    pub fn check(self) -> Result<Self> {
+        let result = self;
         if let Some(tank) = result.tanks.iter().find(|t| t.density <= 0.) {
             return Err(Error::invalid_value(
                 Unexpected::Float(tank.density),
                 &"density of liquid in the tank greater to 0",
             ));
         }
-        if let Some(tank) = result.tanks.iter().find(|t| t.volume < 0.) {
+        if result.tanks.iter().any(|t| t.volume.is_none() && t.fill.is_none()) {
+            return Err(Error::invalid_value(
+                Unexpected::Option,
+                &"volume or fill of tank",
+            ));
+        }
+        if let Some(volume) = result.tanks.iter().filter_map(|t| t.volume).find(|v| *v < 0.) {
             return Err(Error::invalid_value(
-                Unexpected::Float(tank.volume),
+                Unexpected::Float(volume),
                 &"volume of tank greater or equal to 0",
             ));
         }
+        for tank in result.tanks.iter() {
+            if let Some(fill) = &tank.fill {
+                fill.check()?;
+            }
+            if let Some(calibration) = &tank.calibration {
+                calibration.check()?;
+            }
+            if let Some(free_surface) = &tank.free_surface {
+                free_surface.check()?;
+            }
+        }
         if let Some(tank) = result.tanks.iter().find(|t| t.center.len() == 0) {
             return Err(Error::invalid_value(
                 Unexpected::Unsigned(tank.center.len() as u64),
@@ -221,3 +916,191 @@ impl ParsedTanksData {
         Ok(result)
     }
 }
+/// Грузовой план контейнеров
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StowageData {
+    /// беи (номер, x1, x2) - границы контейнера по длине
+    pub bays: Vec<(usize, f64, f64)>,
+    /// ряды (номер, y) - отстояние центра масс контейнера по ширине от ДП
+    pub rows: Vec<(usize, f64)>,
+    /// ярусы (номер, z) - аппликата центра масс контейнера
+    pub tiers: Vec<(usize, f64)>,
+    /// допустимые массы штабелей (бей, ряд, масса)
+    #[serde(default)]
+    pub stack_limits: Vec<(usize, usize, f64)>,
+}
+///
+impl StowageData {
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        if self.bays.is_empty() || self.rows.is_empty() || self.tiers.is_empty() {
+            return Err(Error::invalid_value(
+                Unexpected::Unsigned(0),
+                &"number of stowage bays, rows and tiers greater to 0",
+            ));
+        }
+        if let Some(bay) = self.bays.iter().find(|v| v.1 >= v.2) {
+            return Err(Error::invalid_value(
+                Unexpected::Float(bay.2),
+                &"stowage bay x2 greater than x1",
+            ));
+        }
+        let duplicate = |ids: Vec<usize>| ids.iter().enumerate().find(|(i, id)| ids[..*i].contains(id)).map(|(_, id)| *id);
+        if let Some(id) = duplicate(self.bays.iter().map(|v| v.0).collect())
+            .or(duplicate(self.rows.iter().map(|v| v.0).collect()))
+            .or(duplicate(self.tiers.iter().map(|v| v.0).collect()))
+        {
+            return Err(Error::invalid_value(
+                Unexpected::Unsigned(id as u64),
+                &"unique numbers of stowage bays, rows and tiers",
+            ));
+        }
+        if let Some(limit) = self.stack_limits.iter().find(|v| v.2 <= 0.) {
+            return Err(Error::invalid_value(
+                Unexpected::Float(limit.2),
+                &"positive value of stack limit",
+            ));
+        }
+        Ok(())
+    }
+}
+/// Данные для расчета обледенения судна
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IceData {
+    /// горизонтальные проекции открытых палуб
+    #[serde(default)]
+    pub deck: Vec<IceAreaData>,
+    /// площади парусности - проекции надводной части на ДП
+    #[serde(default)]
+    pub windage: Vec<IceAreaData>,
+}
+///
+impl IceData {
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        if self.deck.is_empty() && self.windage.is_empty() {
+            return Err(Error::invalid_value(
+                Unexpected::Unsigned(0),
+                &"number of ice deck and windage areas greater to 0",
+            ));
+        }
+        for area in self.deck.iter().chain(self.windage.iter()) {
+            if area.area <= 0. {
+                return Err(Error::invalid_value(
+                    Unexpected::Float(area.area),
+                    &"positive value of ice area",
+                ));
+            }
+            if area.bound.0 >= area.center.0 || area.bound.1 <= area.center.0 {
+                return Err(Error::invalid_value(
+                    Unexpected::Float(area.center.0),
+                    &"ice area center x within bound",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+/// Площадь, на которой нарастает лед
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IceAreaData {
+    /// площадь, м^2
+    pub area: f64,
+    /// границы площади по длине, (x1, x2)
+    pub bound: (f64, f64),
+    /// центр площади, (x, y, z)
+    pub center: (f64, f64, f64),
+}
+/// Масса судна порожнем
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LightshipData {
+    /// общая масса
+    pub mass: f64,
+    /// центер масс
+    pub center: (f64, f64, f64),
+    /// относительная интенсивность распределения массы по длине (x, значение),
+    /// если не задана - масса распределяется по трапеции
+    #[serde(default)]
+    pub distribution: Option<Vec<(f64, f64)>>,
+}
+///
+impl LightshipData {
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        if self.mass <= 0. {
+            return Err(Error::invalid_value(
+                Unexpected::Float(self.mass),
+                &"positive value of lightship mass",
+            ));
+        }
+        if let Some(distribution) = &self.distribution {
+            if distribution.len() <= 1 {
+                return Err(Error::invalid_value(
+                    Unexpected::Unsigned(distribution.len() as u64),
+                    &"number of lightship distribution's points greater or equal to 2",
+                ));
+            }
+            if let Some(v) = distribution.iter().find(|v| v.1 < 0.) {
+                return Err(Error::invalid_value(
+                    Unexpected::Float(v.1),
+                    &"lightship distribution value greater or equal to 0",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+/// Данные для проверки критерия погоды
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WeatherData {
+    /// ширина корпуса судна
+    pub breadth: f64,
+    /// коэффициент общей полноты
+    pub block_coefficient: f64,
+    /// суммарная площадь скуловых килей, 0 если их нет
+    #[serde(default)]
+    pub bilge_keel_area: f64,
+    /// судно с острой скулой
+    #[serde(default)]
+    pub hard_chine: bool,
+    /// парусность по средней осадке: (осадка, площадь парусности,
+    /// возвышение центра парусности над центром площади проекции подводной части на ДП)
+    pub windage: Vec<(f64, f64, f64)>,
+}
+///
+impl WeatherData {
+    /// Проверка данных на корректность
+    pub fn check(&self) -> Result<()> {
+        if self.breadth <= 0. {
+            return Err(Error::invalid_value(
+                Unexpected::Float(self.breadth),
+                &"positive value of ship's breadth",
+            ));
+        }
+        if self.block_coefficient <= 0. || self.block_coefficient > 1. {
+            return Err(Error::invalid_value(
+                Unexpected::Float(self.block_coefficient),
+                &"block_coefficient in range 0..=1",
+            ));
+        }
+        if self.bilge_keel_area < 0. {
+            return Err(Error::invalid_value(
+                Unexpected::Float(self.bilge_keel_area),
+                &"bilge_keel_area greater or equal to 0",
+            ));
+        }
+        if self.windage.len() <= 1 {
+            return Err(Error::invalid_value(
+                Unexpected::Unsigned(self.windage.len() as u64),
+                &"number of windage's points greater or equal to 2",
+            ));
+        }
+        if let Some(v) = self.windage.iter().find(|v| v.1 < 0.) {
+            return Err(Error::invalid_value(
+                Unexpected::Float(v.1),
+                &"windage area greater or equal to 0",
+            ));
+        }
+        Ok(())
+    }
+}
@@ -8,6 +8,57 @@ pub struct OutData {
     pub shear_force: Vec<(f64, f64)>,
     /// эпюр изгибающего момента (координата по х, значение)
     pub bending_moment: Vec<(f64, f64)>,
+    /// использование допустимых значений, выводится
+    /// если для судна заданы допустимые значения
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utilisation: Option<OutUtilisation>,
+    /// волновые нагрузки и суммарные эпюры, выводятся
+    /// если для судна заданы данные для их вычисления
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wave: Option<OutWave>,
+    /// посадка судна
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub equilibrium: Option<OutEquilibrium>,
+    /// поперечная остойчивость, выводится если для судна
+    /// задана кривая поперечного метацентрического радиуса
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stability: Option<OutStability>,
+    /// диаграммы статической и динамической остойчивости,
+    /// выводятся если для судна заданы пантокарены
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub righting_lever: Option<OutRightingLever>,
+    /// критерии остойчивости IS Code 2008, выводятся если для судна заданы пантокарены
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub criteria: Option<OutCriteria>,
+    /// критерий погоды, выводится если для судна заданы пантокарены и данные о парусности
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weather: Option<OutWeather>,
+    /// крен от смещения зерна, выводится если заданы пантокарены и в трюмах есть зерно
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grain: Option<OutGrain>,
+    /// сводка нагрузки судна по категориям грузов
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<OutSummary>,
+    /// промежуточные состояния на переходе, выводятся если в запросе задан расход запасов
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voyage: Option<OutVoyage>,
+    /// навалочные грузы в трюмах, выводятся если они есть в варианте загрузки
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bulk: Vec<OutBulk>,
+    /// массы штабелей контейнеров, выводятся если в варианте загрузки заданы контейнеры
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stowage: Option<OutStowage>,
+    /// нагрузка от обледенения, выводится если в варианте загрузки задано обледенение
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ice: Option<OutIce>,
+    /// посадка и прочность судна после затопления,
+    /// выводится если в запросе заданы затопленные отсеки
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub damage: Option<OutDamage>,
+    /// расхождения кривых элементов теоретического чертежа из данных
+    /// по судну с вычисленными по кривым погруженной площади шпангоутов
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hydrostatics_mismatch: Vec<OutMismatch>,
 }
 ///
 impl OutData {
@@ -17,5 +68,292 @@ impl OutData {
         serde_json::to_string(self).ok()
     }
 }
-
-
+/// Использование допустимых значений срезающих сил
+/// и изгибающих моментов для условия эксплуатации судна, %
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutUtilisation {
+    /// использование по срезающей силе (координата по х, значение)
+    pub shear_force: Vec<(f64, f64)>,
+    /// использование по изгибающему моменту (координата по х, значение)
+    pub bending_moment: Vec<(f64, f64)>,
+    /// максимальное использование по срезающей силе (координата по х, значение)
+    pub shear_force_max: (f64, f64),
+    /// максимальное использование по изгибающему моменту (координата по х, значение)
+    pub bending_moment_max: (f64, f64),
+    /// допустимые значения не превышены
+    pub pass: bool,
+}
+/// Волновые нагрузки по IACS UR S11 и огибающие суммарных
+/// (на тихой воде и волновых) эпюров, (координата по х, значение)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutWave {
+    /// волновой изгибающий момент на перегиб
+    pub bending_moment_hogging: Vec<(f64, f64)>,
+    /// волновой изгибающий момент на прогиб
+    pub bending_moment_sagging: Vec<(f64, f64)>,
+    /// положительная волновая срезающая сила
+    pub shear_force_positive: Vec<(f64, f64)>,
+    /// отрицательная волновая срезающая сила
+    pub shear_force_negative: Vec<(f64, f64)>,
+    /// наибольший суммарный изгибающий момент
+    pub total_bending_moment_max: Vec<(f64, f64)>,
+    /// наименьший суммарный изгибающий момент
+    pub total_bending_moment_min: Vec<(f64, f64)>,
+    /// наибольшая суммарная срезающая сила
+    pub total_shear_force_max: Vec<(f64, f64)>,
+    /// наименьшая суммарная срезающая сила
+    pub total_shear_force_min: Vec<(f64, f64)>,
+}
+/// Посадка судна, найденная итерационным расчетом
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutEquilibrium {
+    /// осадка на миделе
+    pub draught: f64,
+    /// осадка кормой
+    pub draught_stern: f64,
+    /// осадка носом
+    pub draught_bow: f64,
+    /// дифферент, разность осадок носом и кормой, положительный на нос
+    pub trim: f64,
+    /// дифферент, градусы
+    pub trim_angle: f64,
+    /// угол крена, градусы, выводится если заданы кривая поперечного
+    /// метацентрического радиуса или пантокарены
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heel: Option<f64>,
+    /// количество выполненных итераций
+    pub iterations: usize,
+    /// невязка массы вытесненной воды и массы судна
+    pub mass_residual: f64,
+    /// невязка отстояния центра величины и центра масс по длине
+    pub shift_residual: f64,
+}
+/// Поперечная остойчивость судна
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutStability {
+    /// аппликата поперечного метацентра
+    pub z_m: f64,
+    /// аппликата центра масс судна
+    pub z_g: f64,
+    /// поперечная метацентрическая высота без учета влияния свободной поверхности жидкости
+    pub h_0: f64,
+    /// поправка к поперечной метацентрической высоте на влияние свободной поверхности жидкости
+    pub delta_m_h: f64,
+    /// поперечная исправленная метацентрическая высота
+    pub h: f64,
+}
+/// Диаграммы статической и динамической остойчивости
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutRightingLever {
+    /// плечи статической остойчивости (угол крена, плечо)
+    pub values: Vec<(f64, f64)>,
+    /// плечи динамической остойчивости (угол крена, плечо)
+    pub dynamic: Vec<(f64, f64)>,
+    /// максимальное плечо статической остойчивости (угол крена, плечо)
+    pub max: (f64, f64),
+    /// угол заката диаграммы статической остойчивости
+    pub angle_vanishing: Option<f64>,
+}
+/// Результаты проверки критериев остойчивости
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutCriteria {
+    /// результаты проверки отдельных критериев
+    pub values: Vec<OutCriterion>,
+    /// все критерии выполнены, вариант загрузки допустим
+    pub pass: bool,
+}
+/// Результат проверки одного критерия остойчивости
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutCriterion {
+    /// наименование критерия
+    pub name: String,
+    /// фактическое значение, null если оно не вычисляется
+    pub actual: Option<f64>,
+    /// минимальное допустимое значение, null если оно не вычисляется
+    pub required: Option<f64>,
+    /// критерий выполнен
+    pub pass: bool,
+}
+/// Критерий погоды
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutWeather {
+    /// кренящее плечо от постоянного ветра
+    pub lever_1: f64,
+    /// кренящее плечо от порыва ветра
+    pub lever_2: f64,
+    /// угол крена от постоянного ветра, градусы
+    pub angle_0: f64,
+    /// амплитуда бортовой качки, градусы
+    pub angle_1: f64,
+    /// угол, ограничивающий площадь b, градусы
+    pub angle_2: f64,
+    /// площадь a, м·рад
+    pub area_a: f64,
+    /// площадь b, м·рад
+    pub area_b: f64,
+}
+/// Крен от смещения зерна
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutGrain {
+    /// суммарный кренящий момент от смещения зерна, т·м
+    pub moment: f64,
+    /// кренящее плечо при 0°
+    pub lever_0: f64,
+    /// кренящее плечо при 40°
+    pub lever_40: f64,
+    /// угол крена от смещения зерна, градусы
+    pub angle: f64,
+    /// угол, ограничивающий остаточную площадь, градусы
+    pub angle_limit: f64,
+    /// остаточная площадь, м·рад
+    pub area: f64,
+}
+/// Сводка нагрузки судна
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutSummary {
+    /// грузы судна
+    pub loads: Vec<OutLoad>,
+    /// итоги по категориям грузов
+    pub categories: Vec<OutCategory>,
+    /// дедвейт - масса всех грузов, кроме судна порожнем
+    pub deadweight: f64,
+    /// водоизмещение - масса всех грузов
+    pub displacement: f64,
+}
+/// Груз судна
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutLoad {
+    /// наименование груза
+    pub name: String,
+    /// категория груза
+    pub category: String,
+    /// масса груза
+    pub mass: f64,
+    /// центр масс груза (x, y, z)
+    pub center: (f64, f64, f64),
+}
+/// Итоги по категории грузов
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutCategory {
+    /// категория грузов
+    pub category: String,
+    /// масса грузов
+    pub mass: f64,
+    /// центр масс грузов (x, y, z)
+    pub center: (f64, f64, f64),
+    /// момент свободной поверхности жидкости (x - поперечный, y - продольный), т·м
+    pub moment_surface: (f64, f64),
+}
+/// Состояния судна на переходе от отхода до прихода
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutVoyage {
+    /// состояния судна, упорядоченные по времени
+    pub conditions: Vec<OutVoyageCondition>,
+    /// индекс наихудшего состояния в conditions
+    pub worst: usize,
+}
+/// Состояние судна на переходе
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutVoyageCondition {
+    /// время от отхода, ч
+    pub time: f64,
+    /// масса израсходованных запасов, т
+    pub consumed: f64,
+    /// результат расчета для состояния
+    pub data: OutData,
+}
+/// Навалочный груз в трюме
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutBulk {
+    /// объем груза
+    pub volume: f64,
+    /// уровень поверхности груза
+    pub level: f64,
+    /// центр масс груза (x, y, z)
+    pub center: (f64, f64, f64),
+    /// трюм заполнен
+    pub filled: bool,
+}
+/// Контейнеры по ячейкам грузового плана
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutStowage {
+    /// количество контейнеров
+    pub count: usize,
+    /// общая масса контейнеров
+    pub mass: f64,
+    /// штабели контейнеров
+    pub stacks: Vec<OutStack>,
+    /// допустимые массы всех штабелей не превышены
+    pub pass: bool,
+}
+/// Нагрузка от обледенения
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutIce {
+    /// масса льда на открытых палубах
+    pub deck: f64,
+    /// масса льда на площадях парусности
+    pub windage: f64,
+    /// общая масса льда
+    pub mass: f64,
+    /// центр масс льда (x, y, z)
+    pub center: (f64, f64, f64),
+}
+/// Масса штабеля контейнеров
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutStack {
+    /// номер бея
+    pub bay: usize,
+    /// номер ряда
+    pub row: usize,
+    /// масса контейнеров в штабеле
+    pub mass: f64,
+    /// допустимая масса штабеля, если задана
+    pub limit: Option<f64>,
+    /// допустимая масса не превышена
+    pub pass: bool,
+}
+/// Посадка и прочность судна после затопления отсеков
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutDamage {
+    /// суммарный теряемый объем затопленных отсеков
+    pub lost_volume: f64,
+    /// посадка судна после затопления
+    pub equilibrium: OutEquilibrium,
+    /// исправленная метацентрическая высота после затопления, если задана
+    /// кривая rad_trans, отрицательная - нет равновесия в прямом положении
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metacentric_height: Option<f64>,
+    /// массив значений срезывающих сил после затопления (координата по х, значение)
+    pub shear_force: Vec<(f64, f64)>,
+    /// массив значений изгибающих моментов после затопления (координата по х, значение)
+    pub bending_moment: Vec<(f64, f64)>,
+    /// использование допустимых значений после затопления
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utilisation: Option<OutUtilisation>,
+}
+/// Расхождение значения кривой из данных по судну
+/// с вычисленным по кривым погруженной площади шпангоутов
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutMismatch {
+    /// название кривой в данных по судну
+    pub curve: String,
+    /// объемное водоизмещение, ключ кривой
+    pub volume: f64,
+    /// значение из данных по судну
+    pub supplied: f64,
+    /// вычисленное значение
+    pub derived: f64,
+}
+/// Выходная структура данных при ошибке расчета
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutError {
+    /// описание ошибки
+    pub error: String,
+}
+///
+impl OutError {
+    ///
+    pub fn new(error: String) -> Self {
+        Self { error }
+    }
+}
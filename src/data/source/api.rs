@@ -0,0 +1,76 @@
+//! Источник данных по судну - сервер api
+use api_tools::client::{
+    api_query::{ApiQuery, ApiQueryKind, ApiQuerySql},
+    api_request::ApiRequest,
+};
+
+use crate::{
+    data::parse_input::{ParsedFramesData, ParsedLoadsData, ParsedShipData, ParsedTanksData},
+    error::Error,
+};
+
+use super::IDataSource;
+
+/// Источник данных по судну - сервер api.  
+/// Для каждого набора данных выполняется запрос
+/// `SELECT data FROM <table> WHERE project_name = '..' AND ship_name = '..';`,
+/// где table - ship, frames, loads или tanks. Сервер возвращает
+/// документ JSON в формате соответствующей структуры parse_input.
+pub struct ApiSource {
+    /// запрос к серверу api
+    request: ApiRequest,
+    /// имя базы данных
+    database: String,
+}
+///
+impl ApiSource {
+    /// Основной конструктор. Аргументы:
+    /// - address: адрес сервера api
+    /// - auth_token: токен авторизации
+    /// - database: имя базы данных
+    pub fn new(address: &str, auth_token: &str, database: &str) -> Self {
+        let query = ApiQuery::new(ApiQueryKind::Sql(ApiQuerySql::new(database, "")), false);
+        Self {
+            request: ApiRequest::new("parent", address, auth_token, query, false, false),
+            database: database.to_owned(),
+        }
+    }
+    /// Запрос документа JSON из таблицы для судна
+    fn fetch(&mut self, table: &str, project_name: &str, ship_name: &str) -> Result<String, Error> {
+        let sql = format!(
+            "SELECT data FROM {table} WHERE project_name = '{}' AND ship_name = '{}';",
+            project_name.replace('\'', "''"),
+            ship_name.replace('\'', "''"),
+        );
+        let query = ApiQuery::new(ApiQueryKind::Sql(ApiQuerySql::new(&self.database, &sql)), false);
+        let reply = self
+            .request
+            .fetch(&query, false)
+            .map_err(|err| Error::DataSource(format!("ApiSource request.fetch: {err}")))?;
+        let reply = String::from_utf8(reply)
+            .map_err(|err| Error::DataSource(format!("ApiSource String::from_utf8: {err}")))?;
+        Ok(reply.to_lowercase().trim().to_owned())
+    }
+}
+///
+impl IDataSource for ApiSource {
+    fn ship(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedShipData, Error> {
+        ParsedShipData::parse(&self.fetch("ship", project_name, ship_name)?)
+            .map_err(|err| Error::DataSource(format!("ParsedShipData::parse: {err}")))
+    }
+
+    fn frames(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedFramesData, Error> {
+        ParsedFramesData::parse(&self.fetch("frames", project_name, ship_name)?)
+            .map_err(|err| Error::DataSource(format!("ParsedFramesData::parse: {err}")))
+    }
+
+    fn loads(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedLoadsData, Error> {
+        ParsedLoadsData::parse(&self.fetch("loads", project_name, ship_name)?)
+            .map_err(|err| Error::DataSource(format!("ParsedLoadsData::parse: {err}")))
+    }
+
+    fn tanks(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedTanksData, Error> {
+        ParsedTanksData::parse(&self.fetch("tanks", project_name, ship_name)?)
+            .map_err(|err| Error::DataSource(format!("ParsedTanksData::parse: {err}")))
+    }
+}
@@ -0,0 +1,59 @@
+//! Источник данных по судну - каталог с файлами JSON
+use std::{fs, path::PathBuf};
+
+use crate::{
+    data::parse_input::{ParsedFramesData, ParsedLoadsData, ParsedShipData, ParsedTanksData},
+    error::Error,
+};
+
+use super::IDataSource;
+
+/// Источник данных по судну - каталог с файлами JSON.  
+/// Данные судна лежат в подкаталоге `<path>/<project_name>/<ship_name>/`
+/// в файлах ship.json, frames.json, loads.json и tanks.json,
+/// формат файлов соответствует структурам parse_input.
+pub struct FileSource {
+    /// корневой каталог с данными
+    path: PathBuf,
+}
+///
+impl FileSource {
+    /// Основной конструктор
+    /// - path: корневой каталог с данными
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+    /// Чтение файла с данными судна
+    fn read(&self, name: &str, project_name: &str, ship_name: &str) -> Result<String, Error> {
+        for value in [project_name, ship_name] {
+            if value.is_empty() || value == "." || value == ".." || value.contains(['/', '\\']) {
+                return Err(Error::DataSource(format!("FileSource invalid directory name: {value:?}")));
+            }
+        }
+        let path = self.path.join(project_name).join(ship_name).join(format!("{name}.json"));
+        fs::read_to_string(&path)
+            .map_err(|err| Error::DataSource(format!("FileSource read {}: {err}", path.display())))
+    }
+}
+///
+impl IDataSource for FileSource {
+    fn ship(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedShipData, Error> {
+        ParsedShipData::parse(&self.read("ship", project_name, ship_name)?)
+            .map_err(|err| Error::DataSource(format!("ParsedShipData::parse: {err}")))
+    }
+
+    fn frames(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedFramesData, Error> {
+        ParsedFramesData::parse(&self.read("frames", project_name, ship_name)?)
+            .map_err(|err| Error::DataSource(format!("ParsedFramesData::parse: {err}")))
+    }
+
+    fn loads(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedLoadsData, Error> {
+        ParsedLoadsData::parse(&self.read("loads", project_name, ship_name)?)
+            .map_err(|err| Error::DataSource(format!("ParsedLoadsData::parse: {err}")))
+    }
+
+    fn tanks(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedTanksData, Error> {
+        ParsedTanksData::parse(&self.read("tanks", project_name, ship_name)?)
+            .map_err(|err| Error::DataSource(format!("ParsedTanksData::parse: {err}")))
+    }
+}
@@ -0,0 +1,55 @@
+//! Источники данных по судну
+pub mod api;
+pub mod file;
+pub mod sqlite;
+
+use crate::error::Error;
+
+use super::parse_input::{ParsedFramesData, ParsedLoadsData, ParsedShipData, ParsedTanksData};
+
+/// Источник данных по судну: корпус, шпангоуты, грузы и цистерны.
+/// Данные выбираются по названию проекта и имени судна.
+pub trait IDataSource {
+    /// данные по корпусу судна
+    fn ship(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedShipData, Error>;
+    /// шпангоуты судна
+    fn frames(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedFramesData, Error>;
+    /// твердые грузы судна
+    fn loads(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedLoadsData, Error>;
+    /// цистерны судна
+    fn tanks(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedTanksData, Error>;
+}
+// заглушка для тестирования, каждый набор данных выдается только один раз
+#[doc(hidden)]
+pub struct FakeDataSource {
+    ship: Option<ParsedShipData>,
+    frames: Option<ParsedFramesData>,
+    loads: Option<ParsedLoadsData>,
+    tanks: Option<ParsedTanksData>,
+}
+#[doc(hidden)]
+impl FakeDataSource {
+    pub fn new(
+        ship: ParsedShipData,
+        frames: ParsedFramesData,
+        loads: ParsedLoadsData,
+        tanks: ParsedTanksData,
+    ) -> Self {
+        Self { ship: Some(ship), frames: Some(frames), loads: Some(loads), tanks: Some(tanks), }
+    }
+}
+#[doc(hidden)]
+impl IDataSource for FakeDataSource {
+    fn ship(&mut self, _: &str, _: &str) -> Result<ParsedShipData, Error> {
+        self.ship.take().ok_or(Error::DataSource("ship already fetched".to_owned()))
+    }
+    fn frames(&mut self, _: &str, _: &str) -> Result<ParsedFramesData, Error> {
+        self.frames.take().ok_or(Error::DataSource("frames already fetched".to_owned()))
+    }
+    fn loads(&mut self, _: &str, _: &str) -> Result<ParsedLoadsData, Error> {
+        self.loads.take().ok_or(Error::DataSource("loads already fetched".to_owned()))
+    }
+    fn tanks(&mut self, _: &str, _: &str) -> Result<ParsedTanksData, Error> {
+        self.tanks.take().ok_or(Error::DataSource("tanks already fetched".to_owned()))
+    }
+}
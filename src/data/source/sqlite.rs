@@ -0,0 +1,916 @@
+//! Источник данных по судну - локальная база данных SQLite
+use rusqlite::{params, Connection};
+
+use crate::{
+    data::parse_input::{
+        BulkData, CalibrationData, CurveFamily, FillData, FreeSurfaceData, LoadCategory, FrameData, GrainData, LimitsData, LoadSpaceData, ParsedFramesData, ParsedLoadsData, ParsedShipData,
+        ParsedTanksData, StrengthLimitsData, LightshipData, StowageData, IceData, IceAreaData, TankData, WaveData, WeatherData,
+    },
+    error::Error,
+};
+
+use super::IDataSource;
+
+/// Имя и категория груза по его идентификатору
+type LoadName = (i64, Option<String>, Option<LoadCategory>);
+
+/// Схема базы данных. Все таблицы содержат ключ судна
+/// (project_name, ship_name), кривые хранятся построчно
+/// по одной точке, порядок точек задается значением ключа кривой.
+pub const SCHEMA: &str = "
+-- корпус судна: длинна корпуса
+CREATE TABLE IF NOT EXISTS ship (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    ship_length REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name)
+);
+-- кривые корпуса по объемному водоизмещению:
+-- curve = 'center_waterline' | 'rad_long' | 'mean_draught' | 'rad_trans'
+CREATE TABLE IF NOT EXISTS ship_curve (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    curve TEXT NOT NULL,
+    key REAL NOT NULL,
+    value REAL NOT NULL
+);
+-- кривая отстояния центра величины погруженной части судна
+CREATE TABLE IF NOT EXISTS center_shift (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    volume REAL NOT NULL,
+    x REAL NOT NULL,
+    y REAL NOT NULL,
+    z REAL NOT NULL
+);
+-- пантокарены: плечо остойчивости формы kn по углу крена angle в градусах
+-- для объемного водоизмещения volume
+CREATE TABLE IF NOT EXISTS cross_curve (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    volume REAL NOT NULL,
+    angle REAL NOT NULL,
+    kn REAL NOT NULL
+);
+-- угол заливания в градусах для критериев остойчивости
+CREATE TABLE IF NOT EXISTS flooding_angle (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    angle REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name)
+);
+-- допустимые значения срезающих сил и изгибающих моментов по длине судна:
+-- condition = 'sea' | 'harbor',
+-- curve = 'sf_positive' | 'sf_negative' | 'bm_hogging' | 'bm_sagging'
+CREATE TABLE IF NOT EXISTS strength_limit (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    condition TEXT NOT NULL,
+    curve TEXT NOT NULL,
+    x REAL NOT NULL,
+    value REAL NOT NULL
+);
+-- данные для вычисления волновых нагрузок: ширина корпуса и коэффициент общей полноты
+CREATE TABLE IF NOT EXISTS wave (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    breadth REAL NOT NULL,
+    block_coefficient REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name)
+);
+-- коэффициенты распределения волновых нагрузок (x/L от кормы, значение),
+-- если не заданы - по правилам: factor = 'm' | 'f1' | 'f2'
+CREATE TABLE IF NOT EXISTS wave_distribution (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    factor TEXT NOT NULL,
+    x REAL NOT NULL,
+    value REAL NOT NULL
+);
+-- данные для проверки критерия погоды: ширина корпуса, коэффициент общей полноты,
+-- суммарная площадь скуловых килей, hard_chine = 1 для судна с острой скулой
+CREATE TABLE IF NOT EXISTS weather (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    breadth REAL NOT NULL,
+    block_coefficient REAL NOT NULL,
+    bilge_keel_area REAL NOT NULL DEFAULT 0,
+    hard_chine INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (project_name, ship_name)
+);
+-- парусность по средней осадке draught: площадь area и возвышение lever
+-- центра парусности над центром площади проекции подводной части на ДП
+CREATE TABLE IF NOT EXISTS windage (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    draught REAL NOT NULL,
+    area REAL NOT NULL,
+    lever REAL NOT NULL
+);
+-- кривые погружаемой площади шпангоутов (draught, area)
+CREATE TABLE IF NOT EXISTS frame (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    frame_index INTEGER NOT NULL,
+    draught REAL NOT NULL,
+    area REAL NOT NULL
+);
+-- масса судна порожнем и ее центр масс
+CREATE TABLE IF NOT EXISTS lightship (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    mass REAL NOT NULL,
+    center_x REAL NOT NULL,
+    center_y REAL NOT NULL,
+    center_z REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name)
+);
+-- относительная интенсивность распределения массы судна порожнем по длине,
+-- если не задана - масса распределяется по трапеции
+CREATE TABLE IF NOT EXISTS lightship_distribution (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    x REAL NOT NULL,
+    value REAL NOT NULL
+);
+-- грузовой план контейнеров: беи - границы контейнера по длине (x1, x2)
+CREATE TABLE IF NOT EXISTS container_bay (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    bay INTEGER NOT NULL,
+    x1 REAL NOT NULL,
+    x2 REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name, bay)
+);
+-- ряды - отстояние центра масс контейнера по ширине от ДП
+CREATE TABLE IF NOT EXISTS container_row (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    row INTEGER NOT NULL,
+    y REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name, row)
+);
+-- ярусы - аппликата центра масс контейнера
+CREATE TABLE IF NOT EXISTS container_tier (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    tier INTEGER NOT NULL,
+    z REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name, tier)
+);
+-- допустимые массы штабелей контейнеров
+CREATE TABLE IF NOT EXISTS container_stack_limit (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    bay INTEGER NOT NULL,
+    row INTEGER NOT NULL,
+    mass REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name, bay, row)
+);
+-- площади обледенения: kind - 'deck' (горизонтальная проекция открытой палубы) или
+-- 'windage' (площадь парусности), площадь area, м^2, границы по длине (x1, x2), центр площади
+CREATE TABLE IF NOT EXISTS ice_area (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    area_id INTEGER NOT NULL,
+    area REAL NOT NULL,
+    x1 REAL NOT NULL,
+    x2 REAL NOT NULL,
+    x REAL NOT NULL,
+    y REAL NOT NULL,
+    z REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name, kind, area_id)
+);
+-- твердые грузы: масса, границы (x1, x2, y1, y2), центр масс
+CREATE TABLE IF NOT EXISTS load_space (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    load_id INTEGER NOT NULL,
+    mass REAL NOT NULL,
+    x1 REAL NOT NULL,
+    x2 REAL NOT NULL,
+    y1 REAL NOT NULL,
+    y2 REAL NOT NULL,
+    center_x REAL NOT NULL,
+    center_y REAL NOT NULL,
+    center_z REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name, load_id)
+);
+-- зерно навалом в трюмах: удельный погрузочный объем, объем зерна,
+-- вместимость трюма, границы (x1, x2, y1, y2), центр масс
+CREATE TABLE IF NOT EXISTS grain (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    load_id INTEGER NOT NULL,
+    stowage_factor REAL NOT NULL,
+    volume REAL NOT NULL,
+    capacity REAL NOT NULL,
+    x1 REAL NOT NULL,
+    x2 REAL NOT NULL,
+    y1 REAL NOT NULL,
+    y2 REAL NOT NULL,
+    center_x REAL NOT NULL,
+    center_y REAL NOT NULL,
+    center_z REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name, load_id)
+);
+-- кривые объемного кренящего момента зерна по объему зерна в трюме
+CREATE TABLE IF NOT EXISTS grain_moment (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    load_id INTEGER NOT NULL,
+    volume REAL NOT NULL,
+    moment REAL NOT NULL
+);
+-- навалочные грузы в трюмах: масса, удельный погрузочный объем stowage_factor
+-- или плотность density (задается одно из двух), границы трюма (x1, x2, y1, y2)
+CREATE TABLE IF NOT EXISTS bulk (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    load_id INTEGER NOT NULL,
+    mass REAL NOT NULL,
+    stowage_factor REAL,
+    density REAL,
+    x1 REAL NOT NULL,
+    x2 REAL NOT NULL,
+    y1 REAL NOT NULL,
+    y2 REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name, load_id)
+);
+-- кривые координат центра масс и уровня поверхности навалочного груза по объему груза
+CREATE TABLE IF NOT EXISTS bulk_hold (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    load_id INTEGER NOT NULL,
+    volume REAL NOT NULL,
+    x REAL NOT NULL,
+    y REAL NOT NULL,
+    z REAL NOT NULL,
+    level REAL NOT NULL
+);
+-- наименования и категории грузов: kind - вид груза 'load_space', 'grain', 'bulk'
+-- или 'tank', load_id - идентификатор груза (tank_id для цистерн), category - категория
+-- 'cargo', 'ballast', 'fuel', 'lubricating_oil', 'fresh_water', 'stores', 'crew' или 'other'
+CREATE TABLE IF NOT EXISTS load_name (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    load_id INTEGER NOT NULL,
+    name TEXT,
+    category TEXT,
+    PRIMARY KEY (project_name, ship_name, kind, load_id)
+);
+-- цистерны: плотность и объем жидкости, границы (x1, x2, y1, y2)
+CREATE TABLE IF NOT EXISTS tank (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    tank_id INTEGER NOT NULL,
+    density REAL NOT NULL,
+    volume REAL NOT NULL,
+    x1 REAL NOT NULL,
+    x2 REAL NOT NULL,
+    y1 REAL NOT NULL,
+    y2 REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name, tank_id)
+);
+-- кривая координат центра объема жидкости в цистерне
+CREATE TABLE IF NOT EXISTS tank_center (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    tank_id INTEGER NOT NULL,
+    volume REAL NOT NULL,
+    x REAL NOT NULL,
+    y REAL NOT NULL,
+    z REAL NOT NULL
+);
+-- кривая момента инерции площади свободной поверхности жидкости
+-- (x - поперечный, y - продольный)
+CREATE TABLE IF NOT EXISTS tank_free_surf_inertia (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    tank_id INTEGER NOT NULL,
+    volume REAL NOT NULL,
+    x REAL NOT NULL,
+    y REAL NOT NULL
+);
+-- заполнение цистерны, заменяет объем из tank: kind - вид заполнения
+-- 'sounding', 'ullage', 'volume', 'percent' или 'mass', дифферент trim, м, и крен heel,
+-- градусы, при замере уровня
+CREATE TABLE IF NOT EXISTS tank_fill (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    tank_id INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    value REAL NOT NULL,
+    trim REAL NOT NULL DEFAULT 0,
+    heel REAL NOT NULL DEFAULT 0,
+    PRIMARY KEY (project_name, ship_name, tank_id)
+);
+-- учет момента свободной поверхности жидкости в цистерне: kind - 'actual', 'max', 'fixed'
+-- или 'none', заданный момент x - поперечный, y - продольный, т·м, только для 'fixed'
+CREATE TABLE IF NOT EXISTS tank_free_surface (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    tank_id INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    x REAL,
+    y REAL,
+    PRIMARY KEY (project_name, ship_name, tank_id)
+);
+-- калибровочная таблица цистерны: высота, от которой отсчитывается пустота
+CREATE TABLE IF NOT EXISTS tank_calibration (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    tank_id INTEGER NOT NULL,
+    height REAL NOT NULL,
+    PRIMARY KEY (project_name, ship_name, tank_id)
+);
+-- объем жидкости в цистерне по уровню
+CREATE TABLE IF NOT EXISTS tank_sounding (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    tank_id INTEGER NOT NULL,
+    sounding REAL NOT NULL,
+    volume REAL NOT NULL
+);
+-- поправки уровня жидкости в цистерне: kind - 'trim' (key - дифферент, м)
+-- или 'heel' (key - крен, градусы)
+CREATE TABLE IF NOT EXISTS tank_sounding_correction (
+    project_name TEXT NOT NULL,
+    ship_name TEXT NOT NULL,
+    tank_id INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    key REAL NOT NULL,
+    sounding REAL NOT NULL,
+    correction REAL NOT NULL
+);
+";
+
+/// Источник данных по судну - локальная база данных SQLite,
+/// схема базы данных приведена в [SCHEMA]
+pub struct SqliteSource {
+    /// соединение с базой данных
+    connection: Connection,
+}
+///
+impl SqliteSource {
+    /// Основной конструктор
+    /// - connection: соединение с базой данных
+    pub fn new(connection: Connection) -> Self {
+        Self { connection }
+    }
+    /// Открытие файла базы данных
+    /// - path: путь к файлу базы данных
+    pub fn open(path: &str) -> Result<Self, Error> {
+        let connection = Connection::open(path)
+            .map_err(|err| Error::DataSource(format!("SqliteSource open {path}: {err}")))?;
+        Ok(Self::new(connection))
+    }
+    /// Выборка строк запроса для судна
+    fn select<T>(
+        &self,
+        sql: &str,
+        project_name: &str,
+        ship_name: &str,
+        row: impl FnMut(&rusqlite::Row<'_>) -> rusqlite::Result<T>,
+    ) -> Result<Vec<T>, Error> {
+        let map_err = |err: rusqlite::Error| Error::DataSource(format!("SqliteSource {sql}: {err}"));
+        let mut statement = self.connection.prepare(sql).map_err(map_err)?;
+        let rows = statement
+            .query_map(params![project_name, ship_name], row)
+            .map_err(map_err)?;
+        rows.collect::<rusqlite::Result<Vec<T>>>().map_err(map_err)
+    }
+    /// Кривая корпуса по ее имени
+    fn ship_curve(&self, curve: &str, project_name: &str, ship_name: &str) -> Result<Vec<(f64, f64)>, Error> {
+        self.select(
+            &format!("SELECT key, value FROM ship_curve WHERE project_name = ?1 AND ship_name = ?2 AND curve = '{curve}' ORDER BY key"),
+            project_name,
+            ship_name,
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+    }
+    /// Пантокарены, None если для судна они не заданы
+    fn cross_curves(&self, project_name: &str, ship_name: &str) -> Result<Option<CurveFamily>, Error> {
+        let rows = self.select(
+            "SELECT volume, angle, kn FROM cross_curve WHERE project_name = ?1 AND ship_name = ?2 ORDER BY volume, angle",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, f64>(0)?, row.get::<_, f64>(1)?, row.get::<_, f64>(2)?)),
+        )?;
+        let mut curves: CurveFamily = Vec::new();
+        for (volume, angle, kn) in rows {
+            match curves.last_mut() {
+                Some(curve) if curve.0 == volume => curve.1.push((angle, kn)),
+                _ => curves.push((volume, vec![(angle, kn)])),
+            }
+        }
+        Ok(Some(curves).filter(|curves| !curves.is_empty()))
+    }
+    /// Допустимые значения для условия эксплуатации
+    fn limits(&self, condition: &str, project_name: &str, ship_name: &str) -> Result<LimitsData, Error> {
+        let curve = |curve: &str| {
+            self.select(
+                &format!("SELECT x, value FROM strength_limit WHERE project_name = ?1 AND ship_name = ?2 AND condition = '{condition}' AND curve = '{curve}' ORDER BY x"),
+                project_name,
+                ship_name,
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+        };
+        Ok(LimitsData {
+            sf_positive: curve("sf_positive")?,
+            sf_negative: curve("sf_negative")?,
+            bm_hogging: curve("bm_hogging")?,
+            bm_sagging: curve("bm_sagging")?,
+        })
+    }
+    /// Допустимые значения срезающих сил и изгибающих моментов,
+    /// None если для судна они не заданы
+    fn strength_limits(&self, project_name: &str, ship_name: &str) -> Result<Option<StrengthLimitsData>, Error> {
+        let count = self
+            .select(
+                "SELECT COUNT(*) FROM strength_limit WHERE project_name = ?1 AND ship_name = ?2",
+                project_name,
+                ship_name,
+                |row| row.get::<_, i64>(0),
+            )?
+            .pop()
+            .unwrap_or(0);
+        if count == 0 {
+            return Ok(None);
+        }
+        Ok(Some(StrengthLimitsData {
+            sea: self.limits("sea", project_name, ship_name)?,
+            harbor: self.limits("harbor", project_name, ship_name)?,
+        }))
+    }
+    /// Данные для вычисления волновых нагрузок,
+    /// None если для судна они не заданы
+    fn wave(&self, project_name: &str, ship_name: &str) -> Result<Option<WaveData>, Error> {
+        let Some((breadth, block_coefficient)) = self
+            .select(
+                "SELECT breadth, block_coefficient FROM wave WHERE project_name = ?1 AND ship_name = ?2",
+                project_name,
+                ship_name,
+                |row| Ok((row.get::<_, f64>(0)?, row.get::<_, f64>(1)?)),
+            )?
+            .pop()
+        else {
+            return Ok(None);
+        };
+        let factor = |factor: &str| -> Result<Option<Vec<(f64, f64)>>, Error> {
+            let curve = self.select(
+                &format!("SELECT x, value FROM wave_distribution WHERE project_name = ?1 AND ship_name = ?2 AND factor = '{factor}' ORDER BY x"),
+                project_name,
+                ship_name,
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )?;
+            Ok(if curve.is_empty() { None } else { Some(curve) })
+        };
+        Ok(Some(WaveData {
+            breadth,
+            block_coefficient,
+            m: factor("m")?,
+            f1: factor("f1")?,
+            f2: factor("f2")?,
+        }))
+    }
+    /// Наименования и категории грузов одного вида (идентификатор, наименование, категория)
+    /// - kind: вид груза
+    fn load_names(
+        &self,
+        kind: &str,
+        project_name: &str,
+        ship_name: &str,
+    ) -> Result<Vec<LoadName>, Error> {
+        self.select(
+            &format!("SELECT load_id, name, category FROM load_name WHERE project_name = ?1 AND ship_name = ?2 AND kind = '{kind}'"),
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, Option<String>>(1)?, row.get::<_, Option<String>>(2)?)),
+        )?
+        .into_iter()
+        .map(|(id, name, category)| {
+            let category = category
+                .map(|category| {
+                    serde_json::from_value(serde_json::Value::String(category.clone()))
+                        .map_err(|err| Error::DataSource(format!("load_name category {category}: {err}")))
+                })
+                .transpose()?;
+            Ok((id, name, category))
+        })
+        .collect()
+    }
+    /// Наименование и категория груза по идентификатору
+    fn load_name(
+        names: &[(i64, Option<String>, Option<LoadCategory>)],
+        id: i64,
+    ) -> (Option<String>, Option<LoadCategory>) {
+        names
+            .iter()
+            .find(|v| v.0 == id)
+            .map(|v| (v.1.clone(), v.2))
+            .unwrap_or((None, None))
+    }
+    /// Масса судна порожнем, None если для судна она не задана
+    fn lightship(&self, project_name: &str, ship_name: &str) -> Result<Option<LightshipData>, Error> {
+        let Some((mass, center)) = self
+            .select(
+                "SELECT mass, center_x, center_y, center_z FROM lightship WHERE project_name = ?1 AND ship_name = ?2",
+                project_name,
+                ship_name,
+                |row| Ok((row.get::<_, f64>(0)?, (row.get(1)?, row.get(2)?, row.get(3)?))),
+            )?
+            .pop()
+        else {
+            return Ok(None);
+        };
+        let distribution = self.select(
+            "SELECT x, value FROM lightship_distribution WHERE project_name = ?1 AND ship_name = ?2 ORDER BY x",
+            project_name,
+            ship_name,
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        Ok(Some(LightshipData {
+            mass,
+            center,
+            distribution: if distribution.is_empty() { None } else { Some(distribution) },
+        }))
+    }
+    /// Грузовой план контейнеров,
+    /// None если для судна он не задан
+    fn stowage(&self, project_name: &str, ship_name: &str) -> Result<Option<StowageData>, Error> {
+        let bays = self.select(
+            "SELECT bay, x1, x2 FROM container_bay WHERE project_name = ?1 AND ship_name = ?2 ORDER BY bay",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)? as usize, row.get(1)?, row.get(2)?)),
+        )?;
+        if bays.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(StowageData {
+            bays,
+            rows: self.select(
+                "SELECT row, y FROM container_row WHERE project_name = ?1 AND ship_name = ?2 ORDER BY row",
+                project_name,
+                ship_name,
+                |row| Ok((row.get::<_, i64>(0)? as usize, row.get(1)?)),
+            )?,
+            tiers: self.select(
+                "SELECT tier, z FROM container_tier WHERE project_name = ?1 AND ship_name = ?2 ORDER BY tier",
+                project_name,
+                ship_name,
+                |row| Ok((row.get::<_, i64>(0)? as usize, row.get(1)?)),
+            )?,
+            stack_limits: self.select(
+                "SELECT bay, row, mass FROM container_stack_limit WHERE project_name = ?1 AND ship_name = ?2 ORDER BY bay, row",
+                project_name,
+                ship_name,
+                |row| Ok((row.get::<_, i64>(0)? as usize, row.get::<_, i64>(1)? as usize, row.get(2)?)),
+            )?,
+        }))
+    }
+    /// Открытые палубы и площади парусности для расчета обледенения,
+    /// None если для судна они не заданы
+    fn ice(&self, project_name: &str, ship_name: &str) -> Result<Option<IceData>, Error> {
+        let areas = self.select(
+            "SELECT kind, area, x1, x2, x, y, z FROM ice_area WHERE project_name = ?1 AND ship_name = ?2 ORDER BY kind, area_id",
+            project_name,
+            ship_name,
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    IceAreaData {
+                        area: row.get(1)?,
+                        bound: (row.get(2)?, row.get(3)?),
+                        center: (row.get(4)?, row.get(5)?, row.get(6)?),
+                    },
+                ))
+            },
+        )?;
+        if areas.is_empty() {
+            return Ok(None);
+        }
+        if let Some((kind, _)) = areas.iter().find(|v| v.0 != "deck" && v.0 != "windage") {
+            return Err(Error::DataSource(format!("ice_area kind {kind} must be 'deck' or 'windage'")));
+        }
+        let areas = |kind: &str| areas.iter().filter(|v| v.0 == kind).map(|v| v.1.clone()).collect();
+        Ok(Some(IceData {
+            deck: areas("deck"),
+            windage: areas("windage"),
+        }))
+    }
+    /// Данные для проверки критерия погоды,
+    /// None если для судна они не заданы
+    fn weather(&self, project_name: &str, ship_name: &str) -> Result<Option<WeatherData>, Error> {
+        let Some((breadth, block_coefficient, bilge_keel_area, hard_chine)) = self
+            .select(
+                "SELECT breadth, block_coefficient, bilge_keel_area, hard_chine FROM weather WHERE project_name = ?1 AND ship_name = ?2",
+                project_name,
+                ship_name,
+                |row| Ok((row.get::<_, f64>(0)?, row.get::<_, f64>(1)?, row.get::<_, f64>(2)?, row.get::<_, i64>(3)? != 0)),
+            )?
+            .pop()
+        else {
+            return Ok(None);
+        };
+        Ok(Some(WeatherData {
+            breadth,
+            block_coefficient,
+            bilge_keel_area,
+            hard_chine,
+            windage: self.select(
+                "SELECT draught, area, lever FROM windage WHERE project_name = ?1 AND ship_name = ?2 ORDER BY draught",
+                project_name,
+                ship_name,
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )?,
+        }))
+    }
+}
+///
+impl IDataSource for SqliteSource {
+    fn ship(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedShipData, Error> {
+        let ship_length = self
+            .select(
+                "SELECT ship_length FROM ship WHERE project_name = ?1 AND ship_name = ?2",
+                project_name,
+                ship_name,
+                |row| row.get::<_, f64>(0),
+            )?
+            .pop()
+            .ok_or(Error::DataSource(format!("SqliteSource ship not found: {project_name} {ship_name}")))?;
+        ParsedShipData {
+            ship_length,
+            center_waterline: self.ship_curve("center_waterline", project_name, ship_name)?,
+            rad_long: self.ship_curve("rad_long", project_name, ship_name)?,
+            mean_draught: self.ship_curve("mean_draught", project_name, ship_name)?,
+            center_shift: self.select(
+                "SELECT volume, x, y, z FROM center_shift WHERE project_name = ?1 AND ship_name = ?2 ORDER BY volume",
+                project_name,
+                ship_name,
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )?,
+            rad_trans: Some(self.ship_curve("rad_trans", project_name, ship_name)?)
+                .filter(|curve| !curve.is_empty()),
+            cross_curves: self.cross_curves(project_name, ship_name)?,
+            flooding_angle: self
+                .select(
+                    "SELECT angle FROM flooding_angle WHERE project_name = ?1 AND ship_name = ?2",
+                    project_name,
+                    ship_name,
+                    |row| row.get::<_, f64>(0),
+                )?
+                .pop(),
+            strength_limits: self.strength_limits(project_name, ship_name)?,
+            wave: self.wave(project_name, ship_name)?,
+            weather: self.weather(project_name, ship_name)?,
+            lightship: self.lightship(project_name, ship_name)?,
+            stowage: self.stowage(project_name, ship_name)?,
+            ice: self.ice(project_name, ship_name)?,
+        }
+        .check()
+        .map_err(|err| Error::DataSource(format!("ParsedShipData::check: {err}")))
+    }
+
+    fn frames(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedFramesData, Error> {
+        let rows = self.select(
+            "SELECT frame_index, draught, area FROM frame WHERE project_name = ?1 AND ship_name = ?2 ORDER BY frame_index, draught",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)? as usize, row.get::<_, f64>(1)?, row.get::<_, f64>(2)?)),
+        )?;
+        let mut frames: Vec<FrameData> = Vec::new();
+        for (index, draught, area) in rows {
+            match frames.last_mut() {
+                Some(frame) if frame.index == index => frame.immersion_area.push((draught, area)),
+                _ => frames.push(FrameData { index, immersion_area: vec![(draught, area)] }),
+            }
+        }
+        ParsedFramesData { frames }
+            .check()
+            .map_err(|err| Error::DataSource(format!("ParsedFramesData::check: {err}")))
+    }
+
+    fn loads(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedLoadsData, Error> {
+        let grain = self.select(
+            "SELECT load_id, stowage_factor, volume, capacity, x1, x2, y1, y2, center_x, center_y, center_z FROM grain WHERE project_name = ?1 AND ship_name = ?2 ORDER BY load_id",
+            project_name,
+            ship_name,
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    GrainData {
+                        stowage_factor: row.get(1)?,
+                        volume: row.get(2)?,
+                        capacity: row.get(3)?,
+                        bound: (row.get(4)?, row.get(5)?, row.get(6)?, row.get(7)?),
+                        center: (row.get(8)?, row.get(9)?, row.get(10)?),
+                        moment: Vec::new(),
+                        name: None,
+                        category: None,
+                    },
+                ))
+            },
+        )?;
+        let moments = self.select(
+            "SELECT load_id, volume, moment FROM grain_moment WHERE project_name = ?1 AND ship_name = ?2 ORDER BY load_id, volume",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)?, (row.get::<_, f64>(1)?, row.get::<_, f64>(2)?))),
+        )?;
+        let bulk = self.select(
+            "SELECT load_id, mass, stowage_factor, density, x1, x2, y1, y2 FROM bulk WHERE project_name = ?1 AND ship_name = ?2 ORDER BY load_id",
+            project_name,
+            ship_name,
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    BulkData {
+                        mass: row.get(1)?,
+                        stowage_factor: row.get(2)?,
+                        density: row.get(3)?,
+                        bound: (row.get(4)?, row.get(5)?, row.get(6)?, row.get(7)?),
+                        center: Vec::new(),
+                        level: Vec::new(),
+                        name: None,
+                        category: None,
+                    },
+                ))
+            },
+        )?;
+        let holds = self.select(
+            "SELECT load_id, volume, x, y, z, level FROM bulk_hold WHERE project_name = ?1 AND ship_name = ?2 ORDER BY load_id, volume",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)?, (row.get::<_, f64>(1)?, row.get(2)?, row.get(3)?, row.get(4)?), row.get::<_, f64>(5)?)),
+        )?;
+        let load_space = self.select(
+            "SELECT load_id, mass, x1, x2, y1, y2, center_x, center_y, center_z FROM load_space WHERE project_name = ?1 AND ship_name = ?2 ORDER BY load_id",
+            project_name,
+            ship_name,
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    LoadSpaceData {
+                        mass: row.get(1)?,
+                        bound: (row.get(2)?, row.get(3)?, row.get(4)?, row.get(5)?),
+                        center: (row.get(6)?, row.get(7)?, row.get(8)?),
+                        name: None,
+                        category: None,
+                    },
+                ))
+            },
+        )?;
+        let (space_names, grain_names, bulk_names) = (
+            self.load_names("load_space", project_name, ship_name)?,
+            self.load_names("grain", project_name, ship_name)?,
+            self.load_names("bulk", project_name, ship_name)?,
+        );
+        ParsedLoadsData {
+            load_space: load_space
+                .into_iter()
+                .map(|(id, mut space)| {
+                    (space.name, space.category) = Self::load_name(&space_names, id);
+                    space
+                })
+                .collect(),
+            grain: grain
+                .into_iter()
+                .map(|(id, mut grain)| {
+                    grain.moment = moments.iter().filter(|v| v.0 == id).map(|v| v.1).collect();
+                    (grain.name, grain.category) = Self::load_name(&grain_names, id);
+                    grain
+                })
+                .collect(),
+            bulk: bulk
+                .into_iter()
+                .map(|(id, mut bulk)| {
+                    bulk.center = holds.iter().filter(|v| v.0 == id).map(|v| v.1).collect();
+                    bulk.level = holds.iter().filter(|v| v.0 == id).map(|v| (v.1 .0, v.2)).collect();
+                    (bulk.name, bulk.category) = Self::load_name(&bulk_names, id);
+                    bulk
+                })
+                .collect(),
+        }
+        .check()
+        .map_err(|err| Error::DataSource(format!("ParsedLoadsData::check: {err}")))
+    }
+
+    fn tanks(&mut self, project_name: &str, ship_name: &str) -> Result<ParsedTanksData, Error> {
+        let tanks = self.select(
+            "SELECT tank_id, density, volume, x1, x2, y1, y2 FROM tank WHERE project_name = ?1 AND ship_name = ?2 ORDER BY tank_id",
+            project_name,
+            ship_name,
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    TankData {
+                        density: row.get(1)?,
+                        volume: Some(row.get(2)?),
+                        fill: None,
+                        calibration: None,
+                        bound: (row.get(3)?, row.get(4)?, row.get(5)?, row.get(6)?),
+                        center: Vec::new(),
+                        free_surf_inertia: Vec::new(),
+                        free_surface: None,
+                        name: None,
+                        category: None,
+                    },
+                ))
+            },
+        )?;
+        let centers = self.select(
+            "SELECT tank_id, volume, x, y, z FROM tank_center WHERE project_name = ?1 AND ship_name = ?2 ORDER BY tank_id, volume",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)?, (row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?))),
+        )?;
+        let inertia = self.select(
+            "SELECT tank_id, volume, x, y FROM tank_free_surf_inertia WHERE project_name = ?1 AND ship_name = ?2 ORDER BY tank_id, volume",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)?, (row.get(1)?, row.get(2)?, row.get(3)?))),
+        )?;
+        let fills = self.select(
+            "SELECT tank_id, kind, value, trim, heel FROM tank_fill WHERE project_name = ?1 AND ship_name = ?2",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
+        )?;
+        let free_surfaces = self.select(
+            "SELECT tank_id, kind, x, y FROM tank_free_surface WHERE project_name = ?1 AND ship_name = ?2",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?, row.get::<_, Option<f64>>(2)?, row.get::<_, Option<f64>>(3)?)),
+        )?;
+        let calibrations = self.select(
+            "SELECT tank_id, height FROM tank_calibration WHERE project_name = ?1 AND ship_name = ?2",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, f64>(1)?)),
+        )?;
+        let soundings = self.select(
+            "SELECT tank_id, sounding, volume FROM tank_sounding WHERE project_name = ?1 AND ship_name = ?2 ORDER BY tank_id, sounding",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)?, (row.get(1)?, row.get(2)?))),
+        )?;
+        let corrections = self.select(
+            "SELECT tank_id, kind, key, sounding, correction FROM tank_sounding_correction WHERE project_name = ?1 AND ship_name = ?2 ORDER BY tank_id, kind, key, sounding",
+            project_name,
+            ship_name,
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?, row.get::<_, f64>(2)?, (row.get(3)?, row.get(4)?))),
+        )?;
+        // поправки уровня одного вида, сгруппированные по дифференту или крену
+        let correction = |id: i64, kind: &str| -> Option<CurveFamily> {
+            let mut result: CurveFamily = Vec::new();
+            for (_, _, key, point) in corrections.iter().filter(|v| v.0 == id && v.1 == kind) {
+                match result.last_mut() {
+                    Some((last, curve)) if last == key => curve.push(*point),
+                    _ => result.push((*key, vec![*point])),
+                }
+            }
+            if result.is_empty() { None } else { Some(result) }
+        };
+        let names = self.load_names("tank", project_name, ship_name)?;
+        let tanks = tanks
+            .into_iter()
+            .map(|(id, mut tank)| {
+                tank.center = centers.iter().filter(|v| v.0 == id).map(|v| v.1).collect();
+                tank.free_surf_inertia = inertia.iter().filter(|v| v.0 == id).map(|v| v.1).collect();
+                (tank.name, tank.category) = Self::load_name(&names, id);
+                if let Some((_, kind, value, trim, heel)) = fills.iter().find(|v| v.0 == id) {
+                    tank.fill = Some(FillData {
+                        kind: serde_json::from_value(serde_json::Value::String(kind.clone()))
+                            .map_err(|err| Error::DataSource(format!("tank_fill kind {kind}: {err}")))?,
+                        value: *value,
+                        trim: *trim,
+                        heel: *heel,
+                    });
+                }
+                if let Some((_, kind, x, y)) = free_surfaces.iter().find(|v| v.0 == id) {
+                    tank.free_surface = Some(FreeSurfaceData {
+                        kind: serde_json::from_value(serde_json::Value::String(kind.clone()))
+                            .map_err(|err| Error::DataSource(format!("tank_free_surface kind {kind}: {err}")))?,
+                        moment: x.zip(*y),
+                    });
+                }
+                if let Some((_, height)) = calibrations.iter().find(|v| v.0 == id) {
+                    tank.calibration = Some(CalibrationData {
+                        height: *height,
+                        volume: soundings.iter().filter(|v| v.0 == id).map(|v| v.1).collect(),
+                        trim: correction(id, "trim"),
+                        heel: correction(id, "heel"),
+                    });
+                }
+                Ok(tank)
+            })
+            .collect::<Result<_, Error>>()?;
+        ParsedTanksData { tanks }
+            .check()
+            .map_err(|err| Error::DataSource(format!("ParsedTanksData::check: {err}")))
+    }
+}
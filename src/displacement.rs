@@ -1,8 +1,9 @@
 //! Водоизмещение судна
-use crate::{frame::Frame, math::bound::Bound};
+use crate::{error::Error, flooding::Compartment, frame::Frame, math::bound::Bound};
 
 /// Водоизмещение судна. Вычисляет водоизмещение диапазона по  
-/// интерполированным значениям погруженной площади шпангоутов.
+/// интерполированным значениям погруженной площади шпангоутов
+/// за вычетом теряемого объема затопленных отсеков.
 pub struct Displacement {
     /// массив шпангоутов
     frames: Vec<Frame>,
@@ -10,38 +11,56 @@ pub struct Displacement {
     ship_length: f64,
     /// шаг шпангоутов
     vec_step: f64,
+    /// затопленные отсеки
+    flooded: Vec<Compartment>,
 }
 
 impl Displacement {
-    ///
-    pub fn new(frames: Vec<Frame>, ship_length: f64) -> Self {
-        assert!(frames.len() > 0, "frames.len() {} > 0", frames.len() );
-        assert!(ship_length > 0., "ship_length {ship_length} > 0.");
-        Self { vec_step: ship_length/(frames.len() as f64 - 1.), frames, ship_length  }
+    /// Основной конструктор для неповрежденного судна. Аргументы:
+    /// - frames: массив шпангоутов
+    /// - ship_length: длинна судна
+    pub fn new(frames: Vec<Frame>, ship_length: f64) -> Result<Self, Error> {
+        Self::new_flooded(frames, ship_length, Vec::new())
+    }
+    /// Конструктор для поврежденного судна. Аргументы:
+    /// - frames: массив шпангоутов
+    /// - ship_length: длинна судна
+    /// - flooded: затопленные отсеки
+    pub fn new_flooded(frames: Vec<Frame>, ship_length: f64, flooded: Vec<Compartment>) -> Result<Self, Error> {
+        if frames.len() <= 1 {
+            return Err(Error::Displacement(format!("frames.len() {} must be greater than 1", frames.len())));
+        }
+        if ship_length <= 0. {
+            return Err(Error::Displacement(format!("ship_length {ship_length} must be greater than 0")));
+        }
+        Ok(Self { vec_step: ship_length/(frames.len() as f64 - 1.), frames, ship_length, flooded })
     }
     /// Погруженный объем шпации.
     /// - bound: диапазон корпуса в длинну, для которого считается водоизмещение
     /// - draft: средняя осадка корпуса в диапазоне
-    pub fn value(&self, bound: Bound, draft: f64) -> f64 {
-        let area_start = self.area(bound.start(), draft);
-        let area_end = self.area(bound.end(), draft);
-        let result = bound.length() * (area_start + area_end)/2.;
-        result
+    pub fn value(&self, bound: Bound, draft: f64) -> Result<f64, Error> {
+        let area_start = self.area(bound.start(), draft)?;
+        let area_end = self.area(bound.end(), draft)?;
+        let lost: f64 = self.flooded.iter().map(|c| c.volume(&bound, draft)).sum();
+        let result = bound.length() * (area_start + area_end)/2. - lost;
+        Ok(result)
     }
     ///Интерполированние значение погруженной площади сечения.  
     ///Считается методом линейной интерполяции.
     /// - pos_x: координата шпангоута по х от центра судна
     /// - draft: осадка в районе шпангоута
-    fn area(&self, pos_x: f64, draft: f64) -> f64 {
-        assert!(pos_x >= -self.ship_length/2., "length = {} >= -self.ship_length/2. = {}", pos_x, -self.ship_length/2.);
-        assert!(pos_x <= self.ship_length/2., "length = {} <= self.ship_length/2. = {}", pos_x, self.ship_length/2.);
+    fn area(&self, pos_x: f64, draft: f64) -> Result<f64, Error> {
+        if pos_x < -self.ship_length/2. || pos_x > self.ship_length/2. {
+            return Err(Error::Displacement(format!("pos_x {pos_x} is outside the hull ({}, {})", -self.ship_length/2., self.ship_length/2.)));
+        }
         let index = (pos_x + self.ship_length/2.)/self.vec_step;
         let index_up = index.ceil();
         let index_down = index.floor();
-        assert!(index_down >= 0., "length_down = {} >= 0.", index_down);
-        assert!(index_up < self.frames.len() as f64, "length_up = {} < self.frames.len() = {}", index_up, self.frames.len());
+        if index_down < 0. || index_up >= self.frames.len() as f64 {
+            return Err(Error::Displacement(format!("frame index ({index_down}, {index_up}) is outside frames.len() {}", self.frames.len())));
+        }
         if index_up == index_down {
-            return self.frames[index_up as usize].area(draft)
+            return Ok(self.frames[index_up as usize].area(draft))
         }
         let coeff_len_up = index - index_down;        
         let coeff_len_down = index_up - index; 
@@ -50,6 +69,6 @@ impl Displacement {
         let frame_up = &self.frames[length_up];
         let frame_down = &self.frames[length_down];
         let result = frame_up.area(draft) * coeff_len_up + frame_down.area(draft) * coeff_len_down;
-        result
+        Ok(result)
     }
 }
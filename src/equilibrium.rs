@@ -0,0 +1,246 @@
+//! Посадка судна. Равновесие сил поддержания и массы судна.
+use std::{cell::Cell, rc::Rc};
+
+use crate::{
+    displacement::Displacement,
+    error::Error,
+    mass::IMass,
+    math::{bound::Bound, curve::{Curve, ICurve}},
+    trim::Trim,
+};
+
+/// Посадка судна, найденная итерационным расчетом
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatingPosition {
+    /// осадка на миделе
+    pub draught: f64,
+    /// дифферент, разность осадок носом и кормой, положительный на нос
+    pub trim: f64,
+    /// количество выполненных итераций
+    pub iterations: usize,
+    /// невязка массы вытесненной воды и массы судна
+    pub mass_residual: f64,
+    /// невязка отстояния центра величины и центра масс по длине
+    pub shift_residual: f64,
+}
+/// Условия сходимости итерационного расчета посадки судна
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Convergence {
+    /// допустимая невязка массы, доля от массы судна, по умолчанию 0.00001
+    pub mass_tolerance: f64,
+    /// допустимая невязка отстояния центра величины по длине, м, по умолчанию 0.0001
+    pub shift_tolerance: f64,
+    /// наибольшее количество итераций, по умолчанию 100
+    pub max_iterations: usize,
+}
+///
+impl Default for Convergence {
+    fn default() -> Self {
+        Self {
+            mass_tolerance: 0.00001,
+            shift_tolerance: 0.0001,
+            max_iterations: 100,
+        }
+    }
+}
+/// Распределение массы вытесненной воды по шпациям при посадке судна,
+/// найденной итерационно методом Ньютона: средняя осадка и дифферент
+/// подбираются до совпадения массы вытесненной воды, вычисленной по
+/// кривым погруженной площади шпангоутов, с массой судна и совпадения
+/// отстояния центра величины по длине с отстоянием центра масс.
+/// Начальное приближение - осадка по кривой средней осадки и дифферент [Trim].
+/// Посадка вычисляется один раз, распределение массы вытесненной воды
+/// соответствует возвращаемой [Equilibrium::solve] посадке.
+pub struct Equilibrium {
+    /// длинна судна
+    ship_length: f64,
+    /// плотность окружающей воды
+    water_density: f64,
+    /// вектор разбиения на отрезки для эпюров
+    bounds: Vec<Bound>,
+    /// все грузы судна
+    mass: Rc<dyn IMass>,
+    /// отстояние центра тяжести ватерлинии по длине от миделя
+    center_waterline_shift: Curve,
+    /// средняя осадка
+    mean_draught: Curve,
+    /// водоизмещение судна
+    displacement: Displacement,
+    /// дифферент судна
+    trim: Trim,
+    /// условия сходимости
+    convergence: Convergence,
+    /// найденная посадка судна
+    position: Cell<Option<FloatingPosition>>,
+}
+///
+impl Equilibrium {
+    /// Основной конструктор, длинна судна и плотность воды
+    /// берутся из дифферента. Аргументы:
+    /// - bounds: вектор разбиения на отрезки для эпюров
+    /// - mass: все грузы судна
+    /// - center_waterline_shift: кривая отстояния центра тяжести ватерлинии по длине от миделя
+    /// - mean_draught: кривая средней осадки
+    /// - displacement: класс водоизмещения судна
+    /// - trim: класс дифферента судна
+    /// - convergence: условия сходимости
+    pub fn new(
+        bounds: Vec<Bound>,
+        mass: Rc<dyn IMass>,
+        center_waterline_shift: Curve,
+        mean_draught: Curve,
+        displacement: Displacement,
+        trim: Trim,
+        convergence: Convergence,
+    ) -> Result<Self, Error> {
+        if bounds.is_empty() {
+            return Err(Error::Equilibrium("bounds must not be empty".to_owned()));
+        }
+        let Convergence { mass_tolerance, shift_tolerance, max_iterations } = convergence;
+        if mass_tolerance <= 0. || shift_tolerance <= 0. {
+            return Err(Error::Equilibrium(format!(
+                "mass_tolerance {mass_tolerance} and shift_tolerance {shift_tolerance} must be greater than 0"
+            )));
+        }
+        if max_iterations == 0 {
+            return Err(Error::Equilibrium("max_iterations must be greater than 0".to_owned()));
+        }
+        Ok(Self {
+            ship_length: trim.ship_length(),
+            water_density: trim.water_density(),
+            bounds,
+            mass,
+            center_waterline_shift,
+            mean_draught,
+            displacement,
+            trim,
+            convergence,
+            position: Cell::new(None),
+        })
+    }
+    /// Масса вытесненной воды по шпациям при заданной посадке
+    /// - draught: осадка на миделе
+    /// - trim: дифферент, разность осадок носом и кормой
+    fn buoyancy(&self, draught: f64, trim: f64) -> Result<Vec<f64>, Error> {
+        self.bounds
+            .iter()
+            .map(|v| {
+                let displacement = self
+                    .displacement
+                    .value(*v, draught + trim * v.center() / self.ship_length)?;
+                Ok(displacement * self.water_density)
+            })
+            .collect()
+    }
+    /// Масса вытесненной воды и ее статический момент относительно миделя
+    fn mass_moment(&self, draught: f64, trim: f64) -> Result<(f64, f64), Error> {
+        let buoyancy = self.buoyancy(draught, trim)?;
+        Ok((
+            buoyancy.iter().sum(),
+            buoyancy.iter().zip(self.bounds.iter()).map(|(b, v)| b * v.center()).sum(),
+        ))
+    }
+    /// Посадка судна, вычисляется при первом обращении
+    pub fn solve(&self) -> Result<FloatingPosition, Error> {
+        if let Some(position) = self.position.get() {
+            return Ok(position);
+        }
+        let position = self.iterate()?;
+        self.position.set(Some(position));
+        Ok(position)
+    }
+    /// Итерационный расчет посадки судна
+    fn iterate(&self) -> Result<FloatingPosition, Error> {
+        let mass_sum = self.mass.sum();
+        if mass_sum <= 0. {
+            return Err(Error::Equilibrium(format!("mass sum {mass_sum} must be greater than 0")));
+        }
+        let shift_x = self.mass.shift().x();
+        let moment = mass_sum * shift_x;
+        // начальное приближение: средняя осадка на ровный киль
+        // и дифферент относительно центра тяжести ватерлинии
+        let volume = mass_sum / self.water_density;
+        let mut trim = self.trim.value()?;
+        let mut draught = self.mean_draught.value(volume)
+            - trim * self.center_waterline_shift.value(volume) / self.ship_length;
+        // шаг для численного вычисления производных
+        let delta = self.ship_length * 0.00001;
+        for iteration in 1..=self.convergence.max_iterations {
+            let (buoyancy, buoyancy_moment) = self.mass_moment(draught, trim)?;
+            let mass_residual = buoyancy - mass_sum;
+            let shift_residual = if buoyancy > 0. {
+                buoyancy_moment / buoyancy - shift_x
+            } else {
+                f64::INFINITY
+            };
+            log::debug!("\t Equilibrium iteration:{iteration} draught:{draught} trim:{trim} mass_residual:{mass_residual} shift_residual:{shift_residual}");
+            if mass_residual.abs() <= self.convergence.mass_tolerance * mass_sum
+                && shift_residual.abs() <= self.convergence.shift_tolerance
+            {
+                let result = FloatingPosition {
+                    draught,
+                    trim,
+                    iterations: iteration,
+                    mass_residual,
+                    shift_residual,
+                };
+                log::debug!("\t Equilibrium result:{:?}", result);
+                return Ok(result);
+            }
+            // невязки и их производные по осадке и дифференту
+            let f = (mass_residual, buoyancy_moment - moment);
+            let (b_d, m_d) = self.mass_moment(draught + delta, trim)?;
+            let (b_t, m_t) = self.mass_moment(draught, trim + delta)?;
+            let j = (
+                (b_d - buoyancy) / delta,
+                (b_t - buoyancy) / delta,
+                (m_d - buoyancy_moment) / delta,
+                (m_t - buoyancy_moment) / delta,
+            );
+            let det = j.0 * j.3 - j.1 * j.2;
+            if det == 0. || !det.is_finite() {
+                return Err(Error::Equilibrium(format!(
+                    "singular jacobian at draught:{draught} trim:{trim}"
+                )));
+            }
+            draught -= (f.0 * j.3 - f.1 * j.1) / det;
+            trim -= (j.0 * f.1 - j.2 * f.0) / det;
+        }
+        Err(Error::Equilibrium(format!(
+            "no convergence in {} iterations, draught:{draught} trim:{trim}",
+            self.convergence.max_iterations
+        )))
+    }
+}
+///
+impl IDraught for Equilibrium {
+    /// Распределение массы вытесненной воды по шпациям
+    fn values(&self) -> Result<Vec<f64>, Error> {
+        let position = self.solve()?;
+        let result = self.buoyancy(position.draught, position.trim)?;
+        log::debug!("\t Equilibrium values result:{:?}", result);
+        Ok(result)
+    }
+}
+
+#[doc(hidden)]
+pub trait IDraught {
+    fn values(&self) -> Result<Vec<f64>, Error>;
+}
+// заглушка для тестирования
+#[doc(hidden)]
+pub struct FakeDraught {
+    data: Vec<f64>,
+}
+#[doc(hidden)]
+impl FakeDraught {
+    pub fn new(data: Vec<f64>) -> Self {
+        Self { data }
+    }
+}
+#[doc(hidden)]
+impl IDraught for FakeDraught {
+    fn values(&self) -> Result<Vec<f64>, Error> {
+        Ok(self.data.clone())
+    }
+}
@@ -0,0 +1,101 @@
+//! Ошибки расчета
+use std::fmt;
+
+/// Ошибка расчета, содержит объект, в котором она возникла,
+/// и описание причины
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// ошибка диапазона значений
+    Bound(String),
+    /// ошибка кривой
+    Curve(String),
+    /// ошибка нагрузки на корпус судна
+    Mass(String),
+    /// ошибка твердого груза
+    LoadSpace(String),
+    /// ошибка цистерны
+    Tank(String),
+    /// ошибка водоизмещения
+    Displacement(String),
+    /// ошибка дифферента
+    Trim(String),
+    /// ошибка результирующей нагрузки
+    TotalForce(String),
+    /// ошибка операции над векторами
+    Vec(String),
+    /// ошибка получения данных из источника
+    DataSource(String),
+    /// ошибка вычисления использования допустимых значений
+    Utilisation(String),
+    /// ошибка вычисления волновых нагрузок
+    Wave(String),
+    /// ошибка расчета посадки судна
+    Equilibrium(String),
+    /// ошибка вычисления элементов теоретического чертежа
+    Hydrostatics(String),
+    /// ошибка вычисления метацентрической высоты
+    MetacentricHeight(String),
+    /// ошибка вычисления крена
+    Heel(String),
+    /// ошибка вычисления диаграммы статической остойчивости
+    RightingLever(String),
+    /// ошибка проверки критериев остойчивости
+    Criteria(String),
+    /// ошибка вычисления критерия погоды
+    Weather(String),
+    /// ошибка в расчете груза зерна
+    Grain(String),
+    /// ошибка в расчете затопления отсеков
+    Flooding(String),
+    /// ошибка в расчете массы судна порожнем
+    Lightship(String),
+    /// ошибка в калибровочной таблице цистерны
+    Calibration(String),
+    /// ошибка в грузовом плане контейнеров
+    Stowage(String),
+    /// ошибка в расчете навалочного груза
+    Bulk(String),
+    /// ошибка в расчете груза навалом в трюме
+    Hold(String),
+    /// ошибка в расчете расхода запасов на переходе
+    Voyage(String),
+    /// ошибка в расчете обледенения
+    Ice(String),
+}
+///
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Bound(msg) => write!(f, "Bound: {msg}"),
+            Error::Curve(msg) => write!(f, "Curve: {msg}"),
+            Error::Mass(msg) => write!(f, "Mass: {msg}"),
+            Error::LoadSpace(msg) => write!(f, "LoadSpace: {msg}"),
+            Error::Tank(msg) => write!(f, "Tank: {msg}"),
+            Error::Displacement(msg) => write!(f, "Displacement: {msg}"),
+            Error::Trim(msg) => write!(f, "Trim: {msg}"),
+            Error::TotalForce(msg) => write!(f, "TotalForce: {msg}"),
+            Error::Vec(msg) => write!(f, "Vec: {msg}"),
+            Error::DataSource(msg) => write!(f, "DataSource: {msg}"),
+            Error::Utilisation(msg) => write!(f, "Utilisation: {msg}"),
+            Error::Wave(msg) => write!(f, "Wave: {msg}"),
+            Error::Equilibrium(msg) => write!(f, "Equilibrium: {msg}"),
+            Error::Hydrostatics(msg) => write!(f, "Hydrostatics: {msg}"),
+            Error::MetacentricHeight(msg) => write!(f, "MetacentricHeight: {msg}"),
+            Error::Heel(msg) => write!(f, "Heel: {msg}"),
+            Error::RightingLever(msg) => write!(f, "RightingLever: {msg}"),
+            Error::Criteria(msg) => write!(f, "Criteria: {msg}"),
+            Error::Weather(msg) => write!(f, "Weather: {msg}"),
+            Error::Grain(msg) => write!(f, "Grain: {msg}"),
+            Error::Flooding(msg) => write!(f, "Flooding: {msg}"),
+            Error::Lightship(msg) => write!(f, "Lightship: {msg}"),
+            Error::Calibration(msg) => write!(f, "Calibration: {msg}"),
+            Error::Stowage(msg) => write!(f, "Stowage: {msg}"),
+            Error::Bulk(msg) => write!(f, "Bulk: {msg}"),
+            Error::Hold(msg) => write!(f, "Hold: {msg}"),
+            Error::Voyage(msg) => write!(f, "Voyage: {msg}"),
+            Error::Ice(msg) => write!(f, "Ice: {msg}"),
+        }
+    }
+}
+///
+impl std::error::Error for Error {}
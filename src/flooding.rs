@@ -0,0 +1,151 @@
+//! Затопление отсеков. Метод постоянного водоизмещения (исключаемых объемов).
+use crate::{equilibrium::FloatingPosition, error::Error, math::bound::Bound, metacentric_height::MetacentricHeight};
+
+/// Затопленный отсек в форме прямоугольного параллелепипеда,
+/// теряемая плавучесть - объем отсека ниже ватерлинии,
+/// умноженный на коэффициент проницаемости
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Compartment {
+    /// границы отсека по длине
+    bound: Bound,
+    /// границы отсека по ширине
+    bound_y: Bound,
+    /// границы отсека по высоте от ОП
+    bound_z: Bound,
+    /// коэффициент проницаемости
+    permeability: f64,
+}
+///
+impl Compartment {
+    /// Основной конструктор. Аргументы:
+    /// - bound: границы отсека по длине
+    /// - bound_y: границы отсека по ширине
+    /// - bound_z: границы отсека по высоте от ОП
+    /// - permeability: коэффициент проницаемости, от 0 до 1
+    /// - breadth: наибольшая ширина корпуса, отсек должен лежать в ее пределах
+    pub fn new(bound: Bound, bound_y: Bound, bound_z: Bound, permeability: f64, breadth: f64) -> Result<Self, Error> {
+        if !(0. ..=1.).contains(&permeability) {
+            return Err(Error::Flooding(format!("permeability {permeability} must be in range 0..=1")));
+        }
+        // допуск на погрешность ширины, вычисленной по кривым шпангоутов
+        if bound_y.start().abs().max(bound_y.end().abs()) > breadth / 2. + 0.001 {
+            return Err(Error::Flooding(format!(
+                "compartment bound_y ({}, {}) is outside the hull breadth {breadth}",
+                bound_y.start(),
+                bound_y.end()
+            )));
+        }
+        Ok(Self { bound, bound_y, bound_z, permeability })
+    }
+    /// Осадка в районе отсека
+    /// - position: посадка судна
+    /// - ship_length: длинна судна
+    fn draught(&self, position: &FloatingPosition, ship_length: f64) -> f64 {
+        position.draught + position.trim * self.bound.center() / ship_length
+    }
+    /// Высота затопленной части отсека ниже ватерлинии
+    /// - draught: осадка в районе отсека
+    fn height(&self, draught: f64) -> f64 {
+        (draught - self.bound_z.start()).clamp(0., self.bound_z.length())
+    }
+    /// Теряемая площадь сечения отсека
+    /// - draught: осадка в районе отсека
+    pub fn area(&self, draught: f64) -> f64 {
+        self.permeability * self.bound_y.length() * self.height(draught)
+    }
+    /// Аппликата центра теряемого объема
+    /// - draught: осадка в районе отсека
+    pub fn center_z(&self, draught: f64) -> f64 {
+        self.bound_z.start() + self.height(draught) / 2.
+    }
+    /// Теряемый объем части отсека в пределах заданного диапазона по длине
+    /// - bound: диапазон по длине
+    /// - draught: осадка в районе отсека
+    pub fn volume(&self, bound: &Bound, draught: f64) -> f64 {
+        self.bound.intersect(bound).map(|v| v.length()).unwrap_or(0.) * self.area(draught)
+    }
+    /// Теряемый момент инерции площади ватерлинии относительно ДП,
+    /// 0 если ватерлиния не пересекает отсек
+    /// - draught: осадка в районе отсека
+    pub fn inertia(&self, draught: f64) -> f64 {
+        if draught <= self.bound_z.start() || draught >= self.bound_z.end() {
+            return 0.;
+        }
+        let (length, breadth, y) = (self.bound.length(), self.bound_y.length(), self.bound_y.center());
+        self.permeability * length * breadth * (breadth.powi(2) / 12. + y.powi(2))
+    }
+}
+/// Затопленные отсеки судна. Плавучесть затопленных отсеков исключается
+/// из водоизмещения, масса судна не меняется. Угол крена для малых углов:
+/// $\tan\theta = ((y_g - y_c) + \sum v_i y_i / V) / h_1$, где $v_i, y_i$ - теряемый объем
+/// отсека и его отстояние от ДП, $h_1$ - метацентрическая высота после затопления.
+pub struct Flooding {
+    /// затопленные отсеки
+    compartments: Vec<Compartment>,
+    /// длинна судна
+    ship_length: f64,
+}
+///
+impl Flooding {
+    /// Основной конструктор. Аргументы:
+    /// - compartments: затопленные отсеки
+    /// - ship_length: длинна судна
+    pub fn new(compartments: Vec<Compartment>, ship_length: f64) -> Result<Self, Error> {
+        if ship_length <= 0. {
+            return Err(Error::Flooding(format!("ship_length {ship_length} must be greater than 0")));
+        }
+        Ok(Self { compartments, ship_length })
+    }
+    /// Суммарный теряемый объем при заданной посадке
+    /// - position: посадка судна
+    pub fn volume(&self, position: &FloatingPosition) -> f64 {
+        self.compartments
+            .iter()
+            .map(|c| c.volume(&c.bound, c.draught(position, self.ship_length)))
+            .sum()
+    }
+    /// Исправленная метацентрическая высота после затопления, отрицательная
+    /// если судно не имеет положения равновесия в прямом положении.
+    /// Корпус погружается до объема $V_1 = V + \sum v_i$, центр величины и
+    /// момент инерции ватерлинии берутся по кривым при этом объеме за вычетом
+    /// теряемых объемов $v_i$ с аппликатами $z_i$ и моментов инерции $i_i$:
+    /// $z_{c1} = (V_1 z_c(V_1) - \sum v_i z_i) / V$, $r_1 = (I(V_1) - \sum i_i) / V$,
+    /// $h_1 = z_{c1} + r_1 - (z_g + \Delta m_h)$
+    /// - position: посадка судна после затопления
+    /// - metacentric_height: метацентрическая высота неповрежденного судна
+    pub fn metacentric_height(&self, position: &FloatingPosition, metacentric_height: &MetacentricHeight) -> Result<f64, Error> {
+        let volume = metacentric_height.volume()?;
+        let (lost, moment, inertia) = self.compartments.iter().fold((0., 0., 0.), |(lost, moment, inertia), c| {
+            let draught = c.draught(position, self.ship_length);
+            let volume = c.volume(&c.bound, draught);
+            (lost + volume, moment + volume * c.center_z(draught), inertia + c.inertia(draught))
+        });
+        let volume_hull = volume + lost;
+        let z_c = (volume_hull * metacentric_height.z_c(volume_hull) - moment) / volume;
+        let rad_trans = (metacentric_height.inertia(volume_hull) - inertia) / volume;
+        let result = z_c + rad_trans - metacentric_height.z_g()?;
+        log::debug!("\t Flooding metacentric_height volume_hull:{volume_hull} z_c:{z_c} rad_trans:{rad_trans} result:{result}");
+        Ok(result)
+    }
+    /// Угол крена после затопления, градусы, None если метацентрическая
+    /// высота после затопления не положительна
+    /// - position: посадка судна после затопления
+    /// - shift_y: отстояние центра масс от центра величины неповрежденного судна по ширине
+    /// - metacentric_height: метацентрическая высота неповрежденного судна
+    pub fn heel(&self, position: &FloatingPosition, shift_y: f64, metacentric_height: &MetacentricHeight) -> Result<Option<f64>, Error> {
+        let volume = metacentric_height.volume()?;
+        let metacentric_height = self.metacentric_height(position, metacentric_height)?;
+        if metacentric_height <= 0. {
+            log::warn!("Flooding.heel | metacentric height after flooding {metacentric_height} is not positive");
+            return Ok(None);
+        }
+        let moment: f64 = self
+            .compartments
+            .iter()
+            .map(|c| c.volume(&c.bound, c.draught(position, self.ship_length)) * c.bound_y.center())
+            .sum();
+        let result = ((shift_y + moment / volume) / metacentric_height).atan().to_degrees();
+        log::debug!("\t Flooding heel result:{:?}", result);
+        Ok(Some(result))
+    }
+}
@@ -0,0 +1,68 @@
+//! Груз - зерно навалом в трюме
+use crate::{
+    error::Error,
+    hold::Hold,
+    load::ILoad,
+    math::{bound::Bound, curve::{Curve, ICurve}, position::Position},
+};
+
+/// Груз - зерно навалом в трюме, реализует интерфейс ILoad.
+/// Помимо массы имеет кренящий момент от смещения зерна по
+/// Международному кодексу безопасной перевозки зерна навалом, A 6:
+/// $M = k M_v / SF$, т·м, где $M_v$ - объемный кренящий момент по таблице
+/// для трюма, $SF$ - удельный погрузочный объем, $k$ - множитель,
+/// учитывающий вертикальное смещение зерна: 1.06 для заполненного трюма,
+/// 1.12 для частично заполненного. Объем, центр масс и распределение
+/// массы зерна по длине в границах трюма - см. [Hold]
+pub struct Grain {
+    /// удельный погрузочный объем, м^3/т
+    stowage_factor: f64,
+    /// зерно в трюме
+    hold: Hold,
+    /// кривая объемного кренящего момента по объему зерна в трюме
+    moment: Curve,
+}
+///
+impl Grain {
+    /// Основной конструктор. Аргументы:
+    /// - stowage_factor: удельный погрузочный объем, м^3/т
+    /// - volume: объем зерна в трюме
+    /// - capacity: вместимость трюма
+    /// - bound: границы трюма
+    /// - center: центер масс
+    /// - moment: кривая объемного кренящего момента по объему зерна в трюме
+    pub fn new(
+        stowage_factor: f64,
+        volume: f64,
+        capacity: f64,
+        bound: Bound,
+        center: Position,
+        moment: Curve,
+    ) -> Result<Self, Error> {
+        if stowage_factor <= 0. {
+            return Err(Error::Grain(format!("stowage_factor {stowage_factor} must be greater than 0")));
+        }
+        Ok(Self { stowage_factor, hold: Hold::new(volume, capacity, bound, center)?, moment })
+    }
+    /// Трюм заполнен
+    pub fn filled(&self) -> bool {
+        self.hold.filled()
+    }
+}
+///
+impl ILoad for Grain {
+    fn mass(&self, bound: Option<Bound>) -> f64 {
+        self.hold.mass(self.hold.volume() / self.stowage_factor, bound)
+    }
+
+    fn center(&self) -> Position {
+        self.hold.center()
+    }
+
+    fn moment_grain(&self) -> f64 {
+        let factor = if self.filled() { 1.06 } else { 1.12 };
+        let result = factor * self.moment.value(self.hold.volume()) / self.stowage_factor;
+        log::debug!("\t Grain moment_grain result:{:?}", result);
+        result
+    }
+}
@@ -0,0 +1,77 @@
+//! Остойчивость при перевозке зерна навалом
+use crate::{error::Error, righting_lever::RightingLever};
+
+/// Крен от смещения зерна по Международному кодексу безопасной
+/// перевозки зерна навалом, A 7. Кренящее плечо от смещения зерна
+/// изменяется линейно от $\lambda_0 = M / \Delta$ при 0° до
+/// $\lambda_{40} = 0.8 \lambda_0$ при 40°:
+/// - угол крена от смещения зерна - первое пересечение кривой
+///   кренящих плеч с диаграммой статической остойчивости,
+/// - остаточная площадь - площадь между диаграммой и кривой кренящих плеч
+///   от угла крена до наименьшего из 40°, угла заливания и угла
+///   наибольшей разности ординат диаграммы и кривой кренящих плеч.
+pub struct GrainHeel {
+    /// диаграмма статической остойчивости
+    righting_lever: RightingLever,
+    /// кренящее плечо от смещения зерна при 0°
+    lever_0: f64,
+    /// угол заливания, градусы
+    flooding_angle: Option<f64>,
+}
+///
+impl GrainHeel {
+    /// Основной конструктор. Аргументы:
+    /// - righting_lever: диаграмма статической остойчивости
+    /// - moment: суммарный кренящий момент от смещения зерна, т·м
+    /// - displacement: водоизмещение судна, т
+    /// - flooding_angle: угол заливания, градусы, необязательно
+    pub fn new(
+        righting_lever: RightingLever,
+        moment: f64,
+        displacement: f64,
+        flooding_angle: Option<f64>,
+    ) -> Result<Self, Error> {
+        if moment < 0. {
+            return Err(Error::Grain(format!("moment {moment} must be greater or equal to 0")));
+        }
+        if displacement <= 0. {
+            return Err(Error::Grain(format!("displacement {displacement} must be greater than 0")));
+        }
+        Ok(Self { righting_lever, lever_0: moment / displacement, flooding_angle })
+    }
+    /// Кренящее плечо от смещения зерна
+    /// - angle: угол крена, градусы
+    pub fn lever(&self, angle: f64) -> f64 {
+        self.lever_0 * (1. - 0.2 * angle / 40.)
+    }
+    /// Угол крена от смещения зерна, градусы,
+    /// наибольший угол пантокарен если диаграмма не пересекает кривую кренящих плеч
+    pub fn angle(&self) -> f64 {
+        let angle_max = self.righting_lever.angle_max();
+        self.righting_lever
+            .angle(|a| self.lever(a), 0., angle_max)
+            .unwrap_or(angle_max)
+    }
+    /// Угол, ограничивающий остаточную площадь, градусы
+    pub fn angle_limit(&self) -> f64 {
+        let angle_to = 40f64.min(self.flooding_angle.unwrap_or(40.));
+        let n = (angle_to * 10.).ceil().max(1.) as usize;
+        let angle_max_difference = (0..=n)
+            .map(|i| angle_to * i as f64 / n as f64)
+            .map(|a| (a, self.righting_lever.value(a) - self.lever(a)))
+            .fold((0., f64::MIN), |max, v| if v.1 > max.1 { v } else { max })
+            .0;
+        angle_to.min(angle_max_difference)
+    }
+    /// Остаточная площадь между диаграммой и кривой кренящих плеч, м·рад
+    pub fn area(&self) -> f64 {
+        let (angle_from, angle_to) = (self.angle(), self.angle_limit());
+        if angle_to <= angle_from {
+            return 0.;
+        }
+        let lever_area = (self.lever(angle_from) + self.lever(angle_to)) / 2. * (angle_to - angle_from).to_radians();
+        let result = self.righting_lever.area(angle_from, angle_to) - lever_area;
+        log::debug!("\t GrainHeel area result:{:?}", result);
+        result
+    }
+}
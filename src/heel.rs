@@ -0,0 +1,130 @@
+//! Крен. Угол наклона корпуса судна в поперечной плоскости.
+use std::rc::Rc;
+
+use crate::{
+    cross_curves::CrossCurves,
+    error::Error,
+    mass::IMass,
+    math::pos_shift::IPosShift,
+    metacentric_height::MetacentricHeight,
+};
+
+/// Статический угол крена судна от смещения центра масс от ДП.
+/// Положительный крен - в сторону положительных значений y.
+/// - для малых углов: $\tan\theta = (y_g - y_c) / h$;
+/// - по пантокаренам: наименьший угол, при котором плечо
+///   статической остойчивости $l = KN - z_g \sin\theta$ равно
+///   кренящему плечу $(y_g - y_c) \cos\theta$, $z_g$ исправлена на влияние
+///   свободной поверхности жидкости в цистернах.
+pub struct Heel {
+    /// плотность окружающей воды
+    water_density: f64,
+    /// отстояние центра величины погруженной части судна
+    center_draught_shift: Box<dyn IPosShift>,
+    /// все грузы судна
+    mass: Rc<dyn IMass>,
+    /// поперечная метацентрическая высота
+    metacentric_height: Option<MetacentricHeight>,
+    /// пантокарены
+    cross_curves: Option<CrossCurves>,
+}
+///
+impl Heel {
+    /// Основной конструктор. Аргументы:
+    /// - water_density: плотность окружающей воды
+    /// - center_draught_shift: отстояние центра величины погруженной части судна
+    /// - mass: все грузы судна
+    /// - metacentric_height: поперечная метацентрическая высота, для малых углов
+    /// - cross_curves: пантокарены, если заданы - крен вычисляется по ним
+    pub fn new(
+        water_density: f64,
+        center_draught_shift: impl IPosShift + 'static,
+        mass: Rc<dyn IMass>,
+        metacentric_height: Option<MetacentricHeight>,
+        cross_curves: Option<CrossCurves>,
+    ) -> Result<Self, Error> {
+        if water_density <= 0. {
+            return Err(Error::Heel(format!("water_density {water_density} must be greater than 0")));
+        }
+        if metacentric_height.is_none() && cross_curves.is_none() {
+            return Err(Error::Heel("metacentric_height or cross_curves must be given".to_owned()));
+        }
+        Ok(Self {
+            water_density,
+            center_draught_shift: Box::new(center_draught_shift),
+            mass,
+            metacentric_height,
+            cross_curves,
+        })
+    }
+    /// Объемное водоизмещение
+    fn volume(&self) -> Result<f64, Error> {
+        let mass_sum = self.mass.sum();
+        if mass_sum <= 0. {
+            return Err(Error::Heel(format!("mass sum {mass_sum} must be greater than 0")));
+        }
+        Ok(mass_sum / self.water_density)
+    }
+    /// Смещение центра масс от центра величины по ширине $y_g - y_c$
+    fn shift(&self) -> Result<f64, Error> {
+        Ok(self.mass.shift().y() - self.center_draught_shift.value(self.volume()?).y())
+    }
+    /// Угол крена для малых углов, градусы
+    pub fn small_angle(&self) -> Result<f64, Error> {
+        let Some(metacentric_height) = &self.metacentric_height else {
+            return Err(Error::Heel("metacentric_height is not given".to_owned()));
+        };
+        let h = metacentric_height.h()?;
+        if h <= 0. {
+            return Err(Error::Heel(format!("metacentric height {h} must be greater than 0")));
+        }
+        let shift = self.shift()?;
+        let result = (shift / h).atan().to_degrees();
+        log::debug!("\t Heel small_angle h:{h} shift:{shift} result:{result}");
+        Ok(result)
+    }
+    /// Угол крена по пантокаренам, градусы
+    pub fn large_angle(&self) -> Result<f64, Error> {
+        let Some(cross_curves) = &self.cross_curves else {
+            return Err(Error::Heel("cross_curves is not given".to_owned()));
+        };
+        let volume = self.volume()?;
+        let shift = self.shift()?;
+        if shift == 0. {
+            return Ok(0.);
+        }
+        let z_g = self.mass.shift().z() + self.mass.delta_m_h_trans()?;
+        // разность плеча статической остойчивости и кренящего плеча
+        let delta = |angle: f64| {
+            let rad = angle.to_radians();
+            cross_curves.value(volume, angle) - z_g * rad.sin() - shift.abs() * rad.cos()
+        };
+        let step = 0.1;
+        let mut angle = 0.;
+        let mut value = delta(angle);
+        while angle < cross_curves.angle_max() {
+            let next_angle = (angle + step).min(cross_curves.angle_max());
+            let next_value = delta(next_angle);
+            if next_value >= 0. {
+                let result = angle + (next_angle - angle) * value / (value - next_value);
+                let result = result.copysign(shift);
+                log::debug!("\t Heel large_angle volume:{volume} shift:{shift} z_g:{z_g} result:{result}");
+                return Ok(result);
+            }
+            angle = next_angle;
+            value = next_value;
+        }
+        Err(Error::Heel(format!(
+            "no equilibrium angle up to {} degrees, shift:{shift} z_g:{z_g}",
+            cross_curves.angle_max()
+        )))
+    }
+    /// Угол крена, градусы: по пантокаренам если они заданы,
+    /// иначе для малых углов
+    pub fn value(&self) -> Result<f64, Error> {
+        match self.cross_curves {
+            Some(_) => self.large_angle(),
+            None => self.small_angle(),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Груз навалом в трюме
+use crate::{
+    error::Error,
+    math::{bound::Bound, distribution::Distribution, position::Position},
+};
+
+/// Груз навалом в трюме: объем груза, вместимость трюма, центр масс
+/// и распределение массы груза по длине в границах трюма, см. [Distribution].
+/// Общая часть зерна [crate::grain::Grain] и навалочного груза [crate::bulk::Bulk].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hold {
+    /// объем груза в трюме
+    volume: f64,
+    /// вместимость трюма
+    capacity: f64,
+    /// центр масс груза
+    center: Position,
+    /// распределение массы груза по длине в границах трюма
+    distribution: Distribution,
+}
+///
+impl Hold {
+    /// Основной конструктор. Аргументы:
+    /// - volume: объем груза в трюме
+    /// - capacity: вместимость трюма
+    /// - bound: границы трюма
+    /// - center: центр масс груза
+    pub fn new(volume: f64, capacity: f64, bound: Bound, center: Position) -> Result<Self, Error> {
+        if volume < 0. || volume > capacity {
+            return Err(Error::Hold(format!("volume {volume} of cargo must be in range 0..={capacity}")));
+        }
+        if bound.start() > center.x() || bound.end() < center.x() {
+            return Err(Error::Hold(format!("bound ({}, {}) must contain center.x {}", bound.start(), bound.end(), center.x())));
+        }
+        Ok(Self { volume, capacity, center, distribution: Distribution::new(bound, center.x())? })
+    }
+    /// Объем груза в трюме
+    pub fn volume(&self) -> f64 {
+        self.volume
+    }
+    /// Центр масс груза
+    pub fn center(&self) -> Position {
+        self.center
+    }
+    /// Трюм заполнен
+    pub fn filled(&self) -> bool {
+        self.volume >= self.capacity
+    }
+    /// Масса груза в указанных границах
+    /// - mass: масса груза
+    /// - bound: границы, если не заданы - вся масса груза
+    pub fn mass(&self, mass: f64, bound: Option<Bound>) -> f64 {
+        if let Some(bound) = bound {
+            self.distribution.part_ratio(&bound) * mass
+        } else {
+            mass
+        }
+    }
+}
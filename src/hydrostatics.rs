@@ -0,0 +1,146 @@
+//! Элементы теоретического чертежа судна на ровный киль
+use crate::{error::Error, frame::Frame, math::curve::Curve};
+
+/// Элементы теоретического чертежа на ровный киль при одной осадке
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HydrostaticsValues {
+    /// осадка
+    pub draught: f64,
+    /// объемное водоизмещение
+    pub volume: f64,
+    /// отстояние центра величины по длине от миделя
+    pub center_x: f64,
+    /// аппликата центра величины
+    pub center_z: f64,
+    /// площадь ватерлинии
+    pub waterplane_area: f64,
+    /// отстояние центра тяжести ватерлинии по длине от миделя
+    pub center_waterline: f64,
+    /// продольный метацентрический радиус
+    pub rad_long: f64,
+}
+/// Допустимое относительное расхождение кривых элементов теоретического
+/// чертежа из данных по судну с вычисленными по шпангоутам
+pub const MISMATCH_TOLERANCE: f64 = 0.02;
+/// Элементы теоретического чертежа на ровный киль, вычисляются
+/// интегрированием кривых погруженной площади шпангоутов (масштаба Бонжана)
+/// методом трапеций по длине судна и по осадке:
+/// - $V = \int S(x) dx$, $x_c = \int S x dx / V$,
+/// - $z_c = \int (T S(x) - \int_0^T S(x, z) dz) dx / V$,
+/// - $A_w = \int b(x) dx$, $b = dS/dz$, $x_f = \int b x dx / A_w$,
+/// - $R = \int b (x - x_f)^2 dx / V$.
+pub struct Hydrostatics {
+    /// шпангоуты, равномерно распределенные по длине судна от кормы
+    frames: Vec<Frame>,
+    /// длинна судна
+    ship_length: f64,
+    /// осадки, для которых вычисляются значения, по возрастанию от 0
+    draughts: Vec<f64>,
+    /// элементы теоретического чертежа для всех осадок кроме нулевой
+    values: Vec<HydrostaticsValues>,
+}
+///
+impl Hydrostatics {
+    /// Основной конструктор. Аргументы:
+    /// - frames: шпангоуты, равномерно распределенные по длине судна от кормы
+    /// - ship_length: длинна судна
+    /// - draughts: осадки, для которых вычисляются значения, по возрастанию от 0,
+    ///   для точного интегрирования должны включать ключи кривых шпангоутов
+    pub fn new(frames: Vec<Frame>, ship_length: f64, draughts: Vec<f64>) -> Result<Self, Error> {
+        if frames.len() <= 1 {
+            return Err(Error::Hydrostatics(format!("frames.len() {} must be greater than 1", frames.len())));
+        }
+        if ship_length <= 0. {
+            return Err(Error::Hydrostatics(format!("ship_length {ship_length} must be greater than 0")));
+        }
+        if draughts.len() <= 1 || draughts[0] != 0. || draughts.windows(2).any(|v| v[1] <= v[0]) {
+            return Err(Error::Hydrostatics(format!("draughts {:?} must be ascending from 0", draughts)));
+        }
+        let mut result = Self { frames, ship_length, draughts, values: Vec::new() };
+        result.values = result.compute();
+        Ok(result)
+    }
+    /// Интеграл по длине судна методом трапеций от значений на шпангоутах
+    /// - f: подынтегральная функция (координата шпангоута по х, номер шпангоута)
+    fn integral(&self, f: impl Fn(f64, usize) -> f64) -> f64 {
+        let step = self.ship_length / (self.frames.len() as f64 - 1.);
+        let values: Vec<f64> = (0..self.frames.len())
+            .map(|i| f(-self.ship_length / 2. + step * i as f64, i))
+            .collect();
+        values.windows(2).map(|v| (v[0] + v[1]) / 2. * step).sum()
+    }
+    /// Элементы теоретического чертежа для всех осадок кроме нулевой
+    pub fn values(&self) -> &[HydrostaticsValues] {
+        &self.values
+    }
+    /// Вычисление элементов теоретического чертежа для всех осадок кроме нулевой
+    fn compute(&self) -> Vec<HydrostaticsValues> {
+        // интеграл погруженной площади шпангоутов по осадке
+        let mut area_integral = vec![0.; self.frames.len()];
+        let mut result = Vec::new();
+        for v in self.draughts.windows(2) {
+            let (lower, draught) = (v[0], v[1]);
+            let breadth: Vec<f64> = self
+                .frames
+                .iter()
+                .map(|f| (f.area(draught) - f.area(lower)) / (draught - lower))
+                .collect();
+            area_integral
+                .iter_mut()
+                .zip(self.frames.iter())
+                .for_each(|(s, f)| *s += (f.area(lower) + f.area(draught)) / 2. * (draught - lower));
+            let volume = self.integral(|_, i| self.frames[i].area(draught));
+            if volume <= 0. {
+                continue;
+            }
+            let center_x = self.integral(|x, i| self.frames[i].area(draught) * x) / volume;
+            let center_z = self.integral(|_, i| draught * self.frames[i].area(draught) - area_integral[i]) / volume;
+            let waterplane_area = self.integral(|_, i| breadth[i]);
+            let center_waterline = if waterplane_area > 0. {
+                self.integral(|x, i| breadth[i] * x) / waterplane_area
+            } else {
+                0.
+            };
+            let rad_long = self.integral(|x, i| breadth[i] * (x - center_waterline).powi(2)) / volume;
+            result.push(HydrostaticsValues {
+                draught,
+                volume,
+                center_x,
+                center_z,
+                waterplane_area,
+                center_waterline,
+                rad_long,
+            });
+        }
+        log::debug!("\t Hydrostatics result:{:?}", result);
+        result
+    }
+    /// Кривая по осадке для одного из элементов теоретического чертежа
+    fn curve(&self, value: impl Fn(&HydrostaticsValues) -> f64) -> Result<Curve, Error> {
+        Curve::new(self.values().iter().map(|v| (v.draught, value(v))).collect())
+    }
+    /// Кривая осадки по объемному водоизмещению
+    pub fn draught(&self) -> Result<Curve, Error> {
+        Curve::new(self.values().iter().map(|v| (v.volume, v.draught)).collect())
+    }
+    /// Кривая отстояния центра величины по длине от миделя по осадке
+    pub fn center_x(&self) -> Result<Curve, Error> {
+        self.curve(|v| v.center_x)
+    }
+    /// Кривая аппликаты центра величины по осадке
+    pub fn center_z(&self) -> Result<Curve, Error> {
+        self.curve(|v| v.center_z)
+    }
+    /// Кривая площади ватерлинии по осадке
+    pub fn waterplane_area(&self) -> Result<Curve, Error> {
+        self.curve(|v| v.waterplane_area)
+    }
+    /// Кривая отстояния центра тяжести ватерлинии по длине от миделя по осадке
+    pub fn center_waterline(&self) -> Result<Curve, Error> {
+        self.curve(|v| v.center_waterline)
+    }
+    /// Кривая продольного метацентрического радиуса по осадке
+    pub fn rad_long(&self) -> Result<Curve, Error> {
+        self.curve(|v| v.rad_long)
+    }
+}
@@ -0,0 +1,67 @@
+//! Нагрузка от обледенения судна
+use crate::{
+    error::Error,
+    load::LoadSpace,
+    math::{bound::Bound, position::Position},
+};
+
+/// Масса льда на 1 м^2 горизонтальной проекции открытых палуб
+/// при полном обледенении, т/м^2
+pub const DECK_LOAD: f64 = 0.030;
+/// Масса льда на 1 м^2 площади парусности (проекции надводной части
+/// на ДП, с учетом обоих бортов) при полном обледенении, т/м^2
+pub const WINDAGE_LOAD: f64 = 0.015;
+
+/// Обледенение судна: лед на открытых палубах и на площади парусности.
+/// Лед на каждой площади - груз [LoadSpace] в границах площади по длине
+/// с центром масс в центре площади. При половинном обледенении
+/// масса льда на 1 м^2 уменьшается вдвое.
+pub struct Ice {
+    /// доля массы льда от полного обледенения
+    ratio: f64,
+    /// открытые палубы (площадь, границы по длине, центр площади)
+    deck: Vec<(f64, Bound, Position)>,
+    /// площади парусности (площадь, границы по длине, центр площади)
+    windage: Vec<(f64, Bound, Position)>,
+}
+///
+impl Ice {
+    /// Основной конструктор. Аргументы:
+    /// - ratio: доля массы льда от полного обледенения, 1 - полное, 0.5 - половинное
+    /// - deck: открытые палубы (площадь, границы по длине, центр площади)
+    /// - windage: площади парусности (площадь, границы по длине, центр площади)
+    pub fn new(
+        ratio: f64,
+        deck: Vec<(f64, Bound, Position)>,
+        windage: Vec<(f64, Bound, Position)>,
+    ) -> Result<Self, Error> {
+        if ratio <= 0. || ratio > 1. {
+            return Err(Error::Ice(format!("ratio {ratio} must be greater than 0 and less or equal to 1")));
+        }
+        if let Some(area) = deck.iter().chain(windage.iter()).find(|v| v.0 <= 0.) {
+            return Err(Error::Ice(format!("area {} must be greater than 0", area.0)));
+        }
+        Ok(Self { ratio, deck, windage })
+    }
+    /// Грузы от льда на площадях с массой льда load на 1 м^2 при полном обледенении
+    fn loads(&self, areas: &[(f64, Bound, Position)], load: f64) -> Result<Vec<LoadSpace>, Error> {
+        areas
+            .iter()
+            .map(|(area, bound, center)| LoadSpace::new(area * load * self.ratio, *bound, *center))
+            .collect()
+    }
+    /// Грузы от льда на открытых палубах
+    pub fn deck(&self) -> Result<Vec<LoadSpace>, Error> {
+        self.loads(&self.deck, DECK_LOAD)
+    }
+    /// Грузы от льда на площадях парусности
+    pub fn windage(&self) -> Result<Vec<LoadSpace>, Error> {
+        self.loads(&self.windage, WINDAGE_LOAD)
+    }
+    /// Общая масса льда
+    pub fn mass(&self) -> f64 {
+        let deck: f64 = self.deck.iter().map(|v| v.0).sum();
+        let windage: f64 = self.windage.iter().map(|v| v.0).sum();
+        (deck * DECK_LOAD + windage * WINDAGE_LOAD) * self.ratio
+    }
+}
@@ -0,0 +1,107 @@
+//! Груз - масса судна порожнем
+use crate::{
+    error::Error,
+    load::ILoad,
+    math::{bound::Bound, curve::{Curve, ICurve}, position::Position},
+};
+
+/// Масса судна порожнем, реализует интерфейс ILoad. Масса распределяется
+/// по длине судна с интенсивностью $q(x) = q_0(x) (\alpha + \beta x)$, где
+/// $q_0$ - относительная интенсивность из таблицы коэффициентов для судна,
+/// по умолчанию постоянная (метод трапеции). Коэффициенты $\alpha, \beta$
+/// подбираются так, чтобы масса и ее момент соответствовали заданным массе
+/// и отстоянию центра масс по длине:
+/// $\alpha I_0 + \beta I_1 = m$, $\alpha I_1 + \beta I_2 = m x_g$, $I_k = \int q_0 x^k dx$.
+pub struct Lightship {
+    /// общая масса
+    mass: f64,
+    /// центер масс
+    center: Position,
+    /// границы распределения массы по длине
+    bound: Bound,
+    /// относительная интенсивность распределения массы
+    shape: Curve,
+    /// координаты точек излома относительной интенсивности
+    keys: Vec<f64>,
+    /// постоянная часть множителя интенсивности
+    alpha: f64,
+    /// линейная часть множителя интенсивности
+    beta: f64,
+}
+///
+impl Lightship {
+    /// Основной конструктор. Аргументы:
+    /// - mass: общая масса
+    /// - center: центер масс
+    /// - bound: границы распределения массы по длине
+    /// - shape: относительная интенсивность распределения массы (x, значение),
+    ///   если не задана - постоянная по длине
+    pub fn new(mass: f64, center: Position, bound: Bound, shape: Option<Vec<(f64, f64)>>) -> Result<Self, Error> {
+        if mass <= 0. {
+            return Err(Error::Lightship(format!("mass {mass} must be greater than 0")));
+        }
+        if bound.start() >= center.x() || bound.end() <= center.x() {
+            return Err(Error::Lightship(format!("bound ({}, {}) must contain center.x {}", bound.start(), bound.end(), center.x())));
+        }
+        let shape = shape.unwrap_or(vec![(bound.start(), 1.), (bound.end(), 1.)]);
+        if let Some(v) = shape.iter().find(|v| v.1 < 0.) {
+            return Err(Error::Lightship(format!("shape value {} must be greater or equal to 0", v.1)));
+        }
+        let keys = shape.iter().map(|v| v.0).filter(|x| *x > bound.start() && *x < bound.end()).collect();
+        let mut result = Self { mass, center, bound, shape: Curve::new(shape)?, keys, alpha: 0., beta: 0. };
+        let integral = |k: i32| result.integral(&bound, |x| result.shape.value(x) * x.powi(k));
+        let (i_0, i_1, i_2) = (integral(0), integral(1), integral(2));
+        let det = i_0 * i_2 - i_1 * i_1;
+        if det <= 0. {
+            return Err(Error::Lightship(format!("shape must have nonzero values at two points at least, det:{det}")));
+        }
+        let moment = mass * center.x();
+        result.alpha = (mass * i_2 - moment * i_1) / det;
+        result.beta = (moment * i_0 - mass * i_1) / det;
+        for x in [bound.start(), bound.end()].into_iter().chain(result.keys.iter().copied()) {
+            if result.intensity(x) < -0.000001 * mass {
+                return Err(Error::Lightship(format!(
+                    "intensity {} at x {x} is negative, center.x {} is too far from shape center",
+                    result.intensity(x),
+                    center.x()
+                )));
+            }
+        }
+        Ok(result)
+    }
+    /// Интенсивность распределения массы, т/м
+    /// - x: координата по длине
+    pub fn intensity(&self, x: f64) -> f64 {
+        self.shape.value(x) * (self.alpha + self.beta * x)
+    }
+    /// Интеграл в пределах диапазона по длине, методом Симпсона между
+    /// точками излома, точный для относительной интенсивности,
+    /// умноженной на полином не выше первой степени
+    /// - bound: диапазон по длине
+    /// - f: подынтегральная функция
+    fn integral(&self, bound: &Bound, f: impl Fn(f64) -> f64) -> f64 {
+        let mut points: Vec<f64> = self.keys.iter().copied().filter(|x| *x > bound.start() && *x < bound.end()).collect();
+        points.insert(0, bound.start());
+        points.push(bound.end());
+        points
+            .windows(2)
+            .map(|v| (v[1] - v[0]) / 6. * (f(v[0]) + 4. * f((v[0] + v[1]) / 2.) + f(v[1])))
+            .sum()
+    }
+}
+///
+impl ILoad for Lightship {
+    fn mass(&self, bound: Option<Bound>) -> f64 {
+        match bound {
+            Some(bound) => match self.bound.intersect(&bound) {
+                Some(bound) => self.integral(&bound, |x| self.intensity(x)),
+                None => 0.,
+            },
+            None => self.mass,
+        }
+    }
+
+    fn center(&self) -> Position {
+        self.center
+    }
+}
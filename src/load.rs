@@ -1,5 +1,5 @@
 //! Нагрузка на судно: постоянный и переменный груз
-use crate::math::{bound::Bound, mass_moment::MassMoment, position::Position, surface_moment::SurfaceMoment};
+use crate::{error::Error, math::{bound::Bound, distribution::Distribution, mass_moment::MassMoment, position::Position, surface_moment::SurfaceMoment}};
 
 
 /// Абстрактный груз: контейнер, трюм или бак.
@@ -17,32 +17,183 @@ pub trait ILoad {
     fn moment_surface(&self) -> SurfaceMoment {
         SurfaceMoment::new(0., 0.,)
     }
+    /// наибольший по заполнению момент свободной поверхности
+    fn moment_surface_max(&self) -> SurfaceMoment {
+        self.moment_surface()
+    }
+    /// учет момента свободной поверхности в нагрузке судна
+    fn free_surface(&self) -> FreeSurface {
+        FreeSurface::Actual
+    }
+    /// кренящий момент от смещения зерна
+    fn moment_grain(&self) -> f64 {
+        0.
+    }
+    /// наименование груза
+    fn name(&self) -> String {
+        String::new()
+    }
+    /// категория груза
+    fn category(&self) -> Category {
+        Category::Other
+    }
+}
+
+/// Учет момента свободной поверхности жидкого груза в нагрузке судна
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FreeSurface {
+    /// по фактическому объему жидкости
+    Actual,
+    /// наибольший по кривой момента инерции, для расходных цистерн
+    Max,
+    /// заданный момент свободной поверхности
+    Fixed(SurfaceMoment),
+    /// не учитывается, для запрессованных и пустых цистерн
+    None,
+}
+///
+impl FreeSurface {
+    /// Момент свободной поверхности груза с учетом способа учета
+    pub fn moment(&self, load: &dyn ILoad) -> SurfaceMoment {
+        match self {
+            FreeSurface::Actual => load.moment_surface(),
+            FreeSurface::Max => load.moment_surface_max(),
+            FreeSurface::Fixed(moment) => *moment,
+            FreeSurface::None => SurfaceMoment::new(0., 0.),
+        }
+    }
+}
+
+/// Категория груза для сводки по дедвейту
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    /// судно порожнем
+    Lightship,
+    /// груз
+    Cargo,
+    /// балласт
+    Ballast,
+    /// топливо
+    Fuel,
+    /// смазочное масло
+    LubricatingOil,
+    /// пресная вода
+    FreshWater,
+    /// снабжение
+    Stores,
+    /// экипаж
+    Crew,
+    /// обледенение
+    Ice,
+    /// прочее
+    Other,
+}
+///
+impl Category {
+    /// Наименование категории
+    pub fn name(&self) -> &'static str {
+        match self {
+            Category::Lightship => "lightship",
+            Category::Cargo => "cargo",
+            Category::Ballast => "ballast",
+            Category::Fuel => "fuel",
+            Category::LubricatingOil => "lubricating_oil",
+            Category::FreshWater => "fresh_water",
+            Category::Stores => "stores",
+            Category::Crew => "crew",
+            Category::Ice => "ice",
+            Category::Other => "other",
+        }
+    }
 }
 
-/// Груз, контенер, трюм и т.п. твердый груз, имеет границы, центр масс и значение
+/// Груз с наименованием и категорией, остальные свойства берутся у исходного груза
+pub struct NamedLoad {
+    /// наименование груза
+    name: String,
+    /// категория груза
+    category: Category,
+    /// исходный груз
+    load: Box<dyn ILoad>,
+}
+///
+impl NamedLoad {
+    /// Основной конструктор. Аргументы:
+    /// - name: наименование груза
+    /// - category: категория груза
+    /// - load: исходный груз
+    pub fn new(name: String, category: Category, load: Box<dyn ILoad>) -> Self {
+        Self { name, category, load }
+    }
+}
+///
+impl ILoad for NamedLoad {
+    fn center(&self) -> Position {
+        self.load.center()
+    }
+
+    fn mass(&self, bound: Option<Bound>) -> f64 {
+        self.load.mass(bound)
+    }
+
+    fn moment_mass(&self) -> MassMoment {
+        self.load.moment_mass()
+    }
+
+    fn moment_surface(&self) -> SurfaceMoment {
+        self.load.moment_surface()
+    }
+
+    fn moment_surface_max(&self) -> SurfaceMoment {
+        self.load.moment_surface_max()
+    }
+
+    fn free_surface(&self) -> FreeSurface {
+        self.load.free_surface()
+    }
+
+    fn moment_grain(&self) -> f64 {
+        self.load.moment_grain()
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn category(&self) -> Category {
+        self.category
+    }
+}
+
+/// Груз, контенер, трюм и т.п. твердый груз, имеет границы, центр масс и значение.
+/// Масса распределяется по длине в границах груза так, чтобы ее центр
+/// совпадал с центром масс груза, см. [Distribution]
 pub struct LoadSpace {
     /// общая масса
     mass: f64,     
-    /// границы груза
-    bound: Bound,  
+    /// распределение массы по длине в границах груза
+    distribution: Distribution,  
     /// центер масс
     center: Position, 
 }
 
-#[allow(dead_code)]
 impl LoadSpace {
     ///
-    pub fn new(mass: f64, bound: Bound, center: Position) -> Self {
-        assert!(bound.start() < center.x(), "bound.start {} < pos.x {}", bound.start(), center.x());
-        assert!(bound.end() > center.x(), "bound.end {} > pos.x {}", bound.end(), center.x());
-        Self { bound, center, mass }
+    pub fn new(mass: f64, bound: Bound, center: Position) -> Result<Self, Error> {
+        if mass < 0. {
+            return Err(Error::LoadSpace(format!("mass {mass} must be greater or equal to 0")));
+        }
+        if bound.start() >= center.x() || bound.end() <= center.x() {
+            return Err(Error::LoadSpace(format!("bound ({}, {}) must contain center.x {}", bound.start(), bound.end(), center.x())));
+        }
+        Ok(Self { distribution: Distribution::new(bound, center.x())?, center, mass })
     }
 }
 
 impl ILoad for LoadSpace {
     fn mass(&self, bound: Option<Bound>) -> f64 {
         if let Some(bound) = bound {
-            self.bound.part_ratio(&bound)*self.mass
+            self.distribution.part_ratio(&bound)*self.mass
         } else {
             self.mass
         }
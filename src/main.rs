@@ -8,6 +8,38 @@
 //!   - rad_long: кривая продольного метацентрического радиуса,
 //!   - mean_draught: кривая средней осадки,
 //!   - center_shift: кривая отстояния центра величины погруженной части судна,
+//!   - rad_trans: кривая поперечного метацентрического радиуса, необязательно,
+//!   - cross_curves: пантокарены (объемное водоизмещение, [(угол крена в градусах, KN)]), необязательно,
+//!   - flooding_angle: угол заливания в градусах для критериев остойчивости, необязательно,
+//!   - weather: данные для проверки критерия погоды, необязательно:
+//!      - breadth: ширина корпуса судна,
+//!      - block_coefficient: коэффициент общей полноты,
+//!      - bilge_keel_area: суммарная площадь скуловых килей, по умолчанию 0,
+//!      - hard_chine: судно с острой скулой, по умолчанию false,
+//!      - windage: парусность [(средняя осадка, площадь, возвышение центра парусности
+//!        над центром площади проекции подводной части на ДП)],
+//!   - strength_limits: допустимые значения срезающих сил и изгибающих моментов
+//!      в море (sea) и в порту (harbor), кривые по длине судна (x, значение по модулю):
+//!      sf_positive, sf_negative, bm_hogging, bm_sagging, необязательно,
+//!   - wave: данные для вычисления волновых нагрузок по IACS UR S11, необязательно,
+//!      только для судов длинной от 90 до 500 м:
+//!      - breadth: ширина корпуса судна,
+//!      - block_coefficient: коэффициент общей полноты,
+//!      - m, f1, f2: коэффициенты распределения (x/L от кормы, значение), по умолчанию по правилам,
+//!   - lightship: масса судна порожнем, необязательно, добавляется к грузам:
+//!      - mass: общая масса, center: центер масс,
+//!      - distribution: относительная интенсивность распределения массы по длине (x, значение),
+//!        по умолчанию постоянная (метод трапеции); интенсивность умножается на линейную функцию,
+//!        подобранную так, чтобы масса и ее момент соответствовали mass и center,
+//!   - stowage: грузовой план контейнеров, необходим для контейнеров варианта загрузки, необязательно:
+//!      - bays: беи (номер, x1, x2) - границы контейнера по длине,
+//!      - rows: ряды (номер, y) - отстояние центра масс контейнера по ширине от ДП,
+//!      - tiers: ярусы (номер, z) - аппликата центра масс контейнера,
+//!      - stack_limits: допустимые массы штабелей (бей, ряд, масса), необязательно,
+//!   - ice: данные для расчета обледенения, необходимы для обледенения варианта загрузки, необязательно:
+//!      - deck: горизонтальные проекции открытых палуб, windage: площади парусности - проекции
+//!        надводной части на ДП, каждая площадь задается площадью area, м^2, границами по длине
+//!        bound (x1, x2) и центром площади center (x, y, z),
 //!   - массив шпангоутов судна [(index, immersion_area)], где:
 //!      - index: порядковый номер шпангоута,
 //!      - immersion_area: кривая погружаемой площади,
@@ -15,17 +47,137 @@
 //!      - mass: общая масса груза,
 //!      - bound: границы груза,
 //!      - center: центер масс;
+//!   - массив данных по зерну навалом в трюмах grain, необязательно, в составе:
+//!      - stowage_factor: удельный погрузочный объем, м^3/т,
+//!      - volume: объем зерна в трюме, capacity: вместимость трюма,
+//!      - bound: границы трюма, center: центер масс,
+//!      - moment: кривая объемного кренящего момента по объему зерна в трюме (volume, м^4);
+//!   - массив данных по навалочным грузам в трюмах bulk, необязательно, в составе:
+//!      - mass: масса груза, stowage_factor: удельный погрузочный объем, м^3/т,
+//!        или density: плотность груза навалом, т/м^3,
+//!      - bound: границы трюма, (x1, x2, y1, y2),
+//!      - center: кривая координат центра масс груза по объему груза (volume, x, y, z),
+//!      - level: кривая уровня поверхности груза по объему груза (volume, z), наибольший
+//!        объем - вместимость трюма, превышение вместимости - ошибка;
 //!   - массив данных по цистернам в составе:
 //!      - density: плотность жидкости в цистерне,
-//!      - volume: объем жидкости в цистерне,
+//!      - volume: объем жидкости в цистерне, если не задано заполнение fill, должен быть задан
+//!        объем или заполнение,
+//!      - fill: заполнение цистерны, необязательно: kind - вид заполнения "sounding" (уровень),
+//!        "ullage" (пустота), "volume" (объем), "percent" (процент вместимости) или "mass" (масса),
+//!        value - значение, trim и heel - дифферент в метрах и крен в градусах при замере уровня,
+//!        по умолчанию 0; заполнение приводится к объему, превышение вместимости - ошибка,
+//!        без калибровочной таблицы вместимость - наибольший объем кривой center,
+//!      - calibration: калибровочная таблица цистерны, необходима для заполнения по уровню,
+//!        пустоте и проценту, необязательно: height - высота, от которой отсчитывается пустота,
+//!        volume - объем по уровню (уровень, объем), trim и heel - поправки уровня на дифферент
+//!        и крен (дифферент или крен, [(уровень, поправка)]), необязательно,
 //!      - bound: границы цистерны, (x1, x2, y1, y2),
 //!      - center: кривая координат центра объема жидкости в цистерне
 //!         в системе координат судна (volume, x, y, z),   
 //!      - free_surf_inertia: кривая момента инерции площади свободной  
-//!         поверхности жидкости (volume, x - поперечный, y - продольный).
-//!   Выходные данные:
-//!   - массив значений срезывающих сил,
-//!   - массив значений изгибающих моментов.
+//!         поверхности жидкости (volume, x - поперечный, y - продольный),
+//!      - free_surface: учет момента свободной поверхности, необязательно: kind - "actual"
+//!        (по фактическому объему, по умолчанию), "max" (наибольший по кривой free_surf_inertia,
+//!        для расходных цистерн), "fixed" (заданный момент moment (x, y), т·м) или "none" (не учитывается,
+//!        для запрессованных и пустых цистерн); используется в поправках delta_m_h.
+//!   Варианты загрузки читаются из стандартного потока ввода в формате JSON Lines,
+//!   каждая строка - вариант загрузки одного и того же судна: project_name, ship_name,
+//!   n_parts, water_density, condition - условие эксплуатации "sea" или "harbor"
+//!   (по умолчанию "sea"), heel_angles - углы крена в градусах для диаграммы остойчивости
+//!   (по умолчанию через 10 градусов) и, при необходимости, собственные load_space, grain, bulk и tanks,
+//!   собственные load_space, grain и bulk вместе заменяют грузы из источника. Для проверки посадки
+//!   и прочности после повреждения задаются затопленные отсеки flooded: bound - границы (x1, x2, y1, y2),
+//!   bound_z - границы по высоте от ОП (z1, z2), permeability - коэффициент проницаемости; отсек,
+//!   выходящий за наибольшую ширину корпуса по кривым погруженной площади шпангоутов, - ошибка.
+//!   Контейнеры варианта загрузки containers задаются по ячейкам грузового плана: bay, row, tier - номера
+//!   бея, ряда и яруса, mass - масса контейнера; контейнер распределяется по длине бея.
+//!   Расход запасов на переходе voyage: duration - продолжительность перехода, ч, step - шаг по времени
+//!   между промежуточными состояниями, ч (по умолчанию 24), levels - уровни заполнения расходных цистерн
+//!   в % от вместимости, при достижении которых также вычисляется состояние, consumption - расход из цистерн
+//!   (tank - индекс цистерны, rate - расход, т/ч), расходы из одной цистерны суммируются.
+//!   Обледенение icing: "full" - полное, 30 кг/м^2 на открытых палубах и 15 кг/м^2 на площади
+//!   парусности, или "half" - половинное, вдвое меньше; лед распределяется по длине в границах
+//!   площадей и входит в нагрузку судна для посадки, срезающих сил и изгибающих моментов.
+//!   Условия сходимости расчета посадки convergence, необязательно: mass_tolerance - допустимая невязка
+//!   массы, доля от массы судна (по умолчанию 0.00001), shift_tolerance - допустимая невязка отстояния
+//!   центра величины по длине, м (по умолчанию 0.0001), max_iterations - наибольшее количество итераций
+//!   (по умолчанию 100).
+//!   Каждый груз load_space, grain, bulk и tanks может иметь наименование name и категорию category:
+//!   "cargo", "ballast", "fuel", "lubricating_oil", "fresh_water", "stores", "crew" или "other",
+//!   по умолчанию наименование по виду груза и номеру, категория "cargo", для цистерн - "other".
+//!   Данные по корпусу судна загружаются один раз для всех вариантов.
+//!   Выходные данные, по одной строке JSON на вариант загрузки в стандартный поток вывода:
+//!   - массив значений срезывающих сил (координата по х, значение),
+//!   - массив значений изгибающих моментов (координата по х, значение),
+//!   - equilibrium: посадка судна - осадки на миделе, кормой и носом, дифферент в метрах
+//!      и градусах, количество итераций и невязки массы и отстояния центра величины,
+//!      угол крена heel в градусах: по пантокаренам, если они заданы, иначе для малых углов
+//!      из поперечной метацентрической высоты $\tan\theta = (y_g - y_c)/h$; не выводится, если
+//!      положение равновесия не найдено (например при h <= 0), это не мешает расчету прочности,
+//!   - stability, если задана кривая rad_trans: аппликата поперечного метацентра z_m = z_c + r,
+//!      аппликата центра масс z_g, поперечная метацентрическая высота h_0 = z_m - z_g, поправка
+//!      на влияние свободной поверхности жидкости в цистернах delta_m_h и исправленная h = h_0 - delta_m_h,
+//!   - righting_lever, если заданы пантокарены: плечи статической остойчивости
+//!      $l = KN - (z_g + \Delta m_h) \sin\theta$ и динамической остойчивости (угол крена, плечо)
+//!      для углов heel_angles, максимальное плечо max (угол, плечо) и угол заката angle_vanishing,
+//!   - criteria, если заданы пантокарены: общие критерии остойчивости IS Code 2008 - площади под диаграммой
+//!      area_0_30, area_0_40 и area_30_40 (до 40° или угла заливания), плечо при угле крена 30° и более lever_30,
+//!      угол максимума диаграммы angle_max_lever и начальная метацентрическая высота metacentric_height
+//!      (по rad_trans, если задана, иначе по наклону диаграммы) - фактическое actual и требуемое required
+//!      значения и признак pass, а также общий признак pass - вариант загрузки удовлетворяет всем критериям;
+//!      если заданы данные weather, проверяется также критерий погоды: weather_angle_0 - угол крена
+//!      от постоянного ветра не более 16°, weather_area_b - площадь b не менее площади a; при h <= 0
+//!      качка не вычисляется, оба критерия не выполнены, их значения null;
+//!      если в трюмах есть зерно - критерии Международного кодекса по перевозке зерна: grain_angle - угол
+//!      крена от смещения зерна не более 12°, grain_area - остаточная площадь не менее 0.075 м·рад,
+//!      grain_metacentric_height - начальная метацентрическая высота не менее 0.3 м,
+//!   - weather, если заданы пантокарены и данные weather и h > 0: кренящие плечи lever_1 и lever_2,
+//!      углы крена от постоянного ветра angle_0, амплитуда качки angle_1, угол angle_2, ограничивающий
+//!      площадь b, и площади area_a, area_b, м·рад,
+//!   - grain, если заданы пантокарены и в трюмах есть зерно: кренящий момент от смещения зерна
+//!      moment, т·м, кренящие плечи lever_0 и lever_40, угол крена angle, угол angle_limit,
+//!      ограничивающий остаточную площадь, и остаточная площадь area, м·рад,
+//!   - voyage, если задан расход запасов: состояния судна conditions от отхода до прихода - время time, ч,
+//!      масса израсходованных запасов consumed и полный результат расчета data для состояния, индекс
+//!      наихудшего состояния worst - с наибольшим использованием допустимых значений, если они заданы,
+//!      иначе с наибольшим по модулю изгибающим моментом,
+//!   - bulk, если есть навалочные грузы: объем груза volume, уровень поверхности level,
+//!      центр масс center и признак заполнения трюма filled для каждого трюма,
+//!   - summary: грузы loads (name, category, mass, center), итоги по категориям categories -
+//!      масса mass, центр масс center и момент свободной поверхности moment_surface, дедвейт
+//!      deadweight - масса всех грузов, кроме судна порожнем, и водоизмещение displacement,
+//!   - ice, если задано обледенение: масса льда на палубах deck, на площадях парусности windage,
+//!      общая масса mass и центр масс center,
+//!   - stowage, если заданы контейнеры: количество count и общая масса mass контейнеров, массы штабелей
+//!      stacks (bay, row, mass, допустимая масса limit, признак pass) и общий признак pass,
+//!   - damage, если заданы затопленные отсеки: посадка и прочность после затопления методом
+//!      постоянного водоизмещения - теряемый объем lost_volume, посадка equilibrium (крен для малых углов
+//!      с учетом потери момента инерции ватерлинии, если задана кривая rad_trans), исправленная
+//!      метацентрическая высота после затопления metacentric_height, если задана кривая rad_trans,
+//!      по центру величины и моменту инерции ватерлинии при объеме корпуса с учетом теряемого
+//!      (при неположительной крен не выводится - нет равновесия в прямом положении), эпюры shear_force,
+//!      bending_moment и использование допустимых значений utilisation, если они заданы,
+//!   - hydrostatics_mismatch: точки кривых mean_draught, center_waterline, rad_long и center_shift,
+//!      а также площади ватерлинии waterplane_area (производной объема по осадке кривой mean_draught),
+//!      расходящиеся более чем на 2% с вычисленными по кривым погруженной площади шпангоутов
+//!      (curve, volume, supplied, derived), выводится при наличии расхождений; сверка выполняется
+//!      один раз при загрузке данных по судну, ее ошибка не мешает расчету,
+//!   - utilisation, если заданы strength_limits: использование допустимых значений
+//!      для условия эксплуатации в процентах по срезающей силе и изгибающему моменту
+//!      (координата по х, значение), их максимумы shear_force_max, bending_moment_max
+//!      и признак pass - допустимые значения не превышены,
+//!   - wave, если заданы данные для вычисления волновых нагрузок: волновые изгибающие моменты
+//!      на перегиб и прогиб, положительная и отрицательная волновые срезающие силы и огибающие
+//!      суммарных эпюров (на тихой воде и волновых) total_*_max, total_*_min.
+//!   При ошибке варианта выводится {"error": описание ошибки}, код завершения 1.
+//!
+//!   Данные по судну выбираются по project_name и ship_name из источника,
+//!   заданного аргументами командной строки:
+//!   - `--api <address> <database>`: сервер api, токен авторизации задается переменной окружения
+//!      SSS_API_AUTH_TOKEN,
+//!   - `--dir <path>`: каталог с файлами JSON `<path>/<project_name>/<ship_name>/{ship,frames,loads,tanks}.json`,
+//!   - `--sqlite <path>`: локальная база данных SQLite, схема в [data::source::sqlite::SCHEMA].
 //!
 //!   Общее описание и порядок расчетов:
 //!   1. Вычисляется общая масса судна путем суммирования всех нагрузок. Из общей массы по кривой водоизмещения с учетом плотности воды вычисляется объемное водоизмещение $\nabla = \Delta/\rho$.
@@ -38,41 +190,68 @@
 //!      - поперечный $r$ и продольный $R$ метацентрические радиусы, м;
 //!      - среднюю осадку $d$;
 //!   Для промежуточных значений определяется линейной интерполяцией. С учетом поправки на влияние свободной поверхности жидкости в цистернах вычисляется дифферент судна.
-//!   3. Из дифферента и средней осадки вычисляется осадка носа и кормы. Из них методом линейной интерполяции вычисляется распределение осадки по каждой шпации.
+//!   3. Из дифферента и средней осадки вычисляется начальное приближение посадки судна. Осадка на миделе $d$ и дифферент $t$ уточняются итерационно методом Ньютона
+//!      до совпадения массы вытесненной воды, вычисленной по кривым погруженной площади шпангоутов, с массой судна $\Delta$ и отстояния центра величины по длине
+//!      с отстоянием центра масс $x_g$ в пределах допуска. Осадка в каждой шпации вычисляется линейно: $d_i = d + t*x_i/L$.
 //!   4. Вычисляется вытесненную массу воды для каждой шпации. Погруженная площадь $S_{start}, S_{end}$ теоретических шпангоутов берется из кривых. $L_{start}, L_{end}$ - расстояние от кормы до шпангоутов, ограничивающих шпацию. Вытесненная масса воды Buoyancy вычисляется как среднее значение погруженной площади умноженное на плотность воды $\gamma$ и на разницу расстояний до теоретических шпангоутов: $$V_i = (S_{start_i} + S_{end_i})/2*(L_{end_i}-L_{start_i})*\gamma$$
 //!   5. Вычисляется результирующая сила TotalForce для каждой шпации как разницу веса вытесненной воды и массы приходящейся на каждую шпацию, умноженную на гравитационную постоянную g: $Ft_i = (m_i - V_i)*g$.
+//!      Масса груза распределяется по длине в его границах так, чтобы центр распределенной массы совпадал
+//!      с центром масс груза: по трапеции, если центр масс отстоит от середины границ не более чем на 1/6 их длины
+//!      (при совпадении - равномерно), иначе по треугольнику с вершиной на ближайшей к центру масс границе.
 //!   6. Вычисляется срезающуя сила ShearForce для каждой шпации через интегрирование. Интегрирование проводим путем вычисления суммы сверху: $Fs_i = Fs_{i-1} + Ft_i, Fs_0 = 0$.
 //!   7. Вычисляется изгибающий момент BendingMoment для каждой шпации как интегриральнуа сумма срезающей силы:
 //!      $M_i = M_{i-1} + Fs_{i-1} + Fs_i, M_0 = 0$.
+//!      Положительный изгибающий момент соответствует перегибу, отрицательный - прогибу.
+//!   8. Вычисляется использование допустимых значений: отношение модуля значения к допустимому значению
+//!      того же знака в той же точке по длине судна, $U_i = |V_i|/L(x_i)*100$.
 
-use std::{io, process, rc::Rc};
+use std::{env, io::{self, BufRead, Write}, process};
 
-use api_tools::client::{
-    api_query::{ApiQuery, ApiQueryKind, ApiQuerySql},
-    api_request::ApiRequest,
-};
 use data::parse_input::ParsedInputData;
 use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
 use log::*;
 use testing::entities::test_value::Value;
 
 use crate::{
-    bending_moment::BendingMoment, data::parse_input::ParsedShipData, displacement::Displacement, draught::Draught, frame::Frame, load::ILoad, mass::{IMass, Mass}, math::{bound::Bound, curve::Curve, inertia_shift::inertia_shift::InertiaShift, pos_shift::PosShift}, shear_force::{IShearForce, ShearForce}, tank::Tank, total_force::TotalForce, trim::Trim
+    batch::Batch, data::{serialize_out::OutError, source::{api::ApiSource, file::FileSource, sqlite::SqliteSource, IDataSource}},
 };
 
+mod batch;
 mod bending_moment;
+mod bulk;
+mod calibration;
+mod computer;
+mod criteria;
+mod cross_curves;
 mod data;
 mod displacement;
-mod draught;
+mod equilibrium;
+mod error;
+mod flooding;
 mod frame;
+mod grain;
+mod grain_heel;
+mod heel;
+mod hold;
+mod hydrostatics;
+mod ice;
+mod lightship;
 mod load;
 mod mass;
 mod math;
+mod metacentric_height;
+mod righting_lever;
 mod shear_force;
+mod stowage;
+mod summary;
 mod tank;
 mod tests;
 mod total_force;
 mod trim;
+mod utilisation;
+mod voyage;
+mod wave;
+mod weather;
 
 fn main() {
     DebugSession::init(LogLevel::Debug, Backtrace::Short);
@@ -87,136 +266,73 @@ fn main() {
     let value = Value::String("66.77".to_string());
     debug!("\t string value: {:?}", value);
 
-    let data = read().unwrap_or_else(|err| {
-        error!("Parsing arguments: {err}");
+    let source = source(env::args().skip(1).collect()).unwrap_or_else(|err| {
+        error!("{err}");
+        write(io::stdout(), &OutError::new(err.to_string())).unwrap_or_else(|err| {
+            error!("write: {err}");
+        });
         process::exit(1);
     });
-
-    let query = ApiQuery::new(
-        ApiQueryKind::Sql(ApiQuerySql::new("database", "sql")),
-        false,
-    );
-    let mut request = ApiRequest::new(
-        "parent",
-        "address",
-        "auth_token",
-        query.clone(),
-        false,
-        false,
-    );
-   // let reply = request.fetch(&query, false);
-
-    let reply_data = request.fetch(&query, false).unwrap_or_else(|err| {
-        error!("request.fetch: {err}");
-        process::exit(1);
-    });
-    let string = String::from_utf8(reply_data).unwrap_or_else(|err| {
-        error!("String::from_utf8: {err}");
-        process::exit(1);
-    });
-    let ship_data = ParsedShipData::parse(&string.to_lowercase().trim().to_owned()).unwrap_or_else(|err| {
-        error!("ParsedShipData::parse: {err}");
-        process::exit(1);
-    });
-
-    // длинна судна
-    let ship_length = 118.39;
-    let n = 20;
-    let delta_x = ship_length / n as f64;
-    let start_x = -ship_length / 2.;
-    // вектор разбиения судна на отрезки
-    let bounds = (0..n as usize)
-        .map(|v| {
-            Bound::new(
-                start_x + delta_x * v as f64,
-                start_x + delta_x * (v as f64 + 1.),
-            )
-        })
-        .collect::<Vec<_>>();
     // ускорение свободного падения
     let gravity_g = 9.81;
-    // плотность окружающей воды
-    let water_density = 1.025;
-    // отстояние центра тяжести ватерлинии по длине от миделя
-    let center_waterline_shift = Curve::new(vec![(0., 0.), (10., 1.)]);
-    // продольный метацентрический радиус
-    let rad_long = Curve::new(vec![(0., 0.), (10., 1.)]);
-    // средняя осадка
-    let mean_draught = Curve::new(vec![(0., 0.), (1000., 1.), (10000., 10.)]);
-    // отстояние центра величины погруженной части судна
-    let center_draught_shift = PosShift::new(
-        Curve::new(vec![(0., 2.), (10., 2.)]),
-        Curve::new(vec![(0., 0.), (10., 0.)]),
-        Curve::new(vec![(0., 0.), (10., 0.)]),
-    );
-    //координаты центра объема жидкости в цистерне в системе координат судна
-    let tank_center_draught_shift = PosShift::new(
-        Curve::new(vec![(0., 2.), (10., 2.)]),
-        Curve::new(vec![(0., 0.), (10., 0.)]),
-        Curve::new(vec![(0., 0.), (10., 0.)]),
-    );
-    //момент инерции площади свободной поверхности жидкости
-    let tank_free_surf_inertia = InertiaShift::new(
-        Curve::new(vec![(0., 0.), (10., 1.)]),
-        Curve::new(vec![(0., 0.), (10., 1.)]),
-    );
-    // все грузы судна
-    let loads: Vec<Rc<Box<dyn ILoad>>> = vec![Rc::new(Box::new(Tank::new(
-        2.,
-        10.,
-        Bound::new(-5., 5.),
-        tank_center_draught_shift,
-        tank_free_surf_inertia,
-    )))];
-    let mass: Rc<dyn IMass> = Rc::new(Mass::new(loads, bounds.clone()));
-    let frames = vec![
-        Frame::new(Curve::new(vec![(0., 0.), (10., 10.)])),
-        Frame::new(Curve::new(vec![(0., 0.), (10., 10.)])),
-        Frame::new(Curve::new(vec![(0., 0.), (10., 10.)])),
-    ];
-
-    let shear_force = ShearForce::new(TotalForce::new(
-        Rc::clone(&mass),
-        Draught::new(
-            ship_length,
-            water_density,
-            bounds,
-            Rc::clone(&mass),
-            center_waterline_shift,
-            mean_draught,
-            Displacement::new(frames, ship_length),
-            Trim::new(
-                water_density,
-                ship_length,
-                center_draught_shift, // отстояние центра величины погруженной части судна
-                rad_long,             // продольный метацентрические радиус
-                Rc::clone(&mass),     // все грузы судна
-            ),
-        ),
-        gravity_g,
-    ));
-    let bending_moment = BendingMoment::new(&shear_force);
-    dbg!(&shear_force.values(), &bending_moment.values());
+    let mut batch = Batch::new(gravity_g, source);
+    let mut failed = false;
+    for line in io::stdin().lock().lines() {
+        if line.as_ref().is_ok_and(|line| line.trim().is_empty()) {
+            continue;
+        }
+        let result = line
+            .map_err(|err| err.to_string())
+            .and_then(|line| ParsedInputData::parse(line.trim()).map_err(|err| format!("Parsing arguments: {err}")))
+            .and_then(|data| batch.compute(data).map_err(|err| err.to_string()));
+        let result = match result {
+            Ok(out_data) => write(io::stdout(), &out_data),
+            Err(err) => {
+                error!("{err}");
+                failed = true;
+                write(io::stdout(), &OutError::new(err))
+            }
+        };
+        result.unwrap_or_else(|err| {
+            error!("write: {err}");
+            process::exit(1);
+        });
+    }
+    if failed {
+        process::exit(1);
+    }
 }
 
-/// Чтение данных из стандартного потока ввода
-pub fn read() -> Result<ParsedInputData, Box<dyn std::error::Error>> {
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
-    Ok(ParsedInputData::parse(
-        &input.to_lowercase().trim().to_owned(),
-    )?)
+/// Переменная окружения с токеном авторизации сервера api
+const API_AUTH_TOKEN: &str = "SSS_API_AUTH_TOKEN";
+
+/// Источник данных по судну, выбирается аргументами командной строки:
+/// - `--api <address> <database>`: сервер api, токен авторизации
+///   берется из переменной окружения [API_AUTH_TOKEN]
+/// - `--dir <path>`: каталог с файлами JSON
+/// - `--sqlite <path>`: локальная база данных SQLite
+fn source(args: Vec<String>) -> Result<Box<dyn IDataSource>, Box<dyn std::error::Error>> {
+    match args.iter().map(|s| s.as_str()).collect::<Vec<_>>().as_slice() {
+        ["--api", address, database] => {
+            let auth_token = env::var(API_AUTH_TOKEN).map_err(|err| format!("{API_AUTH_TOKEN}: {err}"))?;
+            Ok(Box::new(ApiSource::new(address, &auth_token, database)))
+        }
+        ["--dir", path] => Ok(Box::new(FileSource::new(path))),
+        ["--sqlite", path] => Ok(Box::new(SqliteSource::open(path)?)),
+        _ => Err(format!(
+            "Invalid arguments: {:?}, expected --api <address> <database>, --dir <path> or --sqlite <path>",
+            args
+        )
+        .into()),
+    }
 }
 
-/*
-/// Writes a given value to the writer, serializing it into JSON.
-pub fn write<W: Write, T: serde::Serialize>(mut writer: W, t: &T) -> Result<(), WriteError> {
+/// Запись значения в поток вывода в формате JSON
+pub fn write<W: Write, T: serde::Serialize>(mut writer: W, t: &T) -> Result<(), Box<dyn std::error::Error>> {
     // We use to_string here instead of to_vec because it verifies that the JSON is valid UTF-8,
     // which is required by the JSON Lines specification (https://jsonlines.org).
-    let json = serde_json::to_string(t).map_err(WriteError::Serialize)?;
-
-    writer.write_all(json.as_bytes()).map_err(WriteError::Io)?;
-    writer.write_all(b"\n").map_err(WriteError::Io)?;
-
+    let json = serde_json::to_string(t)?;
+    writer.write_all(json.as_bytes())?;
+    writer.write_all(b"\n")?;
     Ok(())
-}*/
+}
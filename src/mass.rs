@@ -1,7 +1,7 @@
 //! Нагрузка на корпус судна
 use std::rc::Rc;
 
-use crate::{load::ILoad, math::{bound::Bound, mass_moment::MassMoment, position::Position, surface_moment::SurfaceMoment}};
+use crate::{error::Error, load::ILoad, math::{bound::Bound, mass_moment::MassMoment, position::Position, surface_moment::SurfaceMoment}};
 
 /// Нагрузка на корпус судна: конструкции, груз, экипаж и т.п.
 #[derive(Clone)]
@@ -16,16 +16,28 @@ impl Mass {
     /// Аргументы конструктора:  
     /// * loads - вектор абстрактных грузов
     /// * bounds - ссылка на вектор разбиения на отрезки для эпюров
-    pub fn new (loads: Vec<Rc<Box<dyn ILoad>>>, bounds: Vec<Bound>) -> Self {
-        Self { loads, bounds, }
+    pub fn new (loads: Vec<Rc<Box<dyn ILoad>>>, bounds: Vec<Bound>) -> Result<Self, Error> {
+        if bounds.is_empty() {
+            return Err(Error::Mass("bounds must not be empty".to_owned()));
+        }
+        Ok(Self { loads, bounds, })
     }
     ///Суммарный статический момент
     fn moment_mass (&self) -> MassMoment {
         self.loads.iter().map(|c| c.moment_mass() ).sum::<MassMoment>()
     }
-    /// Суммарный момент свободной поверхности
+    /// Суммарный момент свободной поверхности с учетом способа учета
+    /// свободной поверхности каждого груза, см. [crate::load::FreeSurface]
     fn moment_surface (&self) -> SurfaceMoment {
-        self.loads.iter().map(|c| c.moment_surface() ).sum::<SurfaceMoment>()
+        self.loads.iter().map(|c| c.free_surface().moment(c.as_ref().as_ref()) ).sum::<SurfaceMoment>()
+    }
+    /// Суммарная масса, ошибка если она не положительна
+    fn positive_sum(&self) -> Result<f64, Error> {
+        let sum = self.sum();
+        if sum <= 0. {
+            return Err(Error::Mass(format!("mass sum {sum} must be greater than 0")));
+        }
+        Ok(sum)
     }
 }
 
@@ -35,26 +47,31 @@ impl IMass for Mass {
         self.loads.iter().map(|v| v.mass(None)).sum::<f64>()
     }    
     /// Распределение массы по вектору разбиения
-    fn values(&self) -> Vec<f64> {
-        self.bounds.iter().map(|b| 
-            self.loads.iter().map(|v| v.mass(Some(*b))).sum::<f64>()).collect()
+    fn values(&self) -> Result<Vec<f64>, Error> {
+        Ok(self.bounds.iter().map(|b| 
+            self.loads.iter().map(|v| v.mass(Some(*b))).sum::<f64>()).collect())
     }
     /// Отстояние центра масс
     fn shift(&self) -> Position {
         self.moment_mass().to_pos(self.sum())
     }
     /// Поправка к продольной метацентрической высоте на влияние свободной поверхности жидкости в цистернах 
-    fn delta_m_h(&self) -> f64 {
-        self.moment_surface().y()/self.sum()
+    fn delta_m_h(&self) -> Result<f64, Error> {
+        Ok(self.moment_surface().y()/self.positive_sum()?)
+    }
+    /// Поправка к поперечной метацентрической высоте на влияние свободной поверхности жидкости в цистернах 
+    fn delta_m_h_trans(&self) -> Result<f64, Error> {
+        Ok(self.moment_surface().x()/self.positive_sum()?)
     }
 }
 
 #[doc(hidden)]
 pub trait IMass {
     fn sum(&self) -> f64;
-    fn values(&self) -> Vec<f64>;
+    fn values(&self) -> Result<Vec<f64>, Error>;
     fn shift(&self) -> Position;
-    fn delta_m_h(&self) -> f64;
+    fn delta_m_h(&self) -> Result<f64, Error>;
+    fn delta_m_h_trans(&self) -> Result<f64, Error>;
 }
 // заглушка для тестирования
 #[doc(hidden)]
@@ -63,6 +80,7 @@ pub struct FakeMass {
     values: Vec<f64>,
     shift: Position,
     delta_m_h: f64,
+    delta_m_h_trans: f64,
 }
 #[doc(hidden)]
 impl FakeMass {
@@ -71,8 +89,9 @@ impl FakeMass {
         values: Vec<f64>,
         shift: Position,
         delta_m_h: f64,
+        delta_m_h_trans: f64,
     ) -> Self {
-        Self { sum, values, shift, delta_m_h, }
+        Self { sum, values, shift, delta_m_h, delta_m_h_trans, }
     }
 }
 #[doc(hidden)]
@@ -80,13 +99,16 @@ impl IMass for FakeMass {
     fn sum(&self) -> f64 {
         self.sum
     }    
-    fn values(&self) -> Vec<f64> {
-        self.values.clone()
+    fn values(&self) -> Result<Vec<f64>, Error> {
+        Ok(self.values.clone())
     }
     fn shift(&self) -> Position {
         self.shift.clone()
     }
-    fn delta_m_h(&self) -> f64 {
-        self.delta_m_h
+    fn delta_m_h(&self) -> Result<f64, Error> {
+        Ok(self.delta_m_h)
+    }
+    fn delta_m_h_trans(&self) -> Result<f64, Error> {
+        Ok(self.delta_m_h_trans)
     }
 }
\ No newline at end of file
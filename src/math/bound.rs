@@ -1,4 +1,6 @@
 //! Диапазон значений
+use crate::error::Error;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Bound {
     /// начало диапазона
@@ -12,12 +14,14 @@ impl Bound {
     /// Конструктор  
     /// - start - начало диапазона
     /// - end - конец диапазона
-    pub fn new(start: f64, end: f64) -> Self {
-        assert!(end > start);
-        Self {
+    pub fn new(start: f64, end: f64) -> Result<Self, Error> {
+        if end <= start {
+            return Err(Error::Bound(format!("start {start} must be lower than end {end}")));
+        }
+        Ok(Self {
             start: start,
             end: end,
-        }
+        })
     }
     ///
     /// Отношение общей части пересечения к длине диапазона
@@ -36,7 +40,10 @@ impl Bound {
         if other.start() <= self.start && other.end() >= self.end {
             return Some(*self);
         }
-        Some(Bound::new(other.start().max(self.start), other.end().min(self.end)))
+        Some(Bound {
+            start: other.start().max(self.start),
+            end: other.end().min(self.end),
+        })
     }
     ///
     /// Длинна диапазона
@@ -1,6 +1,8 @@
 //! Кривая, позволяет получать интерполированные значения
 use splines::{Interpolation, Key, Spline};
 
+use crate::error::Error;
+
 ///
 /// Представление кривой в виде массива пар значений
 /// - Обеспечивает получение промежуточных значений с помощью простой линейной интерполяции
@@ -12,16 +14,53 @@ pub struct Curve {
 /// 
 impl Curve {
     ///
-    /// Creates new instance of the Curve from vector of the key - value pairs
-    pub fn new(values: Vec<(f64, f64)>) -> Curve {
-        assert!(values.len() > 1, "Curve.new | Input array must have at least two elements (values.len > 1), \nvalues: {:?}", values);
+    /// Creates new instance of the Curve from vector of the key - value pairs.
+    /// Ключи и значения должны быть конечными числами, ключи не должны повторяться
+    pub fn new(values: Vec<(f64, f64)>) -> Result<Curve, Error> {
+        if values.len() <= 1 {
+            return Err(Error::Curve(format!("input array must have at least two elements, values: {:?}", values)));
+        }
+        if let Some(v) = values.iter().find(|v| !v.0.is_finite() || !v.1.is_finite()) {
+            return Err(Error::Curve(format!("key and value {:?} must be finite, values: {:?}", v, values)));
+        }
+        let mut keys: Vec<f64> = values.iter().map(|v| v.0).collect();
+        keys.sort_by(|a, b| a.total_cmp(b));
+        if let Some(key) = keys.windows(2).find(|w| w[0] == w[1]).map(|w| w[0]) {
+            return Err(Error::Curve(format!("key {key} must not repeat, values: {:?}", values)));
+        }
         let values: Vec<_> = values
             .into_iter()
             .map(|v| Key::new(v.0, v.1, Interpolation::default()))
             .collect();
-        Self {
+        Ok(Self {
             spline: Spline::from_vec(values),
-        }
+        })
+    }
+    /// Наибольшее значение кривой, при линейной интерполяции
+    /// достигается в одной из точек кривой
+    pub fn max(&self) -> f64 {
+        self.spline
+            .keys()
+            .iter()
+            .map(|key| key.value)
+            .fold(f64::MIN, f64::max)
+    }
+    /// Наименьшее значение кривой, при линейной интерполяции
+    /// достигается в одной из точек кривой
+    pub fn min(&self) -> f64 {
+        self.spline
+            .keys()
+            .iter()
+            .map(|key| key.value)
+            .fold(f64::MAX, f64::min)
+    }
+    /// Наибольший ключ кривой
+    pub fn key_max(&self) -> f64 {
+        self.spline
+            .keys()
+            .iter()
+            .map(|key| key.t)
+            .fold(f64::MIN, f64::max)
     }
 }
 
@@ -29,12 +68,14 @@ impl ICurve for Curve {
     /// Возвращает значение из таблицы по его ключу
     /// - если такого ключа нет, то возвращает промежуточное значение между двумя соседними с помощью линейной интерполяции
     /// - если ключ за пределами ключей таблицы, то вернет либо первое либо последнее значение
-    /// - panic - если нет ключей
-
+    /// - если ключ не является числом (NaN), то вернет NaN
     fn value(&self, key: f64) -> f64 {
-        self.spline
-            .clamped_sample(key)
-            .expect(&format!("Curve.value | Ошибка полуения значения: нет ключей"))
+        if key.is_nan() {
+            log::warn!("Curve.value | key {key} is not a number");
+            return f64::NAN;
+        }
+        // ключи проверены в конструкторе, значение есть для любого ключа
+        self.spline.clamped_sample(key).unwrap_or(f64::NAN)
     }
 }
 
@@ -0,0 +1,63 @@
+//! Распределение массы груза по длине
+use crate::{error::Error, math::bound::Bound};
+
+/// Распределение массы груза по длине в его границах,
+/// центр распределенной массы совпадает с заданным центром масс.
+/// Пусть $a$, $b$ - границы груза, $l = b - a$, $m = (a + b)/2$ - середина, $x_g$ - центр масс:
+/// - при $|x_g - m| \le l/6$ - трапеция по всей длине груза,
+///   интенсивность $q(x) = 1/l + 12 (x_g - m)(x - m)/l^3$,
+///   при $x_g = m$ - равномерное распределение;
+/// - при $|x_g - m| > l/6$ - треугольник (линейное распределение) с вершиной
+///   на ближайшей к центру масс границе и длиной основания $3 |x_g - e|$,
+///   где $e$ - ближайшая к центру масс граница.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distribution {
+    /// границы груза
+    bound: Bound,
+    /// отстояние центра масс по длине
+    center: f64,
+}
+///
+impl Distribution {
+    /// Основной конструктор. Аргументы:
+    /// - bound: границы груза
+    /// - center: отстояние центра масс по длине
+    pub fn new(bound: Bound, center: f64) -> Result<Self, Error> {
+        if bound.start() > center || bound.end() < center {
+            return Err(Error::Bound(format!(
+                "bound ({}, {}) must contain center {center}", bound.start(), bound.end()
+            )));
+        }
+        Ok(Self { bound, center })
+    }
+    /// Доля массы груза, попадающая в указанные границы
+    pub fn part_ratio(&self, bound: &Bound) -> f64 {
+        let (a, b, x_g) = (self.bound.start(), self.bound.end(), self.center);
+        let (l, m) = (self.bound.length(), self.bound.center());
+        if (x_g - m).abs() <= l / 6. {
+            let Some(part) = self.bound.intersect(bound) else {
+                return 0.;
+            };
+            let (s, e) = (part.start(), part.end());
+            let k = 12. * (x_g - m) / l.powi(3);
+            return self.bound.part_ratio(bound) + k * ((e - m).powi(2) - (s - m).powi(2)) / 2.;
+        }
+        // треугольник с вершиной на ближайшей к центру масс границе
+        let (start, end) = if x_g > m { (3. * x_g - 2. * b, b) } else { (a, 3. * x_g - 2. * a) };
+        let c = end - start;
+        if c <= f64::EPSILON * l {
+            // вся масса сосредоточена на границе груза
+            return if bound.start() <= x_g && x_g < bound.end() { 1. } else { 0. };
+        }
+        let s = bound.start().max(start);
+        let e = bound.end().min(end);
+        if e <= s {
+            return 0.;
+        }
+        if x_g > m {
+            ((e - start).powi(2) - (s - start).powi(2)) / c.powi(2)
+        } else {
+            ((end - s).powi(2) - (end - e).powi(2)) / c.powi(2)
+        }
+    }
+}
@@ -21,4 +21,9 @@ impl InertiaShift {
     pub fn value(&self, key: f64) -> InertiaMoment {
         InertiaMoment::new(self.x.value(key), self.y.value(key))
     }
+    ///наибольшие по кривым моменты инерции площади свободной поверхности
+    ///(x - поперечный, y - продольный), каждый по своей кривой
+    pub fn max(&self) -> InertiaMoment {
+        InertiaMoment::new(self.x.max(), self.y.max())
+    }
 }
\ No newline at end of file
@@ -5,5 +5,6 @@ pub mod mass_moment;
 pub mod surface_moment;
 pub mod curve;
 pub mod bound;
+pub mod distribution;
 pub mod pos_shift;
 pub mod inertia_shift;
@@ -27,6 +27,10 @@ impl PosShift {
     pub fn new(x: Curve, y: Curve, z: Curve ) -> Self {
         Self { x, y, z }
     }
+    /// Наибольший ключ кривых
+    pub fn key_max(&self) -> f64 {
+        self.x.key_max().max(self.y.key_max()).max(self.z.key_max())
+    }
 }
 
 impl IPosShift for PosShift {
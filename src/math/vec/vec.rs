@@ -1,4 +1,6 @@
 //! Дополнительные операции над вектором чисел с плавающей точкой
+use crate::error::Error;
+
 /// Сумма сверху: $res_i = res_{i-1} + src_i, res_0 = 0$
 ///
 /// # Example
@@ -97,15 +99,18 @@ impl MultipleSingle for Vec<f64>  {
 /// asserteq!(vec, vec![4., 6., 8.,]);
 /// ```
 pub trait AddVec {
-    fn add_vec(&mut self, rhs: &Self);
+    fn add_vec(&mut self, rhs: &Self) -> Result<(), Error>;
 }
 ///
 impl AddVec for Vec<f64>  {   
-    fn add_vec(&mut self, rhs: &Self) {
-        assert_eq!(self.len(), rhs.len());
+    fn add_vec(&mut self, rhs: &Self) -> Result<(), Error> {
+        if self.len() != rhs.len() {
+            return Err(Error::Vec(format!("add_vec self.len() {} != rhs.len() {}", self.len(), rhs.len())));
+        }
         self.iter_mut()
             .zip(rhs.into_iter())
             .for_each(|(v1, v2)| *v1 += v2 );
+        Ok(())
     }    
 }
 ///
@@ -120,15 +125,18 @@ impl AddVec for Vec<f64>  {
 /// asserteq!(vec, vec![-2., -2., -2.,]);
 /// ```
 pub trait SubVec {
-    fn sub_vec(&mut self, rhs: &Self);
+    fn sub_vec(&mut self, rhs: &Self) -> Result<(), Error>;
 }
 ///
 impl SubVec for Vec<f64>  {   
-    fn sub_vec(&mut self, rhs: &Self) {
-        assert_eq!(self.len(), rhs.len());
+    fn sub_vec(&mut self, rhs: &Self) -> Result<(), Error> {
+        if self.len() != rhs.len() {
+            return Err(Error::Vec(format!("sub_vec self.len() {} != rhs.len() {}", self.len(), rhs.len())));
+        }
         self.iter_mut()
             .zip(rhs.into_iter())
             .for_each(|(v1, v2)| *v1 -= v2 );
+        Ok(())
     }    
 }
 ///
@@ -143,15 +151,18 @@ impl SubVec for Vec<f64>  {
 /// asserteq!(vec, vec![3., 8., 15.,]);
 /// ```
 pub trait MultipleVec {
-    fn mul_vec(&mut self, rhs: &Self);
+    fn mul_vec(&mut self, rhs: &Self) -> Result<(), Error>;
 }
 ///
 impl MultipleVec for Vec<f64>  {   
-    fn mul_vec(&mut self, rhs: &Self) {
-        assert_eq!(self.len(), rhs.len());
+    fn mul_vec(&mut self, rhs: &Self) -> Result<(), Error> {
+        if self.len() != rhs.len() {
+            return Err(Error::Vec(format!("mul_vec self.len() {} != rhs.len() {}", self.len(), rhs.len())));
+        }
         self.iter_mut()
             .zip(rhs.into_iter())
             .for_each(|(v1, v2)| *v1 *= v2 );
+        Ok(())
     }    
 }
 ///
@@ -166,14 +177,17 @@ impl MultipleVec for Vec<f64>  {
 /// asserteq!(vec, vec![2., 2., 2.,]);
 /// ```
 pub trait DivideVec {
-    fn div_vec(&mut self, rhs: &Vec<f64>);
+    fn div_vec(&mut self, rhs: &Vec<f64>) -> Result<(), Error>;
 }
 ///
 impl DivideVec for Vec<f64>  {   
-    fn div_vec(&mut self, rhs: &Vec<f64>) {
-        assert_eq!(self.len(), rhs.len());
+    fn div_vec(&mut self, rhs: &Vec<f64>) -> Result<(), Error> {
+        if self.len() != rhs.len() {
+            return Err(Error::Vec(format!("div_vec self.len() {} != rhs.len() {}", self.len(), rhs.len())));
+        }
         self.iter_mut()
             .zip(rhs.into_iter())
             .for_each(|(v1, v2)| *v1 /= v2 );
+        Ok(())
     }    
 }
@@ -0,0 +1,88 @@
+//! Поперечная метацентрическая высота
+use std::rc::Rc;
+
+use crate::{
+    error::Error,
+    mass::IMass,
+    math::{curve::ICurve, pos_shift::IPosShift},
+};
+
+/// Поперечная метацентрическая высота судна, исправленная
+/// с учетом влияния свободных поверхностей жидкости в цистернах:
+/// $z_m = z_c + r$, $h_0 = z_m - z_g$, $h = h_0 - \Delta m_h$
+pub struct MetacentricHeight {
+    /// плотность окружающей воды
+    water_density: f64,
+    /// отстояние центра величины погруженной части судна
+    center_draught_shift: Box<dyn IPosShift>,
+    /// поперечный метацентрический радиус
+    rad_trans: Box<dyn ICurve>,
+    /// все грузы судна
+    mass: Rc<dyn IMass>,
+}
+///
+impl MetacentricHeight {
+    /// Основной конструктор. Аргументы:
+    /// - water_density: плотность окружающей воды
+    /// - center_draught_shift: отстояние центра величины погруженной части судна
+    /// - rad_trans: кривая поперечного метацентрического радиуса
+    /// - mass: все грузы судна
+    pub fn new(
+        water_density: f64,
+        center_draught_shift: impl IPosShift + 'static,
+        rad_trans: impl ICurve + 'static,
+        mass: Rc<dyn IMass>,
+    ) -> Result<Self, Error> {
+        if water_density <= 0. {
+            return Err(Error::MetacentricHeight(format!("water_density {water_density} must be greater than 0")));
+        }
+        Ok(Self {
+            water_density,
+            center_draught_shift: Box::new(center_draught_shift),
+            rad_trans: Box::new(rad_trans),
+            mass,
+        })
+    }
+    /// Объемное водоизмещение
+    pub fn volume(&self) -> Result<f64, Error> {
+        let mass_sum = self.mass.sum();
+        if mass_sum <= 0. {
+            return Err(Error::MetacentricHeight(format!("mass sum {mass_sum} must be greater than 0")));
+        }
+        Ok(mass_sum / self.water_density)
+    }
+    /// Аппликата центра величины по кривой
+    /// - volume: объемное водоизмещение
+    pub fn z_c(&self, volume: f64) -> f64 {
+        self.center_draught_shift.value(volume).z()
+    }
+    /// Момент инерции площади ватерлинии относительно ДП по кривой
+    /// поперечного метацентрического радиуса, $I = r V$
+    /// - volume: объемное водоизмещение
+    pub fn inertia(&self, volume: f64) -> f64 {
+        self.rad_trans.value(volume) * volume
+    }
+    /// Аппликата центра масс, исправленная на влияние
+    /// свободной поверхности жидкости, $z_g + \Delta m_h$
+    pub fn z_g(&self) -> Result<f64, Error> {
+        Ok(self.mass.shift().z() + self.mass.delta_m_h_trans()?)
+    }
+    /// Аппликата поперечного метацентра
+    pub fn z_m(&self) -> Result<f64, Error> {
+        let volume = self.volume()?;
+        let result = self.center_draught_shift.value(volume).z() + self.rad_trans.value(volume);
+        log::debug!("\t MetacentricHeight volume:{volume} z_m:{result}");
+        Ok(result)
+    }
+    /// Поперечная метацентрическая высота без учета
+    /// влияния свободной поверхности жидкости
+    pub fn h_0(&self) -> Result<f64, Error> {
+        Ok(self.z_m()? - self.mass.shift().z())
+    }
+    /// Поперечная исправленная метацентрическая высота
+    pub fn h(&self) -> Result<f64, Error> {
+        let result = self.h_0()? - self.mass.delta_m_h_trans()?;
+        log::debug!("\t MetacentricHeight result:{result}");
+        Ok(result)
+    }
+}
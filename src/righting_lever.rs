@@ -0,0 +1,113 @@
+//! Диаграмма статической остойчивости
+use std::rc::Rc;
+
+use crate::{cross_curves::CrossCurves, error::Error, mass::IMass};
+
+/// Плечо статической остойчивости по пантокаренам, исправленное
+/// на аппликату центра масс и влияние свободной поверхности жидкости
+/// в цистернах: $l(\theta) = KN(\theta) - (z_g + \Delta m_h) \sin\theta$.
+/// Плечо динамической остойчивости - площадь под диаграммой
+/// статической остойчивости: $d(\theta) = \int_0^\theta l d\theta$, м·рад.
+pub struct RightingLever {
+    /// пантокарены
+    cross_curves: CrossCurves,
+    /// объемное водоизмещение
+    volume: f64,
+    /// аппликата центра масс, исправленная на влияние свободной поверхности
+    z_g: f64,
+    /// шаг по углу крена для поиска экстремумов и интегрирования, градусы
+    step: f64,
+}
+///
+impl RightingLever {
+    /// Основной конструктор. Аргументы:
+    /// - water_density: плотность окружающей воды
+    /// - cross_curves: пантокарены
+    /// - mass: все грузы судна
+    pub fn new(water_density: f64, cross_curves: CrossCurves, mass: Rc<dyn IMass>) -> Result<Self, Error> {
+        if water_density <= 0. {
+            return Err(Error::RightingLever(format!("water_density {water_density} must be greater than 0")));
+        }
+        if mass.sum() <= 0. {
+            return Err(Error::RightingLever(format!("mass sum {} must be greater than 0", mass.sum())));
+        }
+        let volume = mass.sum() / water_density;
+        let z_g = mass.shift().z() + mass.delta_m_h_trans()?;
+        Ok(Self { cross_curves, volume, z_g, step: 0.1 })
+    }
+    /// Наибольший угол крена, для которого заданы пантокарены, градусы
+    pub fn angle_max(&self) -> f64 {
+        self.cross_curves.angle_max()
+    }
+    /// Плечо статической остойчивости, для отрицательных углов крена
+    /// диаграмма симметрична: $l(-\theta) = -l(\theta)$
+    /// - angle: угол крена, градусы
+    pub fn value(&self, angle: f64) -> f64 {
+        if angle < 0. {
+            return -self.value(-angle);
+        }
+        self.cross_curves.value(self.volume, angle) - self.z_g * angle.to_radians().sin()
+    }
+    /// Начальная метацентрическая высота по наклону диаграммы
+    /// статической остойчивости в начале координат, $h = dl/d\theta$
+    pub fn metacentric_height(&self) -> f64 {
+        self.value(self.step) / self.step.to_radians()
+    }
+    /// Плечи статической остойчивости (угол крена, плечо)
+    /// - angles: углы крена, градусы
+    pub fn values(&self, angles: &[f64]) -> Vec<(f64, f64)> {
+        let result = angles.iter().map(|&a| (a, self.value(a))).collect();
+        log::debug!("\t RightingLever values result:{:?}", result);
+        result
+    }
+    /// Углы крена от angle_from до angle_to с шагом не более self.step
+    fn grid(&self, angle_from: f64, angle_to: f64) -> Vec<f64> {
+        let n = ((angle_to - angle_from) / self.step).ceil().max(1.) as usize;
+        (0..=n).map(|i| angle_from + (angle_to - angle_from) * i as f64 / n as f64).collect()
+    }
+    /// Площадь под диаграммой статической остойчивости между углами крена, м·рад
+    /// - angle_from, angle_to: углы крена, градусы
+    pub fn area(&self, angle_from: f64, angle_to: f64) -> f64 {
+        if angle_to <= angle_from {
+            return 0.;
+        }
+        self.grid(angle_from, angle_to)
+            .windows(2)
+            .map(|v| (self.value(v[0]) + self.value(v[1])) / 2. * (v[1] - v[0]).to_radians())
+            .sum()
+    }
+    /// Плечи динамической остойчивости (угол крена, плечо)
+    /// - angles: углы крена, градусы
+    pub fn dynamic(&self, angles: &[f64]) -> Vec<(f64, f64)> {
+        let result = angles.iter().map(|&a| (a, self.area(0., a))).collect();
+        log::debug!("\t RightingLever dynamic result:{:?}", result);
+        result
+    }
+    /// Максимальное плечо статической остойчивости (угол крена, плечо)
+    pub fn max(&self) -> (f64, f64) {
+        self.grid(0., self.angle_max())
+            .into_iter()
+            .map(|a| (a, self.value(a)))
+            .fold((0., self.value(0.)), |max, v| if v.1 > max.1 { v } else { max })
+    }
+    /// Первый угол крена между angle_from и angle_to, при котором плечо статической
+    /// остойчивости равно кренящему плечу, градусы, None если такого угла нет
+    /// - lever: кренящее плечо в зависимости от угла крена в градусах
+    /// - angle_from, angle_to: углы крена, градусы
+    pub fn angle(&self, lever: impl Fn(f64) -> f64, angle_from: f64, angle_to: f64) -> Option<f64> {
+        if angle_to <= angle_from {
+            return None;
+        }
+        self.grid(angle_from, angle_to)
+            .windows(2)
+            .map(|v| (v[0], self.value(v[0]) - lever(v[0]), v[1], self.value(v[1]) - lever(v[1])))
+            .find(|v| v.1 == 0. || v.1.signum() != v.3.signum())
+            .map(|(a0, l0, a1, l1)| if l0 == 0. { a0 } else { a0 + (a1 - a0) * l0 / (l0 - l1) })
+    }
+    /// Угол заката диаграммы статической остойчивости, градусы,
+    /// None если плечо не становится отрицательным до angle_max
+    pub fn angle_vanishing(&self) -> Option<f64> {
+        let (angle_max_lever, _) = self.max();
+        self.angle(|_| 0., angle_max_lever, self.angle_max())
+    }
+}
@@ -1,5 +1,5 @@
 //! Срезающая сила
-use crate::{math::vec::vec::SumAbove, total_force::ITotalForce};
+use crate::{error::Error, math::vec::vec::SumAbove, total_force::ITotalForce};
 
 /// Срезающая сила, вычисляется интегрированием  
 /// путем вычисления суммы сверху результирующей нагрузки по шпациям:  
@@ -19,16 +19,16 @@ impl ShearForce {
 ///
 impl IShearForce for ShearForce {
     ///
-    fn values(&self) -> Vec<f64> {
-        let result = self.total_force.values().sum_above();
+    fn values(&self) -> Result<Vec<f64>, Error> {
+        let result = self.total_force.values()?.sum_above();
         log::debug!("\t ShearForce result:{:?}", result);
-        result
+        Ok(result)
     }
 }
 
 #[doc(hidden)]
 pub trait IShearForce {
-    fn values(&self) -> Vec<f64>;
+    fn values(&self) -> Result<Vec<f64>, Error>;
 }
 // заглушка для тестирования
 #[doc(hidden)]
@@ -43,7 +43,7 @@ impl FakeShearForce {
 }
 #[doc(hidden)]
 impl IShearForce for FakeShearForce {
-    fn values(&self) -> Vec<f64> {
-        self.data.clone()
+    fn values(&self) -> Result<Vec<f64>, Error> {
+        Ok(self.data.clone())
     }
 }
\ No newline at end of file
@@ -0,0 +1,111 @@
+//! Контейнеры по ячейкам грузового плана
+use crate::{
+    error::Error,
+    load::LoadSpace,
+    math::{bound::Bound, position::Position},
+};
+
+/// Штабель контейнеров: бей и ряд, масса штабеля и допустимая масса
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stack {
+    /// номер бея
+    pub bay: usize,
+    /// номер ряда
+    pub row: usize,
+    /// масса контейнеров в штабеле
+    pub mass: f64,
+    /// допустимая масса штабеля, если задана
+    pub limit: Option<f64>,
+}
+///
+impl Stack {
+    /// Допустимая масса штабеля не превышена
+    pub fn pass(&self) -> bool {
+        self.limit.map(|limit| self.mass <= limit).unwrap_or(true)
+    }
+}
+/// Грузовой план контейнеровоза. Ячейка задается номерами бея, ряда и яруса:
+/// бей определяет границы контейнера по длине, ряд - отстояние центра масс
+/// по ширине от ДП, ярус - аппликату центра масс. Контейнер в ячейке - груз
+/// [LoadSpace] в границах бея с центром масс в середине бея.
+pub struct Stowage {
+    /// беи (номер, границы по длине)
+    bays: Vec<(usize, Bound)>,
+    /// ряды (номер, отстояние центра масс по ширине)
+    rows: Vec<(usize, f64)>,
+    /// ярусы (номер, аппликата центра масс)
+    tiers: Vec<(usize, f64)>,
+    /// допустимые массы штабелей (бей, ряд, масса)
+    limits: Vec<(usize, usize, f64)>,
+}
+///
+impl Stowage {
+    /// Основной конструктор. Аргументы:
+    /// - bays: беи (номер, x1, x2)
+    /// - rows: ряды (номер, y)
+    /// - tiers: ярусы (номер, z)
+    /// - limits: допустимые массы штабелей (бей, ряд, масса)
+    pub fn new(
+        bays: Vec<(usize, f64, f64)>,
+        rows: Vec<(usize, f64)>,
+        tiers: Vec<(usize, f64)>,
+        limits: Vec<(usize, usize, f64)>,
+    ) -> Result<Self, Error> {
+        if let Some(limit) = limits.iter().find(|v| v.2 <= 0.) {
+            return Err(Error::Stowage(format!("stack limit {} of bay {} row {} must be greater than 0", limit.2, limit.0, limit.1)));
+        }
+        Ok(Self {
+            bays: bays
+                .into_iter()
+                .map(|(bay, x1, x2)| Ok((bay, Bound::new(x1, x2)?)))
+                .collect::<Result<_, Error>>()?,
+            rows,
+            tiers,
+            limits,
+        })
+    }
+    /// Границы по длине и центр масс контейнера в ячейке
+    fn slot(&self, bay: usize, row: usize, tier: usize) -> Result<(Bound, Position), Error> {
+        let bound = self.bays.iter().find(|v| v.0 == bay).map(|v| v.1)
+            .ok_or(Error::Stowage(format!("bay {bay} not found")))?;
+        let y = self.rows.iter().find(|v| v.0 == row).map(|v| v.1)
+            .ok_or(Error::Stowage(format!("row {row} not found")))?;
+        let z = self.tiers.iter().find(|v| v.0 == tier).map(|v| v.1)
+            .ok_or(Error::Stowage(format!("tier {tier} not found")))?;
+        Ok((bound, Position::new(bound.center(), y, z)))
+    }
+    /// Грузы от контейнеров в ячейках
+    /// - containers: контейнеры (бей, ряд, ярус, масса)
+    pub fn loads(&self, containers: &[(usize, usize, usize, f64)]) -> Result<Vec<LoadSpace>, Error> {
+        containers
+            .iter()
+            .enumerate()
+            .map(|(i, &(bay, row, tier, mass))| {
+                if containers[..i].iter().any(|v| (v.0, v.1, v.2) == (bay, row, tier)) {
+                    return Err(Error::Stowage(format!("slot bay {bay} row {row} tier {tier} is occupied twice")));
+                }
+                let (bound, center) = self.slot(bay, row, tier)?;
+                LoadSpace::new(mass, bound, center)
+            })
+            .collect()
+    }
+    /// Массы штабелей с контейнерами, упорядоченные по бею и ряду
+    /// - containers: контейнеры (бей, ряд, ярус, масса)
+    pub fn stacks(&self, containers: &[(usize, usize, usize, f64)]) -> Vec<Stack> {
+        let mut result: Vec<Stack> = Vec::new();
+        for &(bay, row, _, mass) in containers {
+            match result.iter_mut().find(|s| s.bay == bay && s.row == row) {
+                Some(stack) => stack.mass += mass,
+                None => result.push(Stack {
+                    bay,
+                    row,
+                    mass,
+                    limit: self.limits.iter().find(|v| v.0 == bay && v.1 == row).map(|v| v.2),
+                }),
+            }
+        }
+        result.sort_by_key(|s| (s.bay, s.row));
+        log::debug!("\t Stowage stacks result:{:?}", result);
+        result
+    }
+}
@@ -0,0 +1,88 @@
+//! Сводка нагрузки судна по категориям грузов
+use std::rc::Rc;
+
+use crate::{
+    load::{Category, ILoad},
+    math::{mass_moment::MassMoment, position::Position, surface_moment::SurfaceMoment},
+};
+
+/// Порядок категорий в сводке
+const CATEGORIES: [Category; 10] = [
+    Category::Lightship,
+    Category::Cargo,
+    Category::Ballast,
+    Category::Fuel,
+    Category::LubricatingOil,
+    Category::FreshWater,
+    Category::Stores,
+    Category::Crew,
+    Category::Ice,
+    Category::Other,
+];
+
+/// Итоги по категории грузов
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CategoryTotal {
+    /// категория грузов
+    pub category: Category,
+    /// масса грузов
+    pub mass: f64,
+    /// центр масс грузов
+    pub center: Position,
+    /// момент свободной поверхности жидкости
+    pub moment_surface: SurfaceMoment,
+}
+
+/// Сводка нагрузки судна: масса, центр масс и момент свободной поверхности
+/// по категориям грузов, дедвейт и водоизмещение. Дедвейт - масса всех
+/// грузов, кроме судна порожнем.
+pub struct Summary {
+    /// все грузы судна
+    loads: Vec<Rc<Box<dyn ILoad>>>,
+}
+///
+impl Summary {
+    /// Основной конструктор. Аргументы:
+    /// - loads: все грузы судна
+    pub fn new(loads: Vec<Rc<Box<dyn ILoad>>>) -> Self {
+        Self { loads }
+    }
+    /// Итоги по категориям, в которых есть грузы
+    pub fn categories(&self) -> Vec<CategoryTotal> {
+        let result: Vec<CategoryTotal> = CATEGORIES
+            .iter()
+            .filter_map(|category| {
+                let loads: Vec<_> = self.loads.iter().filter(|l| l.category() == *category).collect();
+                if loads.is_empty() {
+                    return None;
+                }
+                let mass: f64 = loads.iter().map(|l| l.mass(None)).sum();
+                let center = if mass > 0. {
+                    loads.iter().map(|l| l.moment_mass()).sum::<MassMoment>().to_pos(mass)
+                } else {
+                    Position::new(0., 0., 0.)
+                };
+                Some(CategoryTotal {
+                    category: *category,
+                    mass,
+                    center,
+                    moment_surface: loads.iter().map(|l| l.free_surface().moment(l.as_ref().as_ref())).sum(),
+                })
+            })
+            .collect();
+        log::debug!("\t Summary categories result:{:?}", result);
+        result
+    }
+    /// Водоизмещение - масса всех грузов
+    pub fn displacement(&self) -> f64 {
+        self.loads.iter().map(|l| l.mass(None)).sum()
+    }
+    /// Дедвейт - масса всех грузов, кроме судна порожнем
+    pub fn deadweight(&self) -> f64 {
+        self.loads
+            .iter()
+            .filter(|l| l.category() != Category::Lightship)
+            .map(|l| l.mass(None))
+            .sum()
+    }
+}
@@ -1,35 +1,99 @@
 //! Груз - цистерна с жидкостью
-use crate::{load::ILoad, math::{bound::Bound, inertia_shift::inertia_shift::InertiaShift, pos_shift::{IPosShift, PosShift}, position::Position, surface_moment::SurfaceMoment}};
+use crate::{calibration::{Calibration, Fill}, error::Error, load::{FreeSurface, ILoad}, math::{bound::Bound, distribution::Distribution, inertia_shift::inertia_shift::InertiaShift, pos_shift::{IPosShift, PosShift}, position::Position, surface_moment::SurfaceMoment}};
 
 /// Груз - цистерна с жидкостью, реализует интерфейс ILoad.  
 /// Помимо массы имеет свойства свободной поверхности жидкости.
+/// Масса жидкости распределяется по длине так, чтобы ее центр
+/// совпадал с центром объема жидкости, см. [Distribution]
 #[derive(Clone)]
 pub struct Tank {    
     /// плотность жидкости в цистерне 
     density: f64,     
     /// объем жидкости в цистерне
     volume: f64,  
-    /// границы цистерны
-    bound: Bound,  
+    /// распределение массы жидкости по длине в границах цистерны
+    distribution: Distribution,  
     /// кривая координат центра объема жидкости в цистерне в системе координат судна
     center: PosShift, 
     /// кривая момента инерции площади свободной поверхности жидкости
     free_surf_inertia: InertiaShift,
+    /// учет момента свободной поверхности жидкости
+    free_surface: FreeSurface,
+    /// вместимость цистерны
+    capacity: f64,
 }
 ///
 impl Tank {
     ///
-    pub fn new(density: f64, volume: f64, bound: Bound, center: PosShift, free_surf_inertia: InertiaShift, ) -> Self {
-        assert!(density > 0., "density {} > 0", density);
-        assert!(volume >= 0., "volume {} >= 0", volume);
-        Self { density, volume, bound, center, free_surf_inertia }
+    pub fn new(density: f64, volume: f64, bound: Bound, center: PosShift, free_surf_inertia: InertiaShift, ) -> Result<Self, Error> {
+        if density <= 0. {
+            return Err(Error::Tank(format!("density {density} must be greater than 0")));
+        }
+        if volume < 0. {
+            return Err(Error::Tank(format!("volume {volume} must be greater or equal to 0")));
+        }
+        let center_x = center.value(volume).x();
+        if bound.start() > center_x || bound.end() < center_x {
+            return Err(Error::Tank(format!("bound ({}, {}) must contain center.x {center_x}", bound.start(), bound.end())));
+        }
+        let distribution = Distribution::new(bound, center_x)?;
+        let capacity = center.key_max();
+        Ok(Self { density, volume, distribution, center, free_surf_inertia, free_surface: FreeSurface::Actual, capacity })
+    }
+    /// Конструктор по заполнению цистерны, заполнение приводится к объему
+    /// жидкости по калибровочной таблице. Без таблицы допускается только
+    /// заполнение объемом или массой. Вместимость цистерны - наибольший объем
+    /// калибровочной таблицы, без нее - кривой координат центра объема. Аргументы:
+    /// - density: плотность жидкости в цистерне
+    /// - fill: заполнение цистерны
+    /// - calibration: калибровочная таблица цистерны
+    /// - bound: границы цистерны
+    /// - center: кривая координат центра объема жидкости в цистерне
+    /// - free_surf_inertia: кривая момента инерции площади свободной поверхности жидкости
+    /// - free_surface: учет момента свободной поверхности жидкости
+    pub fn from_fill(
+        density: f64,
+        fill: Fill,
+        calibration: Option<Calibration>,
+        bound: Bound,
+        center: PosShift,
+        free_surf_inertia: InertiaShift,
+        free_surface: FreeSurface,
+    ) -> Result<Self, Error> {
+        if density <= 0. {
+            return Err(Error::Tank(format!("density {density} must be greater than 0")));
+        }
+        let capacity = calibration.as_ref().map(|c| c.capacity()).unwrap_or(center.key_max());
+        let volume = match (fill, calibration) {
+            (fill, Some(calibration)) => calibration.value(fill, density)?,
+            (Fill::Volume(value), None) => value,
+            (Fill::Mass(value), None) => value / density,
+            (fill, None) => return Err(Error::Tank(format!("fill {:?} requires calibration table", fill))),
+        };
+        if volume > capacity {
+            return Err(Error::Tank(format!("volume {volume} must be less or equal to tank capacity {capacity}")));
+        }
+        if let FreeSurface::Fixed(moment) = free_surface {
+            if moment.x() < 0. || moment.y() < 0. {
+                return Err(Error::Tank(format!("fixed free surface moment {moment} must be greater or equal to 0")));
+            }
+        }
+        Ok(Self { free_surface, capacity, ..Self::new(density, volume, bound, center, free_surf_inertia)? })
+    }
+    /// Объем жидкости в цистерне
+    pub fn volume(&self) -> f64 {
+        self.volume
+    }
+    /// Вместимость цистерны
+    pub fn capacity(&self) -> f64 {
+        self.capacity
     }
 }
 ///
 impl ILoad for Tank {
     fn mass(&self, bound: Option<Bound>) -> f64 {
         self.volume*self.density*if let Some(bound) = bound {
-            self.bound.part_ratio(&bound)
+            self.distribution.part_ratio(&bound)
         } else {
             1.
         }
@@ -44,4 +108,12 @@ impl ILoad for Tank {
         log::debug!("\t Tank result:{:?}", result);
         result
     }
+
+    fn moment_surface_max(&self) -> SurfaceMoment {
+        SurfaceMoment::from_inertia(self.free_surf_inertia.max(), self.density)
+    }
+
+    fn free_surface(&self) -> FreeSurface {
+        self.free_surface
+    }
 }
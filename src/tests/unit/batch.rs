@@ -0,0 +1,48 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{batch::Batch, data::{parse_input::{LoadSpaceData, ParsedInputData}, source::FakeDataSource}, tests::unit::full_calc::{frames::frames, input_data::input_data, loads::loads, ship::ship, tanks::tanks}};
+
+    #[test]
+    fn compute() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Batch compute";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let mut batch = Batch::new(9.81, Box::new(FakeDataSource::new(ship(), frames(), loads(), tanks())));
+        // данные по судну загружаются один раз, повторный запрос к источнику вернет ошибку
+        let result = (0..3).map(|_| batch.compute(input_data()).is_ok()).collect::<Vec<_>>();
+        let target = vec![true; 3];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // грузы варианта загрузки заменяют грузы из источника,
+        // груз в корме дает ненулевую срезающую силу
+        let mut condition = input_data();
+        condition.load_space = Some(vec![LoadSpaceData {
+            mass: 1998.75,
+            bound: (-10., 0., -5., 5.),
+            center: (-5., 0., 5.),
+            name: None,
+            category: None,
+        }]);
+        let result = batch.compute(condition).unwrap().shear_force.iter().all(|v| v.1.abs() < 0.000001);
+        let target = false;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = batch.compute(ParsedInputData {
+            ship_name: "OTHER SHIP".to_owned(),
+            ..input_data()
+        }).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
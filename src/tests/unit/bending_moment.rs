@@ -22,7 +22,7 @@ mod tests {
         let result = BendingMoment::new(&FakeShearForce::new(vec![
             0.0, 5.0, 10., 15.0, 10.0, 5.0, 0.0, -5.0, -10.0, -15.0, -15.0, 0.0,
         ]))
-        .values();
+        .values().unwrap();
         let target = Vec::from([0.0, 5.0, 20.0, 45.0, 70.0, 85.0, 90.0, 85.0, 70.0, 45.0, 15.0, 0.0]);
 
         assert!(
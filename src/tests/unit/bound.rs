@@ -16,12 +16,12 @@ mod tests {
         let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
         test_duration.run().unwrap();
         let test_data = [
-            (Bound::new(2., 4.), Bound::new(0., 1.,), None),
-            (Bound::new(2., 4.), Bound::new(4., 5.,), None),
-            (Bound::new(2., 4.), Bound::new(1., 3.,), Some(Bound::new(2., 3.))),
-            (Bound::new(2., 4.), Bound::new(1., 5.,), Some(Bound::new(2., 4.))),
-            (Bound::new(2., 4.), Bound::new(3., 5.,), Some(Bound::new(3., 4.))),
-            (Bound::new(2., 4.), Bound::new(2., 3.,), Some(Bound::new(2., 3.))),
+            (Bound::new(2., 4.).unwrap(), Bound::new(0., 1.,).unwrap(), None),
+            (Bound::new(2., 4.).unwrap(), Bound::new(4., 5.,).unwrap(), None),
+            (Bound::new(2., 4.).unwrap(), Bound::new(1., 3.,).unwrap(), Some(Bound::new(2., 3.).unwrap())),
+            (Bound::new(2., 4.).unwrap(), Bound::new(1., 5.,).unwrap(), Some(Bound::new(2., 4.).unwrap())),
+            (Bound::new(2., 4.).unwrap(), Bound::new(3., 5.,).unwrap(), Some(Bound::new(3., 4.).unwrap())),
+            (Bound::new(2., 4.).unwrap(), Bound::new(2., 3.,).unwrap(), Some(Bound::new(2., 3.).unwrap())),
         ];
         for (left, right, target) in test_data {
             let result = left.intersect(&right);
@@ -39,12 +39,12 @@ mod tests {
         let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
         test_duration.run().unwrap();
         let test_data = [
-            (Bound::new(2., 4.), Bound::new(0., 1.,), 0.),
-            (Bound::new(2., 4.), Bound::new(4., 5.,), 0.),
-            (Bound::new(2., 4.), Bound::new(1., 3.,), 0.5),
-            (Bound::new(2., 4.), Bound::new(1., 5.,), 1.),
-            (Bound::new(2., 4.), Bound::new(3., 5.,), 0.5),
-            (Bound::new(2., 4.), Bound::new(2., 3.,), 0.5),
+            (Bound::new(2., 4.).unwrap(), Bound::new(0., 1.,).unwrap(), 0.),
+            (Bound::new(2., 4.).unwrap(), Bound::new(4., 5.,).unwrap(), 0.),
+            (Bound::new(2., 4.).unwrap(), Bound::new(1., 3.,).unwrap(), 0.5),
+            (Bound::new(2., 4.).unwrap(), Bound::new(1., 5.,).unwrap(), 1.),
+            (Bound::new(2., 4.).unwrap(), Bound::new(3., 5.,).unwrap(), 0.5),
+            (Bound::new(2., 4.).unwrap(), Bound::new(2., 3.,).unwrap(), 0.5),
         ];
         for (left, right, target) in test_data {
             let result = left.part_ratio(&right);
@@ -53,6 +53,25 @@ mod tests {
         test_duration.exit();
     }
 
+    #[test]
+    fn new() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Bound new";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+        let test_data = [
+            (Bound::new(2., 4.).is_ok(), true),
+            (Bound::new(4., 4.).is_ok(), false),
+            (Bound::new(4., 2.).is_ok(), false),
+        ];
+        for (result, target) in test_data {
+            assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+        }
+        test_duration.exit();
+    }
+
     #[test]
     fn center() {
         DebugSession::init(LogLevel::Debug, Backtrace::Short);
@@ -61,7 +80,7 @@ mod tests {
         println!("{}", self_id);
         let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
         test_duration.run().unwrap();
-        let result = Bound::new(-2., 4.).center();   
+        let result = Bound::new(-2., 4.).unwrap().center();   
         let target = 1.;
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
         test_duration.exit();
@@ -0,0 +1,121 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{
+        bulk::Bulk,
+        data::parse_input::BulkData,
+        error::Error,
+        load::ILoad,
+        math::{bound::Bound, curve::Curve, pos_shift::PosShift, position::Position},
+    };
+
+    /// Трюм - параллелепипед 20 x 10 x 10 м, вместимость 2000 м^3
+    fn bulk(mass: f64, stowage_factor: f64) -> Result<Bulk, Error> {
+        Bulk::new(
+            mass,
+            stowage_factor,
+            Bound::new(-10., 10.).unwrap(),
+            PosShift::new(
+                Curve::new(vec![(0., 0.), (2000., 0.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (2000., 0.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (2000., 5.)]).unwrap(),
+            ),
+            vec![(0., 0.), (2000., 10.)],
+        )
+    }
+
+    #[test]
+    fn slack() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Bulk slack";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let bulk = bulk(1000., 0.5).unwrap();
+        let result = (bulk.volume(), bulk.level(), bulk.center(), bulk.filled());
+        let target = (500., 2.5, Position::new(0., 0., 1.25), false);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = (bulk.mass(None), bulk.mass(Some(Bound::new(0., 20.).unwrap())));
+        let target = (1000., 500.);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn capacity() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Bulk capacity";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let bulk_full = bulk(1000., 2.).unwrap();
+        let result = (bulk_full.volume(), bulk_full.level(), bulk_full.filled());
+        let target = (2000., 10., true);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = bulk(1000., 2.5).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // без кривой уровня вместимость трюма не определена
+        let result = Bulk::new(
+            1000.,
+            0.5,
+            Bound::new(-10., 10.).unwrap(),
+            PosShift::new(
+                Curve::new(vec![(0., 0.), (2000., 0.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (2000., 0.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (2000., 5.)]).unwrap(),
+            ),
+            vec![],
+        ).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn data() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test BulkData";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let data = |stowage_factor, density| BulkData {
+            mass: 1000.,
+            stowage_factor,
+            density,
+            bound: (-10., 10., -5., 5.),
+            center: vec![(0., 0., 0., 0.), (2000., 0., 0., 5.)],
+            level: vec![(0., 0.), (2000., 10.)],
+            name: None,
+            category: None,
+        };
+        let result = data(None, Some(2.)).check().map(|_| data(None, Some(2.)).stowage_factor()).ok();
+        let target = Some(0.5);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = (
+            data(Some(0.5), Some(2.)).check().is_err(),
+            data(None, None).check().is_err(),
+            data(Some(-0.5), None).check().is_err(),
+        );
+        let target = (true, true, true);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{
+        calibration::{Calibration, Fill}, load::{FreeSurface, ILoad}, tank::Tank,
+        math::{bound::Bound, curve::Curve, inertia_shift::inertia_shift::InertiaShift, pos_shift::PosShift},
+    };
+
+    /// Цистерна высотой 5 м, 20 м^3 на метр уровня, поправки
+    /// на дифферент +0.1 м на метр и на крен -0.01 м на градус
+    fn calibration() -> Calibration {
+        Calibration::new(
+            5.,
+            vec![(0., 0.), (5., 100.)],
+            Some(vec![(0., vec![(0., 0.), (5., 0.)]), (2., vec![(0., 0.2), (5., 0.2)])]),
+            Some(vec![(0., vec![(0., 0.), (5., 0.)]), (10., vec![(0., -0.1), (5., -0.1)])]),
+        ).unwrap()
+    }
+
+    #[test]
+    fn value() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Calibration value";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let calibration = calibration();
+        let result = calibration.capacity();
+        let target = 100.;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        for (fill, target) in [
+            (Fill::Sounding { value: 2., trim: 0., heel: 0. }, 40.),
+            (Fill::Sounding { value: 2., trim: 1., heel: 0. }, 42.),
+            (Fill::Sounding { value: 2., trim: 1., heel: 10. }, 40.),
+            (Fill::Ullage { value: 3., trim: 0., heel: 0. }, 40.),
+            (Fill::Volume(40.), 40.),
+            (Fill::Percent(50.), 50.),
+            (Fill::Mass(30.), 40.),
+        ] {
+            let result = calibration.value(fill, 0.75).unwrap();
+            assert!((result - target).abs() < 1e-9, "\nfill: {:?}\nresult: {:?}\ntarget: {:?}", fill, result, target);
+        }
+        test_duration.exit();
+    }
+
+    #[test]
+    fn capacity() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Calibration capacity";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let calibration = calibration();
+        for fill in [
+            Fill::Sounding { value: 5., trim: 2., heel: 0. },
+            Fill::Ullage { value: 6., trim: 0., heel: 0. },
+            Fill::Volume(120.),
+            Fill::Percent(110.),
+            Fill::Mass(80.),
+        ] {
+            let result = calibration.value(fill, 0.75).is_err();
+            let target = true;
+            assert!(result == target, "\nfill: {:?}\nresult: {:?}\ntarget: {:?}", fill, result, target);
+        }
+        test_duration.exit();
+    }
+
+    #[test]
+    fn tank() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Tank from fill";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let tank = |fill, calibration| Tank::from_fill(
+            2.,
+            fill,
+            calibration,
+            Bound::new(0., 4.).unwrap(),
+            PosShift::new(
+                Curve::new(vec![(0., 2.), (100., 2.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (100., 0.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (100., 5.)]).unwrap(),
+            ),
+            InertiaShift::new(
+                Curve::new(vec![(0., 0.), (100., 1.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (100., 1.)]).unwrap(),
+            ),
+            FreeSurface::Actual,
+        );
+        let result = tank(Fill::Sounding { value: 2., trim: 0., heel: 0. }, Some(calibration())).unwrap().mass(None);
+        let target = 80.;
+        assert!((result - target).abs() < 1e-9, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = tank(Fill::Mass(20.), None).unwrap().mass(None);
+        let target = 20.;
+        assert!((result - target).abs() < 1e-9, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = tank(Fill::Sounding { value: 2., trim: 0., heel: 0. }, None).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = tank(Fill::Percent(110.), Some(calibration())).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+        test_duration.exit();
+    }
+}
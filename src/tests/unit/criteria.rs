@@ -0,0 +1,83 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{rc::Rc, sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{criteria::{Criteria, Criterion}, cross_curves::CrossCurves, mass::{FakeMass, IMass}, math::position::Position, righting_lever::RightingLever, weather::WeatherCriterion};
+
+    // пантокарены судна с прямыми бортами, z_m = 8 м,
+    // исправленная аппликата центра масс z_g + 0.5
+    fn righting_lever(z_g: f64) -> RightingLever {
+        let kn = (0..=90).map(|i| (i as f64, 8. * (i as f64).to_radians().sin())).collect::<Vec<_>>();
+        let cross_curves = CrossCurves::new(vec![(1000., kn.clone()), (3000., kn)]).unwrap();
+        let mass: Rc<dyn IMass> = Rc::new(FakeMass::new(2050., vec![0.], Position::new(0., 0., z_g), 0.5, 0.5));
+        RightingLever::new(1.025, cross_curves, mass).unwrap()
+    }
+
+    fn actual(criteria: &Criteria) -> Vec<(String, f64, bool)> {
+        criteria.values().into_iter().map(|v| (v.name.clone(), v.actual.unwrap(), v.pass())).collect()
+    }
+
+    fn check(result: Vec<(String, f64, bool)>, target: Vec<(&str, f64, bool)>) {
+        assert!(
+            result.len() == target.len() && result.iter().zip(target.iter()).all(|(r, t)| r.0 == t.0 && (r.1 - t.1).abs() < 0.001 && r.2 == t.2),
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+    }
+
+    #[test]
+    fn values() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Criteria values";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // $l = \sin\theta$, площадь $1 - \cos\theta$
+        let result = actual(&Criteria::new(righting_lever(6.5), 1., None, None, None).unwrap());
+        let target = vec![
+            ("area_0_30", 1. - 30f64.to_radians().cos(), true),
+            ("area_0_40", 1. - 40f64.to_radians().cos(), true),
+            ("area_30_40", 30f64.to_radians().cos() - 40f64.to_radians().cos(), true),
+            ("lever_30", 1., true),
+            ("angle_max_lever", 90., true),
+            ("metacentric_height", 1., true),
+        ];
+        check(result, target);
+
+        // $l = 0.1 \sin\theta$, угол заливания 35°
+        let result = actual(&Criteria::new(righting_lever(7.4), 0.1, Some(35.), None, None).unwrap());
+        let target = vec![
+            ("area_0_30", 0.1 * (1. - 30f64.to_radians().cos()), false),
+            ("area_0_40", 0.1 * (1. - 35f64.to_radians().cos()), false),
+            ("area_30_40", 0.1 * (30f64.to_radians().cos() - 35f64.to_radians().cos()), false),
+            ("lever_30", 0.1, false),
+            ("angle_max_lever", 90., true),
+            ("metacentric_height", 0.1, false),
+        ];
+        check(result, target);
+
+        let result = Criteria::new(righting_lever(6.5), 1., Some(0.), None, None).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // качка не вычисляется: значения критерия погоды не определены, он не выполнен
+        let result = Criteria::new(righting_lever(6.5), 1., None, Some(WeatherCriterion::Unstable), None)
+            .unwrap()
+            .values()
+            .into_iter()
+            .filter(|v| v.name.starts_with("weather"))
+            .map(|v| (v.pass(), v))
+            .collect::<Vec<_>>();
+        let target = vec![
+            (false, Criterion::new_unknown("weather_angle_0", Some(16.), true)),
+            (false, Criterion::new_unknown("weather_area_b", None, false)),
+        ];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
@@ -8,26 +8,27 @@ mod tests {
     use crate::math::curve::{Curve, ICurve};
 
     #[test]
-    #[ignore = "Checked bihavior on empty input array"]
-    fn curve_except() {
+    fn curve_error() {
         DebugSession::init(LogLevel::Debug, Backtrace::Short);
         println!("");
-        let self_id = "test Curve value";
+        let self_id = "test Curve error";
         println!("{}", self_id);
         let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
         test_duration.run().unwrap();
-        let curve = Curve::new(vec![
-            // (2., 2.)
-        ]);
         let test_data = [
-            (curve.value(-1.), 2.),
-            (curve.value(0.), 2.),
-            (curve.value(1.), 2.),
-            (curve.value(2.), 2.),
-            (curve.value(3.), 2.),];
+            (Curve::new(vec![]).is_err(), true),
+            (Curve::new(vec![(2., 2.)]).is_err(), true),
+            (Curve::new(vec![(0., 0.), (2., 2.)]).is_err(), false),
+            (Curve::new(vec![(0., 0.), (f64::NAN, 2.)]).is_err(), true),
+            (Curve::new(vec![(0., 0.), (2., f64::INFINITY)]).is_err(), true),
+            (Curve::new(vec![(0., 0.), (2., 2.), (0., 1.)]).is_err(), true),];
         for (result, target) in test_data {
             assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
         }
+        // ключ не является числом - значение не является числом, без паники
+        let result = Curve::new(vec![(0., 0.), (2., 2.)]).unwrap().value(f64::NAN).is_nan();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
         test_duration.exit();
     }
 
@@ -42,7 +43,7 @@ mod tests {
         let curve = Curve::new(vec![
             (0., 0.), 
             (2., 2.)
-        ]);
+        ]).unwrap();
         let test_data = [
             (curve.value(-1.), 0.),
             (curve.value(0.), 0.),
@@ -24,11 +24,11 @@ mod tests {
         test_duration.run().unwrap();
 
         let frames = vec![
-            Frame::new(Curve::new(vec![(0., 0.), (10., 0.)])),
-            Frame::new(Curve::new(vec![(0., 0.), (10., 40.)])),
+            Frame::new(Curve::new(vec![(0., 0.), (10., 0.)]).unwrap()),
+            Frame::new(Curve::new(vec![(0., 0.), (10., 40.)]).unwrap()),
         ];
 
-        let result = Displacement::new(frames, 20.).value(Bound::new(-10., 0.), 10.);
+        let result = Displacement::new(frames, 20.).unwrap().value(Bound::new(-10., 0.).unwrap(), 10.).unwrap();
         let target = 100.;
         assert!(
             result == target,
@@ -39,4 +39,30 @@ mod tests {
 
         test_duration.exit();
     }
+
+    #[test]
+    fn value_outside_hull() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Displacement value_outside_hull";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let frames = vec![
+            Frame::new(Curve::new(vec![(0., 0.), (10., 0.)]).unwrap()),
+            Frame::new(Curve::new(vec![(0., 0.), (10., 40.)]).unwrap()),
+        ];
+
+        let result = Displacement::new(frames, 20.).unwrap().value(Bound::new(0., 15.).unwrap(), 10.).is_err();
+        let target = true;
+        assert!(
+            result == target,
+            "\nresult: {:?}\ntarget: {:?}",
+            result,
+            target
+        );
+
+        test_duration.exit();
+    }
 }
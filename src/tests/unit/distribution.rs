@@ -0,0 +1,100 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::math::{bound::Bound, distribution::Distribution};
+
+    /// Доли массы по отрезкам длиной 1 в границах (0, 6)
+    /// и центр распределенной массы
+    fn parts(center: f64) -> (Vec<f64>, f64) {
+        let distribution = Distribution::new(Bound::new(0., 6.).unwrap(), center).unwrap();
+        let parts: Vec<f64> = (0..6)
+            .map(|i| distribution.part_ratio(&Bound::new(i as f64, i as f64 + 1.).unwrap()))
+            .collect();
+        // момент линейной на отрезке интенсивности через долю массы отрезка p
+        // и долю его первой половины h: p*x_c + (p - 2h)/3
+        let moment = (0..6).map(|i| {
+            let bound = Bound::new(i as f64, i as f64 + 1.).unwrap();
+            let half = distribution.part_ratio(&Bound::new(i as f64, i as f64 + 0.5).unwrap());
+            let part = distribution.part_ratio(&bound);
+            part * bound.center() + (part - 2. * half) / 3.
+        }).sum();
+        (parts, moment)
+    }
+
+    #[test]
+    fn uniform() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Distribution uniform";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let (result, _) = parts(3.);
+        let target = vec![1. / 6.; 6];
+        assert!(
+            result.iter().zip(target.iter()).all(|(r, t)| (r - t).abs() < 1e-9),
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+        test_duration.exit();
+    }
+
+    #[test]
+    fn trapezoid() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Distribution trapezoid";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // q(x) = 1/6 + (x - 3)/36
+        let (result, moment) = parts(3.5);
+        let target = vec![7. / 72., 9. / 72., 11. / 72., 13. / 72., 15. / 72., 17. / 72.];
+        assert!(
+            result.iter().zip(target.iter()).all(|(r, t)| (r - t).abs() < 1e-9),
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+        let result = (result.iter().sum::<f64>(), moment);
+        let target = (1., 3.5);
+        assert!((result.0 - target.0).abs() < 1e-9 && (result.1 - target.1).abs() < 1e-9, "\nresult: {:?}\ntarget: {:?}", result, target);
+        test_duration.exit();
+    }
+
+    #[test]
+    fn linear() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Distribution linear";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // треугольник на (3, 6) с вершиной на 6
+        let (result, moment) = parts(5.);
+        let target = vec![0., 0., 0., 1. / 9., 3. / 9., 5. / 9.];
+        assert!(
+            result.iter().zip(target.iter()).all(|(r, t)| (r - t).abs() < 1e-9),
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+        let result = (result.iter().sum::<f64>(), moment);
+        let target = (1., 5.);
+        assert!((result.0 - target.0).abs() < 1e-9 && (result.1 - target.1).abs() < 1e-9, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // треугольник на (0, 3) с вершиной на 0
+        let (result, moment) = parts(1.);
+        let target = vec![5. / 9., 3. / 9., 1. / 9., 0., 0., 0.];
+        assert!(
+            result.iter().zip(target.iter()).all(|(r, t)| (r - t).abs() < 1e-9),
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+        let result = (result.iter().sum::<f64>(), moment);
+        let target = (1., 1.);
+        assert!((result.0 - target.0).abs() < 1e-9 && (result.1 - target.1).abs() < 1e-9, "\nresult: {:?}\ntarget: {:?}", result, target);
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{rc::Rc, sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{displacement::Displacement, equilibrium::{Convergence, Equilibrium, IDraught}, frame::Frame, mass::{FakeMass, IMass}, math::{bound::Bound, curve::{Curve, FakeCurve}, pos_shift::FakePosShift, position::Position}, trim::Trim};
+
+    fn equilibrium(mass: Rc<dyn IMass>, max_iterations: usize) -> Equilibrium {
+        // понтон 20x10 м
+        let ship_length = 20.;
+        let bounds = (0..20).map(|i| Bound::new(-10. + i as f64, -9. + i as f64).unwrap()).collect::<Vec<_>>();
+        let frames = (0..3).map(|_| Frame::new(Curve::new(vec![(0., 0.), (20., 200.)]).unwrap())).collect();
+        Equilibrium::new(
+            bounds,
+            Rc::clone(&mass),
+            Curve::new(vec![(0., 0.), (4000., 0.)]).unwrap(),
+            Curve::new(vec![(0., 0.), (4000., 20.)]).unwrap(),
+            Displacement::new(frames, ship_length).unwrap(),
+            Trim::new(
+                1.025,
+                ship_length,
+                FakePosShift::new(Position::new(0., 0., 5.)),
+                FakeCurve::new(100.),
+                Rc::clone(&mass),
+            ).unwrap(),
+            Convergence { max_iterations, ..Convergence::default() },
+        ).unwrap()
+    }
+
+    #[test]
+    fn solve() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Equilibrium solve";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // центр масс смещен в корму на 1 м
+        let mass: Rc<dyn IMass> = Rc::new(FakeMass::new(2000., vec![100.; 20], Position::new(-1., 0., 5.), 0., 0.));
+        let solver = equilibrium(Rc::clone(&mass), 100);
+        let result = solver.solve().unwrap();
+        // осадка на миделе - объемное водоизмещение на площадь ватерлинии,
+        // дифферент из равенства момента сил поддержания по шпациям
+        // $\sum B (d + t x_i / L) x_i dx = \Delta x_g$
+        let draught = 2000. / 1.025 / 200.;
+        let trim = -1. * draught * 400. / 665.;
+        assert!((result.draught - draught).abs() < 0.001, "\nresult: {:?}\ntarget: {:?}", result.draught, draught);
+        assert!((result.trim - trim).abs() < 0.001, "\nresult: {:?}\ntarget: {:?}", result.trim, trim);
+        assert!(result.mass_residual.abs() <= 2000. * 0.00001, "\nresult: {:?}", result);
+        assert!(result.shift_residual.abs() <= 0.0001, "\nresult: {:?}", result);
+        assert!(result.iterations > 1, "\nresult: {:?}", result);
+
+        let result = solver.values().unwrap().iter().sum::<f64>();
+        let target = 2000.;
+        assert!((result - target).abs() < 0.1, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = equilibrium(mass, 1).solve().is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
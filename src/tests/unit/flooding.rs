@@ -0,0 +1,205 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{rc::Rc, sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{
+        computer::Computer, data::parse_input::CompartmentData, equilibrium::FloatingPosition,
+        flooding::{Compartment, Flooding}, mass::{FakeMass, IMass},
+        math::{bound::Bound, curve::FakeCurve, pos_shift::FakePosShift, position::Position},
+        metacentric_height::MetacentricHeight,
+    };
+
+    // объемное водоизмещение 2000 м^3, z_c = 5 м, z_g + \Delta m_h = z + 0.5
+    fn metacentric_height(rad_trans: f64, z_g: f64) -> MetacentricHeight {
+        let mass: Rc<dyn IMass> = Rc::new(FakeMass::new(2050., vec![0.], Position::new(0., 0., z_g), 0.1, 0.5));
+        MetacentricHeight::new(1.025, FakePosShift::new(Position::new(0., 0., 5.)), FakeCurve::new(rad_trans), mass).unwrap()
+    }
+
+    #[test]
+    fn heel() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Flooding heel";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // отсек по правому борту, проницаемость 0.5
+        let compartment = Compartment::new(
+            Bound::new(-5., 5.).unwrap(),
+            Bound::new(0., 5.).unwrap(),
+            Bound::new(0., 20.).unwrap(),
+            0.5,
+            10.,
+        ).unwrap();
+        let result = (compartment.volume(&Bound::new(0., 10.).unwrap(), 10.), compartment.inertia(10.), compartment.inertia(25.));
+        let target = (125., 0.5 * 10. * 5. * (25. / 12. + 6.25), 0.);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let position = FloatingPosition { draught: 10., trim: 0., iterations: 1, mass_residual: 0., shift_residual: 0. };
+        let flooding = Flooding::new(vec![compartment], 20.).unwrap();
+        let result = flooding.volume(&position);
+        let target = 250.;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // корпус погружается до 2250 м^3, центр теряемого объема на высоте 5 м:
+        // $z_{c1} = (2250 \cdot 5 - 250 \cdot 5) / 2000$, $r_1 = (3 \cdot 2250 - 208.33) / 2000$
+        let inertia = 0.5 * 10. * 5. * (25. / 12. + 6.25);
+        let h = 5. + (3. * 2250. - inertia) / 2000. - 6.5;
+        let result = flooding.metacentric_height(&position, &metacentric_height(3., 6.)).unwrap();
+        let target = h;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // $\tan\theta = (250 \cdot 2.5 / 2000) / h_1$
+        let result = flooding.heel(&position, 0., &metacentric_height(3., 6.)).unwrap().unwrap();
+        let target = (0.3125f64 / h).atan().to_degrees();
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // отсек на всю ширину: потеря момента инерции ватерлинии больше
+        // метацентрической высоты 0.15 м, равновесия в прямом положении нет
+        let flooding = Flooding::new(vec![Compartment::new(
+            Bound::new(-5., 5.).unwrap(),
+            Bound::new(-5., 5.).unwrap(),
+            Bound::new(0., 20.).unwrap(),
+            0.5,
+            10.,
+        ).unwrap()], 20.).unwrap();
+        let metacentric_height = metacentric_height(0.05, 4.4);
+        let result = (
+            metacentric_height.h().unwrap(),
+            flooding.heel(&position, 0., &metacentric_height).unwrap(),
+            flooding.metacentric_height(&position, &metacentric_height).unwrap(),
+        );
+        let target = (0.15, None, 5. + (0.05 * 2500. - 0.5 * 10. * 1000. / 12.) / 2000. - 4.9);
+        assert!(
+            (result.0 - target.0).abs() < 0.000001 && result.1 == target.1 && (result.2 - target.2).abs() < 0.000001 && result.2 < 0.,
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+
+        let result = Compartment::new(Bound::new(-5., 5.).unwrap(), Bound::new(0., 5.).unwrap(), Bound::new(0., 20.).unwrap(), 1.5, 10.).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // отсек шире корпуса
+        let result = Compartment::new(Bound::new(-5., 5.).unwrap(), Bound::new(0., 6.).unwrap(), Bound::new(0., 20.).unwrap(), 0.5, 10.).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn damage() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Flooding damage";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // понтон 20 x 10 м, объемное водоизмещение 2000 м^3, осадка 10 м,
+        // затопленный отсек на всю ширину в средней части длинной 5 м
+        let mut input = crate::tests::unit::full_calc::input_data::input_data();
+        input.flooded = Some(vec![CompartmentData {
+            bound: (-2.5, 2.5, -5., 5.),
+            bound_z: (0., 20.),
+            permeability: 1.,
+        }]);
+        let computer = Computer::new(
+            9.81,
+            input,
+            crate::tests::unit::full_calc::ship::ship(),
+            crate::tests::unit::full_calc::frames::frames(),
+            crate::tests::unit::full_calc::loads::loads(),
+            crate::tests::unit::full_calc::tanks::tanks(),
+        );
+        let out = computer.out_data().unwrap();
+        let intact = out.equilibrium.unwrap();
+        let damage = out.damage.unwrap();
+        let result = (intact.draught, damage.equilibrium.draught, damage.equilibrium.trim, damage.lost_volume);
+        let target = (10., 2000. / 150., 0., 5. * 10. * 2000. / 150.);
+        assert!(
+            (result.0 - target.0).abs() < 0.001 && (result.1 - target.1).abs() < 0.001
+                && (result.2 - target.2).abs() < 0.001 && (result.3 - target.3).abs() < 0.01,
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+
+        // потеря плавучести в средней части вызывает прогиб
+        let result = damage.bending_moment.iter().map(|v| v.1).fold(f64::MAX, f64::min) < 0.
+            && out.bending_moment.iter().all(|v| v.1.abs() < 0.001);
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // отсек шире корпуса 10 м не принимается
+        let mut input = crate::tests::unit::full_calc::input_data::input_data();
+        input.flooded = Some(vec![CompartmentData {
+            bound: (-2.5, 2.5, -6., 6.),
+            bound_z: (0., 20.),
+            permeability: 1.,
+        }]);
+        let result = Computer::new(
+            9.81,
+            input,
+            crate::tests::unit::full_calc::ship::ship(),
+            crate::tests::unit::full_calc::frames::frames(),
+            crate::tests::unit::full_calc::loads::loads(),
+            crate::tests::unit::full_calc::tanks::tanks(),
+        ).out_data().is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn damage_negative_metacentric_height() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Flooding damage negative metacentric height";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // отсек на всю ширину выше ОП на 9 м: корпус погружается до осадки
+        // d = (2000 - 50 * 9) / 150, подъем центра величины меньше потери
+        // момента инерции ватерлинии 5 * 10^3 / 12 = 416.7 м^4
+        let mut input = crate::tests::unit::full_calc::input_data::input_data();
+        input.flooded = Some(vec![CompartmentData {
+            bound: (-2.5, 2.5, -5., 5.),
+            bound_z: (9., 20.),
+            permeability: 1.,
+        }]);
+        let mut ship = crate::tests::unit::full_calc::ship::ship();
+        ship.rad_trans = Some(vec![(0., 0.05), (4000., 0.05)]);
+        let out = Computer::new(
+            9.81,
+            input,
+            ship,
+            crate::tests::unit::full_calc::frames::frames(),
+            crate::tests::unit::full_calc::loads::loads(),
+            crate::tests::unit::full_calc::tanks::tanks(),
+        ).out_data().unwrap();
+        let intact = out.stability.unwrap().h;
+        let damage = out.damage.unwrap();
+        // z_c = V / 400 по кривой center_shift, z_g + \Delta m_h = z_m - h
+        let draught = (2000. - 50. * 9.) / 150.;
+        let lost = 50. * (draught - 9.);
+        let volume = 2000. + lost;
+        let z_c = (volume * volume / 400. - lost * (9. + (draught - 9.) / 2.)) / 2000.;
+        let rad_trans = (0.05 * volume - 5. * 1000. / 12.) / 2000.;
+        let h = z_c + rad_trans - (5. + 0.05 - intact);
+        let result = (
+            intact > 0.,
+            damage.metacentric_height.map(|v| (v - h).abs() < 0.001 && v < 0.),
+            damage.equilibrium.heel,
+            damage.bending_moment.is_empty(),
+        );
+        let target = (true, Some(true), None, false);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
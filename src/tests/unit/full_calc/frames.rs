@@ -6,7 +6,15 @@ pub(crate) fn frames() -> ParsedFramesData {
         "frames": [ 
             {
                 "index": 0, 
-                "immersion_area": [[0.0, 0.0], [1.0, 1.0], [5.0, 10.0]]
+                "immersion_area": [[0.0, 0.0], [20.0, 200.0]]
+            },
+            {
+                "index": 1, 
+                "immersion_area": [[0.0, 0.0], [20.0, 200.0]]
+            },
+            {
+                "index": 2, 
+                "immersion_area": [[0.0, 0.0], [20.0, 200.0]]
             }
         ]
     }"#;        
@@ -5,12 +5,12 @@ pub(crate) fn loads() -> ParsedLoadsData {
     {
         "load_space": [ 
             {
-                "mass": 10.0,
-                "bound": [-10.0, 0.0, 0.0, 5.0], 
-                "center": [0.0, 0.0, 1.0]
+                "mass": 1998.75,
+                "bound": [-10.0, 10.0, -5.0, 5.0], 
+                "center": [0.0, 0.0, 5.0]
             }
         ]
     }"#;        
 
     ParsedLoadsData::parse(&data).expect("parse error")
-}
\ No newline at end of file
+}
@@ -5,14 +5,14 @@ pub(crate) fn tanks() -> ParsedTanksData {
     {
         "tanks": [
             {
-                "density": 0.7,
-                "volume": 10.0,
-                "bound": [10.0, 20.0, 5.0, 10.0], 
-                "center": [[0.0, 15.0, 7.5, -1.0], [10.0, 15.0, 7.5, 2.0]],
-                "free_surf_inertia": [[0.0, 0.0, 0.0], [10.0, 0.0, 0.0]]       
+                "density": 1.025,
+                "volume": 50.0,
+                "bound": [-10.0, 10.0, -5.0, 5.0], 
+                "center": [[0.0, 0.0, 0.0, 0.0], [100.0, 0.0, 0.0, 1.0]],
+                "free_surf_inertia": [[0.0, 0.0, 0.0], [100.0, 10.0, 100.0]]       
             }
         ]
     }"#;        
 
     ParsedTanksData::parse(&data).expect("parse error")
-}
\ No newline at end of file
+}
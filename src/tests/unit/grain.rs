@@ -0,0 +1,98 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{rc::Rc, sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{
+        cross_curves::CrossCurves, grain::Grain, grain_heel::GrainHeel, load::ILoad,
+        mass::{FakeMass, IMass}, math::{bound::Bound, curve::Curve, position::Position},
+        righting_lever::RightingLever,
+    };
+
+    fn grain(volume: f64) -> Result<Grain, crate::error::Error> {
+        Grain::new(
+            1.25,
+            volume,
+            1000.,
+            Bound::new(-10., 10.).unwrap(),
+            Position::new(0., 0., 5.),
+            Curve::new(vec![(0., 0.), (500., 1000.), (1000., 500.)]).unwrap(),
+        )
+    }
+
+    // пантокарены судна с прямыми бортами, z_m = 8 м,
+    // исправленная аппликата центра масс 7 м, $l = \sin\theta$
+    fn righting_lever() -> RightingLever {
+        let kn = (0..=90).map(|i| (i as f64, 8. * (i as f64).to_radians().sin())).collect::<Vec<_>>();
+        let cross_curves = CrossCurves::new(vec![(1000., kn.clone()), (3000., kn)]).unwrap();
+        let mass: Rc<dyn IMass> = Rc::new(FakeMass::new(2050., vec![0.], Position::new(0., 0., 6.5), 0.5, 0.5));
+        RightingLever::new(1.025, cross_curves, mass).unwrap()
+    }
+
+    #[test]
+    fn load() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Grain load";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let result = (grain(500.).unwrap().mass(None), grain(500.).unwrap().mass(Some(Bound::new(0., 10.).unwrap())));
+        let target = (400., 200.);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // частично заполненный трюм 1.12 * 1000 / 1.25, заполненный 1.06 * 500 / 1.25
+        let result = (grain(500.).unwrap().moment_grain(), grain(1000.).unwrap().moment_grain());
+        let target = (896., 424.);
+        assert!(
+            (result.0 - target.0).abs() < 0.000001 && (result.1 - target.1).abs() < 0.000001,
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+
+        let result = grain(1001.).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn heel() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test GrainHeel heel";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // $\lambda_0 = 205 / 2050 = 0.1$, $\lambda_{40} = 0.08$
+        let grain_heel = GrainHeel::new(righting_lever(), 205., 2050., None).unwrap();
+        let result = (grain_heel.lever(0.), grain_heel.lever(40.));
+        let target = (0.1, 0.08);
+        assert!(
+            (result.0 - target.0).abs() < 0.000001 && (result.1 - target.1).abs() < 0.000001,
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+
+        // $\sin\theta = 0.1 (1 - 0.005 \theta)$
+        let angle = (0..10).fold(0f64, |a, _| (0.1 * (1. - 0.005 * a)).asin().to_degrees());
+        let result = grain_heel.angle();
+        let target = angle;
+        assert!((result - target).abs() < 0.01, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // разность ординат растет до 40°
+        let result = grain_heel.area();
+        let target = angle.to_radians().cos() - 40f64.to_radians().cos()
+            - (grain_heel.lever(angle) + 0.08) / 2. * (40. - angle).to_radians();
+        assert!((result - target).abs() < 0.0001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = GrainHeel::new(righting_lever(), 205., 2050., Some(30.)).unwrap().angle_limit();
+        let target = 30.;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{rc::Rc, sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{computer::Computer, cross_curves::CrossCurves, heel::Heel, mass::{FakeMass, IMass}, math::{curve::FakeCurve, pos_shift::FakePosShift, position::Position}, metacentric_height::MetacentricHeight};
+
+    fn mass(y: f64) -> Rc<dyn IMass> {
+        Rc::new(FakeMass::new(2000., vec![0.], Position::new(0., y, 6.), 0.1, 0.5))
+    }
+
+    fn metacentric_height(y: f64) -> MetacentricHeight {
+        MetacentricHeight::new(
+            1.025,
+            FakePosShift::new(Position::new(0., 0., 5.)),
+            FakeCurve::new(3.),
+            mass(y),
+        ).unwrap()
+    }
+
+    // пантокарены судна с прямыми бортами, z_m = 8 м
+    fn cross_curves() -> CrossCurves {
+        let kn = (0..=12).map(|i| (i as f64 * 5., 8. * (i as f64 * 5.).to_radians().sin())).collect::<Vec<_>>();
+        CrossCurves::new(vec![(1000., kn.clone()), (3000., kn)]).unwrap()
+    }
+
+    #[test]
+    fn small_angle() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Heel small_angle";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // h = 8 - 6 - 0.5 = 1.5
+        let result = Heel::new(
+            1.025,
+            FakePosShift::new(Position::new(0., 0., 5.)),
+            mass(0.1),
+            Some(metacentric_height(0.1)),
+            None,
+        ).unwrap().value().unwrap();
+        let target = (0.1f64 / 1.5).atan().to_degrees();
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = Heel::new(1.025, FakePosShift::new(Position::new(0., 0., 5.)), mass(0.1), None, None).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn large_angle() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Heel large_angle";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // $8 \sin\theta - 6.5 \sin\theta = y_g \cos\theta$
+        let heel = |y: f64| Heel::new(
+            1.025,
+            FakePosShift::new(Position::new(0., 0., 5.)),
+            mass(y),
+            Some(metacentric_height(y)),
+            Some(cross_curves()),
+        ).unwrap();
+        let result = heel(0.1).value().unwrap();
+        let target = (0.1f64 / 1.5).atan().to_degrees();
+        assert!((result - target).abs() < 0.05, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // погрешность линейной интерполяции KN между точками через 5 градусов
+        let result = heel(-1.).value().unwrap();
+        let target = -(1f64 / 1.5).atan().to_degrees();
+        assert!((result - target).abs() < 0.2, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = heel(0.).value().unwrap();
+        let target = 0.;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = heel(5.).value().is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // центр масс над центром величины, смещенным от ДП: крена нет
+        let result = Heel::new(
+            1.025,
+            FakePosShift::new(Position::new(0., 0.1, 5.)),
+            mass(0.1),
+            Some(metacentric_height(0.1)),
+            Some(cross_curves()),
+        ).unwrap();
+        let result = (result.large_angle().unwrap(), result.small_angle().unwrap());
+        let target = (0., 0.);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn negative_metacentric_height() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Heel negative metacentric height";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // центр величины на уровне основной плоскости, h < 0,
+        // пантокарены не заданы: крен не вычисляется, прочность вычисляется
+        let mut ship = crate::tests::unit::full_calc::ship::ship();
+        ship.center_shift = vec![(0., 0., 0., 0.), (4000., 0., 0., 0.)];
+        ship.rad_trans = Some(vec![(0., 1.), (4000., 1.)]);
+        let out = Computer::new(
+            9.81,
+            crate::tests::unit::full_calc::input_data::input_data(),
+            ship,
+            crate::tests::unit::full_calc::frames::frames(),
+            crate::tests::unit::full_calc::loads::loads(),
+            crate::tests::unit::full_calc::tanks::tanks(),
+        ).out_data().unwrap();
+        let result = (
+            out.stability.map(|s| s.h < 0.),
+            out.equilibrium.map(|e| e.heel),
+            out.bending_moment.is_empty(),
+        );
+        let target = (Some(true), Some(None), false);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{computer::Computer, frame::Frame, hydrostatics::{Hydrostatics, MISMATCH_TOLERANCE}, math::curve::{Curve, ICurve}};
+
+    #[test]
+    fn values() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Hydrostatics values";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // понтон 20x10 м, 21 шпангоут через 1 м
+        let frames = (0..21).map(|_| Frame::new(Curve::new(vec![(0., 0.), (20., 200.)]).unwrap())).collect();
+        let hydrostatics = Hydrostatics::new(frames, 20., vec![0., 5., 10., 20.]).unwrap();
+
+        let result = hydrostatics.values().iter().find(|v| v.draught == 10.).unwrap().volume;
+        let target = 2000.;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = hydrostatics.draught().unwrap().value(1000.);
+        let target = 5.;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = hydrostatics.center_x().unwrap().value(10.);
+        let target = 0.;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = hydrostatics.center_z().unwrap().value(10.);
+        let target = 5.;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = hydrostatics.waterplane_area().unwrap().value(10.);
+        let target = 200.;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = hydrostatics.center_waterline().unwrap().value(10.);
+        let target = 0.;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // момент инерции ватерлинии методом трапеций по 21 шпангоуту:
+        // $I = b (\sum x_i^2 - (x_0^2 + x_{20}^2)/2) = 10 * 670$
+        let result = hydrostatics.rad_long().unwrap().value(10.);
+        let target = 6700. / 2000.;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let frames = (0..21).map(|_| Frame::new(Curve::new(vec![(0., 0.), (20., 200.)]).unwrap())).collect();
+        let result = Hydrostatics::new(frames, 20., vec![5., 10.]).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn mismatch() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Hydrostatics mismatch";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // в данных по судну продольный метацентрический радиус задан
+        // постоянным, остальные кривые соответствуют понтону
+        let result = Computer::hydrostatics_mismatch(
+            &crate::tests::unit::full_calc::ship::ship(),
+            &crate::tests::unit::full_calc::frames::frames(),
+            MISMATCH_TOLERANCE,
+        )
+        .unwrap();
+        assert!(result.len() == 1, "\nresult: {:?}", result);
+        assert!(result[0].curve == "rad_long" && result[0].volume == 4000. && result[0].supplied == 100., "\nresult: {:?}", result);
+        assert!((result[0].derived - 2.5).abs() < 0.000001, "\nresult: {:?}", result);
+
+        // объем на осадке 20 м в данных по судну завышен: площадь ватерлинии
+        // на втором отрезке кривой mean_draught (2400 / 10) больше вычисленной
+        let mut ship = crate::tests::unit::full_calc::ship::ship();
+        ship.mean_draught = vec![(0., 0.), (2000., 10.), (4400., 20.)];
+        let result = Computer::hydrostatics_mismatch(
+            &ship,
+            &crate::tests::unit::full_calc::frames::frames(),
+            MISMATCH_TOLERANCE,
+        )
+        .unwrap();
+        let result = result.iter().find(|v| v.curve == "waterplane_area").map(|v| (v.volume, v.supplied, v.derived.round()));
+        let target = Some((3200., 240., 200.));
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
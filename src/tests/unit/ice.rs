@@ -0,0 +1,121 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{
+        computer::Computer,
+        data::parse_input::{IceAreaData, IceData, Icing, ParsedInputData, ParsedShipData},
+        ice::Ice,
+        load::ILoad,
+        math::{bound::Bound, position::Position},
+    };
+
+    /// Палуба 200 м^2 по всей длине понтона и площадь парусности 40 м^2 в корме
+    fn ice(ratio: f64) -> Result<Ice, crate::error::Error> {
+        Ice::new(
+            ratio,
+            vec![(200., Bound::new(-10., 10.).unwrap(), Position::new(0., 0., 10.))],
+            vec![(40., Bound::new(-10., 0.).unwrap(), Position::new(-5., 0., 12.))],
+        )
+    }
+
+    #[test]
+    fn loads() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Ice loads";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let full = ice(1.).unwrap();
+        let result: Vec<(f64, f64, Position)> = full
+            .deck()
+            .unwrap()
+            .iter()
+            .chain(full.windage().unwrap().iter())
+            .map(|l| (l.mass(None), l.mass(Some(Bound::new(-10., -5.).unwrap())), l.center()))
+            .collect();
+        let target = vec![
+            (6., 1.5, Position::new(0., 0., 10.)),
+            (0.6, 0.3, Position::new(-5., 0., 12.)),
+        ];
+        assert!(result.len() == target.len(), "\nresult: {:?}\ntarget: {:?}", result, target);
+        for (result, target) in result.iter().zip(target.iter()) {
+            assert!(
+                (result.0 - target.0).abs() < 0.000001 && (result.1 - target.1).abs() < 0.000001 && result.2 == target.2,
+                "\nresult: {:?}\ntarget: {:?}", result, target
+            );
+        }
+
+        let result = (full.mass(), ice(0.5).unwrap().mass());
+        let target = (6.6, 3.3);
+        assert!((result.0 - target.0).abs() < 0.000001 && (result.1 - target.1).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = (ice(0.).is_err(), ice(1.5).is_err());
+        let target = (true, true);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn computer() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Ice computer";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let ship = || ParsedShipData {
+            ice: Some(IceData {
+                deck: vec![IceAreaData { area: 200., bound: (-10., 10.), center: (0., 0., 10.) }],
+                windage: vec![IceAreaData { area: 40., bound: (-10., 0.), center: (-5., 0., 12.) }],
+            }),
+            ..crate::tests::unit::full_calc::ship::ship()
+        };
+        let computer = |icing, ship| Computer::new(
+            9.81,
+            ParsedInputData {
+                icing,
+                ..crate::tests::unit::full_calc::input_data::input_data()
+            },
+            ship,
+            crate::tests::unit::full_calc::frames::frames(),
+            crate::tests::unit::full_calc::loads::loads(),
+            crate::tests::unit::full_calc::tanks::tanks(),
+        );
+        let clean = computer(None, ship()).out_data().unwrap();
+        let iced = computer(Some(Icing::Half), ship()).out_data().unwrap();
+
+        let ice = iced.ice.clone().unwrap();
+        let result = (ice.deck, ice.windage, ice.mass, ice.center.0);
+        let target = (3., 0.3, 3.3, -1.5 / 3.3);
+        assert!(
+            (result.0 - target.0).abs() < 0.000001 && (result.1 - target.1).abs() < 0.000001
+                && (result.2 - target.2).abs() < 0.000001 && (result.3 - target.3).abs() < 0.000001,
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+
+        // лед входит в нагрузку судна: растут осадка и водоизмещение,
+        // появляются срезающие силы
+        let result = (
+            clean.ice.is_none(),
+            iced.equilibrium.as_ref().unwrap().draught > clean.equilibrium.as_ref().unwrap().draught,
+            (iced.summary.as_ref().unwrap().displacement - clean.summary.as_ref().unwrap().displacement - 3.3).abs() < 0.000001,
+            iced.shear_force.iter().any(|v| v.1.abs() > 0.000001),
+        );
+        let target = (true, true, true, true);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = computer(Some(Icing::Full), crate::tests::unit::full_calc::ship::ship()).out_data().is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
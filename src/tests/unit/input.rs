@@ -30,8 +30,29 @@ mod tests {
             ship_name: "YURIY ARSHENEVSKIY".to_string(),
             n_parts: 20,
             water_density: 1.025,     
+            load_space: None,
+            grain: None,
+            bulk: None,
+            tanks: None,
+            condition: Condition::Sea,
+            heel_angles: None,
+            flooded: None,
+            containers: None,
+            voyage: None,
+            icing: None,
+            convergence: None,
         };
 
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // условия сходимости задаются частично, нулевое количество итераций - ошибка
+        let data = data.replace("\"n_parts\"", "\"convergence\": { \"max_iterations\": 10 }, \"n_parts\"");
+        let result = ParsedInputData::parse(&data).expect("parse error").convergence;
+        let target = Some(ConvergenceData { mass_tolerance: None, shift_tolerance: None, max_iterations: Some(10) });
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = ParsedInputData::parse(&data.replace("10", "0")).is_err();
+        let target = true;
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
         test_duration.exit();
     }
@@ -61,12 +82,94 @@ mod tests {
             rad_long: vec![ (0.0, 0.0), (10.0, 2.0)],
             mean_draught: vec![ (0.0, 0.0), (10.0, 3.0)],
             center_shift: vec![(0.0, 2.0, 0.0, 0.0), (10.0, 2.0, 0.0, 0.0),],           
+            rad_trans: None,
+            cross_curves: None,
+            flooding_angle: None,
+            strength_limits: None,
+            wave: None,
+            weather: None,
+            lightship: None,
+            stowage: None,
+            ice: None,
         };
 
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
         test_duration.exit();
     }
 
+    #[test]
+    fn ship_limits() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Parse ship limits";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let data = r#"
+        {
+            "ship_length": 200.0, 
+            "center_waterline": [[0.0, 0.0], [10.0, 1.0]],
+            "rad_long": [[0.0, 0.0], [10.0, 2.0]],
+            "mean_draught": [[0.0, 0.0], [10.0, 3.0]],
+            "center_shift": [[0.0, 2.0, 0.0, 0.0], [10.0, 2.0, 0.0, 0.0]],
+            "strength_limits": {
+                "sea": {
+                    "sf_positive": [[-100.0, 10.0], [100.0, 10.0]],
+                    "sf_negative": [[-100.0, 10.0], [100.0, 10.0]],
+                    "bm_hogging": [[-100.0, 20.0], [100.0, 20.0]],
+                    "bm_sagging": [[-100.0, 20.0], [100.0, 20.0]]
+                },
+                "harbor": {
+                    "sf_positive": [[-100.0, 15.0], [100.0, 15.0]],
+                    "sf_negative": [[-100.0, 15.0], [100.0, 15.0]],
+                    "bm_hogging": [[-100.0, 30.0], [100.0, 30.0]],
+                    "bm_sagging": [[-100.0, 30.0], [100.0, 0.0]]
+                }
+            }
+        }"#;
+
+        let result = ParsedShipData::parse(&data).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = ParsedShipData::parse(&data.replace("[100.0, 0.0]", "[100.0, 30.0]"))
+            .expect("parse error")
+            .strength_limits
+            .map(|limits| limits.harbor.bm_sagging);
+        let target = Some(vec![(-100.0, 30.0), (100.0, 30.0)]);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+        test_duration.exit();
+    }
+
+    #[test]
+    fn ship_wave() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Parse ship wave";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // волновые нагрузки по IACS UR S11 определены для длинны от 90 до 500 м
+        let data = r#"
+        {
+            "ship_length": 200.0,
+            "center_waterline": [[0.0, 0.0], [10.0, 1.0]],
+            "rad_long": [[0.0, 0.0], [10.0, 2.0]],
+            "mean_draught": [[0.0, 0.0], [10.0, 3.0]],
+            "center_shift": [[0.0, 2.0, 0.0, 0.0], [10.0, 2.0, 0.0, 0.0]],
+            "wave": { "breadth": 30.0, "block_coefficient": 0.8 }
+        }"#;
+        let result = (
+            ParsedShipData::parse(&data).is_ok(),
+            ParsedShipData::parse(&data.replace("200.0", "80.0")).is_err(),
+        );
+        let target = (true, true);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+        test_duration.exit();
+    }
+
     #[test]
     fn frames() {
         DebugSession::init(LogLevel::Debug, Backtrace::Short);
@@ -132,7 +235,11 @@ mod tests {
                 mass: 10.0, 
                 bound: (-10.0, 0.0, 0.0, 5.0), 
                 center: (0.0, 0.0, 1.0), 
+                name: None,
+                category: None,
             }, ],          
+            grain: vec![],
+            bulk: vec![],
         };
 
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
@@ -165,13 +272,23 @@ mod tests {
         let target = ParsedTanksData {
             tanks: vec![ TankData { 
                 density: 0.7, 
-                volume: 10.0, 
+                volume: Some(10.0), 
+                fill: None,
+                calibration: None,
                 bound: (10.0, 20.0, 5.0, 10.0), 
                 center: vec![(0.0, 15.0, 7.5, -1.0), (10.0, 15.0, 7.5, 2.0),],
                 free_surf_inertia: vec![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0),],
+                free_surface: None,
+                name: None,
+                category: None,
             }, ],           
         };
 
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // должен быть задан объем или заполнение цистерны
+        let result = ParsedTanksData::parse(&data.replace("\"volume\": 10.0,", "")).is_err();
+        let target = true;
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
         test_duration.exit();
     }
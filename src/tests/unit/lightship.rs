@@ -0,0 +1,88 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{lightship::Lightship, load::ILoad, math::{bound::Bound, position::Position}};
+
+    fn lightship(x_g: f64, shape: Option<Vec<(f64, f64)>>) -> Result<Lightship, crate::error::Error> {
+        Lightship::new(1000., Position::new(x_g, 0., 8.), Bound::new(-50., 50.).unwrap(), shape)
+    }
+
+    // момент массы по отрезкам длинной 1 м с поправкой на наклон интенсивности,
+    // точный если изломы интенсивности совпадают с границами отрезков
+    fn moment(lightship: &Lightship) -> f64 {
+        (0..100).map(|i| {
+            let bound = Bound::new(-50. + i as f64, -49. + i as f64).unwrap();
+            let (q_0, q_1) = (lightship.intensity(bound.start()), lightship.intensity(bound.end()));
+            lightship.mass(Some(bound)) * bound.center() + (q_1 - q_0) / 12.
+        }).sum()
+    }
+
+    #[test]
+    fn trapezoid() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Lightship trapezoid";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // $q = m/L (1 + 12 x_g x / L^2)$
+        let load = lightship(5., None).unwrap();
+        let result = (
+            load.intensity(-50.),
+            load.intensity(50.),
+            load.mass(Some(Bound::new(0., 50.).unwrap())),
+            load.mass(Some(Bound::new(50., 60.).unwrap())),
+            load.mass(None),
+        );
+        let target = (7., 13., 575., 0., 1000.);
+        assert!(
+            (result.0 - target.0).abs() < 0.000001 && (result.1 - target.1).abs() < 0.000001
+                && (result.2 - target.2).abs() < 0.000001 && result.3 == target.3 && result.4 == target.4,
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+
+        let result = moment(&load);
+        let target = 5000.;
+        assert!((result - target).abs() < 0.0001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // центр масс дальше L/6 от миделя дает отрицательную интенсивность
+        let result = lightship(20., None).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn table() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Lightship table";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let shape = Some(vec![(-50., 0.5), (-20., 1.), (20., 1.), (50., 0.5)]);
+        let result = lightship(0., shape.clone()).unwrap().intensity(0.);
+        let target = 1000. / 85.;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let load = lightship(-3., shape).unwrap();
+        let result = (0..10)
+            .map(|i| load.mass(Some(Bound::new(-50. + i as f64 * 10., -40. + i as f64 * 10.).unwrap())))
+            .sum::<f64>();
+        let target = 1000.;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = moment(&load);
+        let target = -3000.;
+        assert!((result - target).abs() < 0.0001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
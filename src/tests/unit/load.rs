@@ -16,13 +16,29 @@ mod tests {
         let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
         test_duration.run().unwrap();
 
-        let result = LoadSpace::new( 20., Bound::new(-1., 3.), Position::new( 1., 0., 0.)).mass(Some(Bound::new(1., 3.)));
+        let result = LoadSpace::new( 20., Bound::new(-1., 3.).unwrap(), Position::new( 1., 0., 0.)).unwrap().mass(Some(Bound::new(1., 3.).unwrap()));
         let target = 10.;
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
 
         test_duration.exit();
     }
 
+    #[test]
+    fn center_outside_bound() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Load center_outside_bound";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let result = LoadSpace::new( 20., Bound::new(-1., 3.).unwrap(), Position::new( 3., 0., 0.)).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
     #[test]
     fn moment() {
         DebugSession::init(LogLevel::Debug, Backtrace::Short);
@@ -32,7 +48,7 @@ mod tests {
         let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
         test_duration.run().unwrap();
 
-        let result = LoadSpace::new( 20., Bound::new(-1., 3.), Position::new( 1., 0., 0.),).moment_mass();
+        let result = LoadSpace::new( 20., Bound::new(-1., 3.).unwrap(), Position::new( 1., 0., 0.),).unwrap().moment_mass();
         let target = MassMoment::new(20., 0., 0.);
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
 
@@ -26,45 +26,45 @@ mod tests {
     fn init_once() {
         INIT.call_once(|| {
             let center = PosShift::new(
-                Curve::new(vec![(0., 1.), (10., 1.)]),
-                Curve::new(vec![(0., 0.), (10., 0.)]),
-                Curve::new(vec![(0., 0.), (10., 0.)]),
+                Curve::new(vec![(0., 1.), (10., 1.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 0.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 0.)]).unwrap(),
             );
 
             let free_surf_inertia = InertiaShift::new(
-                Curve::new(vec![(0., 0.), (10., 1.)]),
-                Curve::new(vec![(0., 0.), (10., 1.)]),
+                Curve::new(vec![(0., 0.), (10., 1.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 1.)]).unwrap(),
             );
 
             let loads: Vec<Rc<Box<dyn ILoad>>> = vec![
                 Rc::new(Box::new(LoadSpace::new(
                     10.,
-                    Bound::new(-10., 0.),
+                    Bound::new(-10., 0.).unwrap(),
                     Position::new(-5., 0., 0.),
-                ))),
+                ).unwrap())),
                 Rc::new(Box::new(LoadSpace::new(
                     20.,
-                    Bound::new(0., 10.),
+                    Bound::new(0., 10.).unwrap(),
                     Position::new(5., 0., 0.),
-                ))),
+                ).unwrap())),
                 Rc::new(Box::new(Tank::new(
                     2.,
                     10.,
-                    Bound::new(-5., 5.),
+                    Bound::new(-5., 5.).unwrap(),
                     center,
                     free_surf_inertia,
-                ))),
+                ).unwrap())),
             ];
 
             let bounds = vec![
-                Bound::new(-10., -5.),
-                Bound::new(-5., 0.),
-                Bound::new(0., 5.),
-                Bound::new(5., 10.),
+                Bound::new(-10., -5.).unwrap(),
+                Bound::new(-5., 0.).unwrap(),
+                Bound::new(0., 5.).unwrap(),
+                Bound::new(5., 10.).unwrap(),
             ];
 
             unsafe {
-                MASS.replace(Mass::new(loads, bounds));
+                MASS.replace(Mass::new(loads, bounds).unwrap());
             }
         })
     }
@@ -81,27 +81,27 @@ mod tests {
 
         /*     let loads: Vec<Rc<Box<dyn ILoad>>> = vec![
                     Rc::new(Box::new(LoadSpace::new(
-                        Bound::new(-10., 0.),
+                        Bound::new(-10., 0.).unwrap(),
                         Position::new(-5., 0., 0.),
                         10.,
-                    ))),
+                    ).unwrap())),
                     Rc::new(Box::new(LoadSpace::new(
-                        Bound::new(0., 10.),
+                        Bound::new(0., 10.).unwrap(),
                         Position::new(5., 0., 0.),
                         20.,
-                    ))),
+                    ).unwrap())),
                     Rc::new(Box::new(LoadSpace::new(
-                        Bound::new(-5., 5.),
+                        Bound::new(-5., 5.).unwrap(),
                         Position::new(0., 0., 0.),
                         10.,
-                    ))),
+                    ).unwrap())),
                 ];
 
                 let bounds = vec![
-                    Bound::new(-10., -5.),
-                    Bound::new(-5., 0.),
-                    Bound::new(0., 5.),
-                    Bound::new(5., 10.),
+                    Bound::new(-10., -5.).unwrap(),
+                    Bound::new(-5., 0.).unwrap(),
+                    Bound::new(0., 5.).unwrap(),
+                    Bound::new(5., 10.).unwrap(),
                 ];
         */
         let result = unsafe { MASS.clone().unwrap().sum() };
@@ -126,10 +126,12 @@ mod tests {
         let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
         test_duration.run().unwrap();
 
-        let result = unsafe { MASS.clone().unwrap().values() };
-        let target = vec![5., 15., 20., 10.];
+        // центр цистерны x = 1 смещен от середины ее границ,
+        // масса цистерны распределена по трапеции: 7 и 13
+        let result = unsafe { MASS.clone().unwrap().values().unwrap() };
+        let target = vec![5., 12., 23., 10.];
         assert!(
-            result == target,
+            result.iter().zip(target.iter()).all(|(r, t)| (r - t).abs() < 1e-9),
             "\nresult: {:?}\ntarget: {:?}",
             result,
             target
@@ -148,7 +150,7 @@ mod tests {
         let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
         test_duration.run().unwrap();
 
-        let result = unsafe { MASS.clone().unwrap().delta_m_h() };
+        let result = unsafe { MASS.clone().unwrap().delta_m_h().unwrap() };
         let target = 0.04; // valie from curve 1. * density 2. / mass sum 50.
         assert!(
             result == target,
@@ -159,4 +161,43 @@ mod tests {
 
         test_duration.exit();
     }
+
+    #[test]
+    fn delta_m_h_trans() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        println!("");
+        let self_id = "test Mass delta_m_h_trans";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let result = unsafe { MASS.clone().unwrap().delta_m_h_trans().unwrap() };
+        let target = 0.04; // value from curve 1. * density 2. / mass sum 50.
+        assert!(
+            result == target,
+            "\nresult: {:?}\ntarget: {:?}",
+            result,
+            target
+        );
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn zero_sum() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Mass zero_sum";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let mass = Mass::new(vec![], vec![Bound::new(-10., 10.).unwrap()]).unwrap();
+        let result = (mass.delta_m_h().is_err(), mass.delta_m_h_trans().is_err());
+        let target = (true, true);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
 }
@@ -0,0 +1,49 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{rc::Rc, sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{mass::FakeMass, math::{curve::FakeCurve, pos_shift::FakePosShift, position::Position}, metacentric_height::MetacentricHeight};
+
+    #[test]
+    fn metacentric_height() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test MetacentricHeight";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let metacentric_height = MetacentricHeight::new(
+            1.025,
+            FakePosShift::new(Position::new(0., 0., 5.)),
+            FakeCurve::new(3.),
+            Rc::new(FakeMass::new(2000., vec![0.], Position::new(0., 0., 6.), 0.1, 0.5)),
+        ).unwrap();
+
+        let result = metacentric_height.z_m().unwrap();
+        let target = 8.;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = metacentric_height.h_0().unwrap();
+        let target = 2.;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = metacentric_height.h().unwrap();
+        let target = 1.5;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = MetacentricHeight::new(
+            1.025,
+            FakePosShift::new(Position::new(0., 0., 5.)),
+            FakeCurve::new(3.),
+            Rc::new(FakeMass::new(0., vec![0.], Position::new(0., 0., 6.), 0.1, 0.5)),
+        ).unwrap().h().is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
@@ -1,17 +1,38 @@
 //! Юнит-тесты
 mod input;
+mod batch;
 mod bound;
+mod calibration;
 mod vec;
 mod output;
+mod source;
+mod stowage;
+mod summary;
+mod criteria;
 mod curve;
+mod flooding;
 mod frame;
+mod grain;
+mod heel;
+mod hydrostatics;
+mod ice;
 mod displacement;
+mod distribution;
+mod lightship;
 mod load;
 mod mass;
-mod draught;
+mod righting_lever;
+mod metacentric_height;
+mod equilibrium;
 mod shear_force;
 mod tank;
 mod total_force;
 mod trim;
+mod utilisation;
+mod voyage;
+mod wave;
+mod weather;
 mod bending_moment;
-// mod full_calc;
\ No newline at end of file
+mod bulk;
+#[cfg(test)]
+mod full_calc;
\ No newline at end of file
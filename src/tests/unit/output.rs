@@ -18,7 +18,22 @@ mod tests {
 
         let data = OutData {
             shear_force: vec![ (-10.0, 0.0), (-5.0, -10.0), (0.0, 0.0), (5.0, 10.0), (10.0, 0.0)],
-            bending_moment: vec![(-10.0, 0.0), (-5.0, 5.0), (0.0, 10.0), (5.0, 5.0), (10.0, 0.0)]           
+            bending_moment: vec![(-10.0, 0.0), (-5.0, 5.0), (0.0, 10.0), (5.0, 5.0), (10.0, 0.0)],
+            utilisation: None,
+            wave: None,
+            equilibrium: None,
+            stability: None,
+            righting_lever: None,
+            criteria: None,
+            weather: None,
+            grain: None,
+            bulk: vec![],
+            voyage: None,
+            summary: None,
+            stowage: None,
+            ice: None,
+            damage: None,
+            hydrostatics_mismatch: Vec::new(),
         };        
     
         let result = OutData::serialize(&data).expect("serialize error");
@@ -34,4 +49,20 @@ mod tests {
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
         test_duration.exit();
     }
+
+    #[test]
+    fn serizlize_error() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Serizlize error";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let result = serde_json::to_string(&OutError::new("Bound: start 1 must be lower than end 0".to_owned())).expect("serialize error");
+        let target = r#"{"error":"Bound: start 1 must be lower than end 0"}"#;
+
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+        test_duration.exit();
+    }
 }
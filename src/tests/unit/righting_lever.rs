@@ -0,0 +1,86 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{rc::Rc, sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{cross_curves::CrossCurves, mass::{FakeMass, IMass}, math::position::Position, righting_lever::RightingLever};
+
+    // z_g = 6, delta_m_h_trans = 1, исправленная аппликата центра масс 7 м
+    fn mass() -> Rc<dyn IMass> {
+        Rc::new(FakeMass::new(2050., vec![0.], Position::new(0., 0., 6.), 0.5, 1.))
+    }
+
+    // пантокарены судна с прямыми бортами, z_m = 8 м, объем 2000 м^3
+    // посередине между кривыми, заданными для двух объемов
+    fn righting_lever() -> RightingLever {
+        let kn = |z_m: f64| (0..=90).map(|i| (i as f64, z_m * (i as f64).to_radians().sin())).collect::<Vec<_>>();
+        let cross_curves = CrossCurves::new(vec![(1000., kn(7.)), (3000., kn(9.))]).unwrap();
+        RightingLever::new(1.025, cross_curves, mass()).unwrap()
+    }
+
+    #[test]
+    fn values() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test RightingLever values";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // $l = (8 - 7) \sin\theta$
+        let result = righting_lever().values(&[0., 30., 90.]);
+        let target = vec![(0., 0.), (30., 0.5), (90., 1.)];
+        assert!(
+            result.iter().zip(target.iter()).all(|(r, t)| r.0 == t.0 && (r.1 - t.1).abs() < 0.000001),
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+
+        // $d = 1 - \cos\theta$
+        let result = righting_lever().dynamic(&[0., 60., 90.]);
+        let target = vec![(0., 0.), (60., 0.5), (90., 1.)];
+        assert!(
+            result.iter().zip(target.iter()).all(|(r, t)| r.0 == t.0 && (r.1 - t.1).abs() < 0.001),
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+
+        let result = RightingLever::new(0., CrossCurves::new(vec![(1000., vec![(0., 0.), (10., 1.)])]).unwrap(), mass()).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn max_vanishing() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test RightingLever max_vanishing";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let result = righting_lever().max();
+        let target = (90., 1.);
+        assert!(
+            (result.0 - target.0).abs() < 0.000001 && (result.1 - target.1).abs() < 0.000001,
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+
+        // $l = \sin\theta$ обращается в 0 при 180 градусах
+        let result = righting_lever().angle_vanishing();
+        let target = None;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // пантокарена после 60 градусов подобрана так, что плечо
+        // $l = KN - 7 \sin\theta$ обращается в 0 при 80 градусах
+        let kn = vec![(0., 0.), (30., 4.), (60., 7.), (80., 7. * 80f64.to_radians().sin()), (90., 6.)];
+        let cross_curves = CrossCurves::new(vec![(1000., kn.clone()), (3000., kn)]).unwrap();
+        let result = RightingLever::new(1.025, cross_curves, mass()).unwrap().angle_vanishing().unwrap();
+        let target = 80.;
+        assert!((result - target).abs() < 0.1, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
@@ -22,7 +22,7 @@ mod tests {
         test_duration.run().unwrap();
 
         let result =
-            ShearForce::new(FakeTotalForce::new(vec![15., -5., -5., -5., -5., -5., -5., -5., 5., 15.])).values();
+            ShearForce::new(FakeTotalForce::new(vec![15., -5., -5., -5., -5., -5., -5., -5., 5., 15.])).values().unwrap();
         let target = Vec::from([
             0.0, 15.0, 10.0, 5.0, 0.0, -5.0, -10.0, -15.0, -20.0, -15.0, 0.0,
         ]);
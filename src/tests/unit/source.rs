@@ -0,0 +1,165 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{fs, sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use rusqlite::Connection;
+    use crate::data::{parse_input::*, source::{file::FileSource, sqlite::{SqliteSource, SCHEMA}, IDataSource}};
+
+    #[test]
+    fn file() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test FileSource";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let root = std::env::temp_dir().join("sss-computing-strength-test-file-source");
+        let path = root.join("project").join("ship");
+        fs::create_dir_all(&path).unwrap();
+        fs::write(path.join("loads.json"), r#"
+        {
+            "load_space": [
+                {
+                    "mass": 10.0,
+                    "bound": [-10.0, 0.0, 0.0, 5.0],
+                    "center": [-5.0, 0.0, 1.0]
+                }
+            ]
+        }"#).unwrap();
+        let mut source = FileSource::new(&root);
+
+        let result = source.loads("project", "ship").unwrap();
+        let target = ParsedLoadsData {
+            load_space: vec![ LoadSpaceData {
+                mass: 10.0,
+                bound: (-10.0, 0.0, 0.0, 5.0),
+                center: (-5.0, 0.0, 1.0),
+                name: None,
+                category: None,
+            }, ],
+            grain: vec![],
+            bulk: vec![],
+        };
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = source.loads("..", "ship").is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = source.tanks("project", "ship").is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        fs::remove_dir_all(&root).unwrap();
+        test_duration.exit();
+    }
+
+    #[test]
+    fn sqlite() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test SqliteSource";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let connection = Connection::open_in_memory().unwrap();
+        connection.execute_batch(SCHEMA).unwrap();
+        connection.execute_batch("
+            INSERT INTO ship VALUES ('p', 's', 200.0);
+            INSERT INTO ship_curve VALUES ('p', 's', 'center_waterline', 10.0, 1.0), ('p', 's', 'center_waterline', 0.0, 0.0);
+            INSERT INTO ship_curve VALUES ('p', 's', 'rad_long', 0.0, 0.0), ('p', 's', 'rad_long', 10.0, 2.0);
+            INSERT INTO ship_curve VALUES ('p', 's', 'mean_draught', 0.0, 0.0), ('p', 's', 'mean_draught', 10.0, 3.0);
+            INSERT INTO center_shift VALUES ('p', 's', 0.0, 2.0, 0.0, 0.0), ('p', 's', 10.0, 2.0, 0.0, 0.0);
+            INSERT INTO frame VALUES ('p', 's', 1, 0.0, 0.0), ('p', 's', 0, 0.0, 0.0), ('p', 's', 0, 5.0, 10.0), ('p', 's', 1, 5.0, 10.0);
+            INSERT INTO load_space VALUES ('p', 's', 1, 10.0, -10.0, 0.0, 0.0, 5.0, -5.0, 0.0, 1.0);
+            INSERT INTO tank VALUES ('p', 's', 1, 0.7, 10.0, 10.0, 20.0, 5.0, 10.0);
+            INSERT INTO tank_center VALUES ('p', 's', 1, 10.0, 15.0, 7.5, 2.0), ('p', 's', 1, 0.0, 15.0, 7.5, -1.0);
+            INSERT INTO tank_free_surf_inertia VALUES ('p', 's', 1, 0.0, 0.0, 0.0), ('p', 's', 1, 10.0, 0.0, 0.0);
+            INSERT INTO tank_fill VALUES ('p', 's', 1, 'sounding', 2.0, 1.0, 0.0);
+            INSERT INTO tank_calibration VALUES ('p', 's', 1, 5.0);
+            INSERT INTO tank_sounding VALUES ('p', 's', 1, 5.0, 10.0), ('p', 's', 1, 0.0, 0.0);
+            INSERT INTO tank_sounding_correction VALUES ('p', 's', 1, 'trim', 2.0, 0.0, 0.2), ('p', 's', 1, 'trim', 0.0, 0.0, 0.0),
+                ('p', 's', 1, 'trim', 0.0, 5.0, 0.0), ('p', 's', 1, 'trim', 2.0, 5.0, 0.2);
+            INSERT INTO ice_area VALUES ('p', 's', 'windage', 1, 40.0, -10.0, 0.0, -5.0, 0.0, 12.0), ('p', 's', 'deck', 1, 200.0, -10.0, 10.0, 0.0, 0.0, 10.0);
+            INSERT INTO tank_free_surface VALUES ('p', 's', 1, 'fixed', 1.5, 0.5);
+            INSERT INTO load_name VALUES ('p', 's', 'load_space', 1, 'hold 1', NULL), ('p', 's', 'tank', 1, 'FO 1', 'fuel');
+        ").unwrap();
+        let mut source = SqliteSource::new(connection);
+
+        let result = source.ship("p", "s").unwrap();
+        let target = ParsedShipData {
+            ship_length: 200.,
+            center_waterline: vec![ (0.0, 0.0), (10.0, 1.0)],
+            rad_long: vec![ (0.0, 0.0), (10.0, 2.0)],
+            mean_draught: vec![ (0.0, 0.0), (10.0, 3.0)],
+            center_shift: vec![(0.0, 2.0, 0.0, 0.0), (10.0, 2.0, 0.0, 0.0),],
+            rad_trans: None,
+            cross_curves: None,
+            flooding_angle: None,
+            strength_limits: None,
+            wave: None,
+            weather: None,
+            lightship: None,
+            stowage: None,
+            ice: Some(IceData {
+                deck: vec![IceAreaData { area: 200.0, bound: (-10.0, 10.0), center: (0.0, 0.0, 10.0) }],
+                windage: vec![IceAreaData { area: 40.0, bound: (-10.0, 0.0), center: (-5.0, 0.0, 12.0) }],
+            }),
+        };
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = source.frames("p", "s").unwrap();
+        let target = ParsedFramesData { frames: vec![
+            FrameData { index: 0, immersion_area: vec![ (0.0, 0.0), (5.0, 10.0),], },
+            FrameData { index: 1, immersion_area: vec![ (0.0, 0.0), (5.0, 10.0),], },
+        ] };
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = source.loads("p", "s").unwrap();
+        let target = ParsedLoadsData {
+            load_space: vec![ LoadSpaceData {
+                mass: 10.0,
+                bound: (-10.0, 0.0, 0.0, 5.0),
+                center: (-5.0, 0.0, 1.0),
+                name: Some("hold 1".to_owned()),
+                category: None,
+            }, ],
+            grain: vec![],
+            bulk: vec![],
+        };
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = source.tanks("p", "s").unwrap();
+        let target = ParsedTanksData {
+            tanks: vec![ TankData {
+                density: 0.7,
+                volume: Some(10.0),
+                fill: Some(FillData { kind: FillKind::Sounding, value: 2.0, trim: 1.0, heel: 0.0 }),
+                calibration: Some(CalibrationData {
+                    height: 5.0,
+                    volume: vec![(0.0, 0.0), (5.0, 10.0)],
+                    trim: Some(vec![(0.0, vec![(0.0, 0.0), (5.0, 0.0)]), (2.0, vec![(0.0, 0.2), (5.0, 0.2)])]),
+                    heel: None,
+                }),
+                bound: (10.0, 20.0, 5.0, 10.0),
+                center: vec![(0.0, 15.0, 7.5, -1.0), (10.0, 15.0, 7.5, 2.0),],
+                free_surf_inertia: vec![(0.0, 0.0, 0.0), (10.0, 0.0, 0.0),],
+                free_surface: Some(FreeSurfaceData { kind: FreeSurfaceKind::Fixed, moment: Some((1.5, 0.5)) }),
+                name: Some("FO 1".to_owned()),
+                category: Some(LoadCategory::Fuel),
+            }, ],
+        };
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = source.ship("p", "unknown").is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{
+        load::ILoad,
+        math::{bound::Bound, position::Position},
+        stowage::{Stack, Stowage},
+    };
+
+    /// Два бея по 6 м, три ряда, два яруса, допустимые массы двух штабелей
+    fn stowage() -> Stowage {
+        Stowage::new(
+            vec![(1, -6., 0.), (3, 0., 6.)],
+            vec![(0, 0.), (1, 2.5), (2, -2.5)],
+            vec![(82, 4.), (84, 6.5)],
+            vec![(1, 0, 40.), (3, 1, 30.)],
+        ).unwrap()
+    }
+
+    #[test]
+    fn loads() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Stowage loads";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let stowage = stowage();
+        let loads = stowage.loads(&[(1, 0, 82, 20.), (3, 2, 84, 10.)]).unwrap();
+        let result: Vec<(Position, f64, f64)> = loads
+            .iter()
+            .map(|l| (l.center(), l.mass(None), l.mass(Some(Bound::new(-3., 3.).unwrap()))))
+            .collect();
+        let target = vec![
+            (Position::new(-3., 0., 4.), 20., 10.),
+            (Position::new(3., -2.5, 6.5), 10., 5.),
+        ];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = stowage.loads(&[(1, 0, 82, 20.), (1, 0, 82, 10.)]).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = stowage.loads(&[(5, 0, 82, 20.)]).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = stowage.loads(&[(1, 0, 86, 20.)]).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn stacks() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Stowage stacks";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let result = stowage().stacks(&[(3, 1, 82, 20.), (1, 0, 82, 20.), (1, 0, 84, 15.), (3, 1, 84, 15.), (1, 2, 82, 25.)]);
+        let target = vec![
+            Stack { bay: 1, row: 0, mass: 35., limit: Some(40.) },
+            Stack { bay: 1, row: 2, mass: 25., limit: None },
+            Stack { bay: 3, row: 1, mass: 35., limit: Some(30.) },
+        ];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result: Vec<bool> = result.iter().map(|s| s.pass()).collect();
+        let target = vec![true, true, false];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
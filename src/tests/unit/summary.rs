@@ -0,0 +1,99 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{rc::Rc, sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{
+        load::{Category, ILoad, LoadSpace, NamedLoad},
+        math::{bound::Bound, position::Position, surface_moment::SurfaceMoment},
+        summary::Summary,
+    };
+
+    /// Груз с наименованием и категорией
+    fn load(name: &str, category: Category, mass: f64, x: f64, z: f64) -> Rc<Box<dyn ILoad>> {
+        Rc::new(Box::new(NamedLoad::new(
+            name.to_owned(),
+            category,
+            Box::new(LoadSpace::new(mass, Bound::new(x - 5., x + 5.).unwrap(), Position::new(x, 0., z)).unwrap()),
+        )))
+    }
+
+    /// Судно порожнем, два груза и балласт
+    fn summary() -> Summary {
+        Summary::new(vec![
+            load("lightship", Category::Lightship, 100., 0., 5.),
+            load("hold 1", Category::Cargo, 20., 10., 2.),
+            load("hold 2", Category::Cargo, 30., -10., 4.),
+            load("ballast", Category::Ballast, 10., 0., 1.),
+        ])
+    }
+
+    #[test]
+    fn named() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test NamedLoad";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let load = load("hold 1", Category::Cargo, 20., 10., 2.);
+        let result = (load.name(), load.category(), load.mass(None), load.mass(Some(Bound::new(5., 10.).unwrap())), load.center());
+        let target = ("hold 1".to_owned(), Category::Cargo, 20., 10., Position::new(10., 0., 2.));
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn categories() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Summary categories";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let result: Vec<(Category, f64, Position, SurfaceMoment)> = summary()
+            .categories()
+            .into_iter()
+            .map(|t| (t.category, t.mass, t.center, t.moment_surface))
+            .collect();
+        let target = vec![
+            (Category::Lightship, 100., Position::new(0., 0., 5.), SurfaceMoment::new(0., 0.)),
+            (Category::Cargo, 50., Position::new(-2., 0., 3.2), SurfaceMoment::new(0., 0.)),
+            (Category::Ballast, 10., Position::new(0., 0., 1.), SurfaceMoment::new(0., 0.)),
+        ];
+        assert!(result.len() == target.len(), "\nresult: {:?}\ntarget: {:?}", result, target);
+        for (result, target) in result.iter().zip(target.iter()) {
+            assert!(
+                result.0 == target.0 && (result.1 - target.1).abs() < 0.000001
+                    && (result.2.x() - target.2.x()).abs() < 0.000001
+                    && (result.2.z() - target.2.z()).abs() < 0.000001
+                    && result.3 == target.3,
+                "\nresult: {:?}\ntarget: {:?}", result, target
+            );
+        }
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn deadweight() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Summary deadweight";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let summary = summary();
+        let result = (summary.deadweight(), summary.displacement());
+        let target = (60., 160.);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
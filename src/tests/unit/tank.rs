@@ -5,7 +5,8 @@ mod tests {
     use std::{sync::Once, time::{Duration, Instant}};
     use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
     use testing::stuff::max_test_duration::TestDuration;
-    use crate::{load::ILoad, math::{bound::Bound, curve::Curve, inertia_shift::inertia_shift::InertiaShift, pos_shift::PosShift, position::Position, surface_moment::SurfaceMoment}, tank::Tank};
+    use std::rc::Rc;
+    use crate::{calibration::Fill, load::{FreeSurface, ILoad}, mass::{IMass, Mass}, math::{bound::Bound, curve::Curve, inertia_shift::inertia_shift::InertiaShift, pos_shift::PosShift, position::Position, surface_moment::SurfaceMoment}, tank::Tank};
     
     static INIT: Once = Once::new();
     
@@ -14,22 +15,22 @@ mod tests {
     fn init_once() {
         INIT.call_once(|| {
                 let tank_center_shift = PosShift::new(
-                    Curve::new(vec![(0., 2.), (10., 2.)]),
-                    Curve::new(vec![(0., 0.), (10., 0.)]),
-                    Curve::new(vec![(0., 0.), (10., 0.)]),
+                    Curve::new(vec![(0., 2.), (10., 2.)]).unwrap(),
+                    Curve::new(vec![(0., 0.), (10., 0.)]).unwrap(),
+                    Curve::new(vec![(0., 0.), (10., 0.)]).unwrap(),
                 );
                 let tank_free_surf_inertia = InertiaShift::new(
-                    Curve::new(vec![(0., 0.), (10., 1.)]),
-                    Curve::new(vec![(0., 0.), (10., 1.)]),
+                    Curve::new(vec![(0., 0.), (10., 1.)]).unwrap(),
+                    Curve::new(vec![(0., 0.), (10., 1.)]).unwrap(),
                 );
         
                 unsafe { TANK = Some(Tank::new(
                     2.,
                     10.,
-                    Bound::new(0., 4.),
+                    Bound::new(0., 4.).unwrap(),
                     tank_center_shift,
                     tank_free_surf_inertia,
-                )); }
+                ).unwrap()); }
             }
         )
     }
@@ -84,4 +85,120 @@ mod tests {
 
         test_duration.exit();
     }
+
+    #[test]
+    fn center_outside_bound() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Tank center_outside_bound";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let result = Tank::new(
+            2.,
+            10.,
+            Bound::new(4., 8.).unwrap(),
+            PosShift::new(
+                Curve::new(vec![(0., 2.), (10., 2.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 0.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 0.)]).unwrap(),
+            ),
+            InertiaShift::new(
+                Curve::new(vec![(0., 0.), (10., 1.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 1.)]).unwrap(),
+            ),
+        ).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+    #[test]
+    fn free_surface() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Tank free_surface";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // поперечный момент инерции наибольший при половинном заполнении
+        let tank = |free_surface| Tank::from_fill(
+            2.,
+            Fill::Volume(10.),
+            None,
+            Bound::new(0., 4.).unwrap(),
+            PosShift::new(
+                Curve::new(vec![(0., 2.), (10., 2.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 0.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 0.)]).unwrap(),
+            ),
+            InertiaShift::new(
+                Curve::new(vec![(0., 0.), (5., 3.), (10., 1.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 1.)]).unwrap(),
+            ),
+            free_surface,
+        );
+        let result: Vec<(f64, f64)> = [
+            FreeSurface::Actual,
+            FreeSurface::Max,
+            FreeSurface::Fixed(SurfaceMoment::new(4., 1.)),
+            FreeSurface::None,
+        ]
+        .into_iter()
+        .map(|free_surface| {
+            let load: Rc<Box<dyn ILoad>> = Rc::new(Box::new(tank(free_surface).unwrap()));
+            let mass = Mass::new(vec![load], vec![Bound::new(0., 4.).unwrap()]).unwrap();
+            (mass.delta_m_h_trans().unwrap(), mass.delta_m_h().unwrap())
+        })
+        .collect();
+        let target = vec![(0.1, 0.1), (0.3, 0.1), (0.2, 0.05), (0., 0.)];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = tank(FreeSurface::Fixed(SurfaceMoment::new(-1., 0.))).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn over_capacity() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Tank over_capacity";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // без калибровочной таблицы вместимость - наибольший объем кривой центра, 10 м^3
+        let tank = |fill| Tank::from_fill(
+            2.,
+            fill,
+            None,
+            Bound::new(0., 4.).unwrap(),
+            PosShift::new(
+                Curve::new(vec![(0., 2.), (10., 2.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 0.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 0.)]).unwrap(),
+            ),
+            InertiaShift::new(
+                Curve::new(vec![(0., 0.), (10., 1.)]).unwrap(),
+                Curve::new(vec![(0., 0.), (10., 1.)]).unwrap(),
+            ),
+            FreeSurface::Actual,
+        );
+        let result = (
+            tank(Fill::Volume(10.)).map(|t| t.volume()).ok(),
+            tank(Fill::Mass(20.)).map(|t| t.volume()).ok(),
+            tank(Fill::Volume(12.)).is_err(),
+            tank(Fill::Mass(22.)).is_err(),
+            tank(Fill::Volume(5.)).map(|t| t.capacity()).ok(),
+        );
+        let target = (Some(10.), Some(10.), true, true, Some(10.));
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
 #[cfg(test)]
 
 mod tests {
-    use crate::{draught::{FakeDraught, IDraught}, mass::FakeMass, math::{position::Position, vec::vec::MultipleSingle}, total_force::{ITotalForce, TotalForce}};
+    use crate::{equilibrium::FakeDraught, mass::FakeMass, math::{position::Position, vec::vec::MultipleSingle}, total_force::{ITotalForce, TotalForce}};
     use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
     use log::{debug, info, warn};
     use std::{
@@ -20,10 +20,10 @@ mod tests {
 
         let gravity_g = 9.81;
         let result = TotalForce::new(
-            Rc::new(FakeMass::new(30., vec![20.; 10], Position::new(0., 0., 0.,), 0.)),
-            FakeDraught::new(vec![5., 25., 25., 25., 25., 25., 25., 25., 15., 5.]),
+            Rc::new(FakeMass::new(30., vec![20.; 10], Position::new(0., 0., 0.,), 0., 0.)),
+            Rc::new(FakeDraught::new(vec![5., 25., 25., 25., 25., 25., 25., 25., 15., 5.])),
             gravity_g,
-        ).values();
+        ).unwrap().values().unwrap();
         let mut target = Vec::from([15., -5., -5., -5., -5., -5., -5., -5., 5., 15.]);
         target.mul_single(gravity_g);
         
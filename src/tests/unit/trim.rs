@@ -31,9 +31,9 @@ mod tests {
             118.39,
             FakePosShift::new(Position::new(-0.194609657, 0., 0.735524704)),
             FakeCurve::new(696.702572991),                  
-            Rc::new(FakeMass::new(2044.10, vec![0.], Position::new(1.05, 0., 5.32,), 0.)), 
-        )
-        .value();
+            Rc::new(FakeMass::new(2044.10, vec![0.], Position::new(1.05, 0., 5.32,), 0., 0.)), 
+        ).unwrap()
+        .value().unwrap();
         let target = 0.2115;
 
         assert!(
@@ -43,6 +43,29 @@ mod tests {
             target
         );
 
+        test_duration.exit();
+    }
+    #[test]
+    fn zero_mass() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Trim zero_mass";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let result = Trim::new(
+            1.025,
+            118.39,
+            FakePosShift::new(Position::new(-0.194609657, 0., 0.735524704)),
+            FakeCurve::new(696.702572991),
+            Rc::new(FakeMass::new(0., vec![0.], Position::new(1.05, 0., 5.32,), 0., 0.)),
+        ).unwrap()
+        .value()
+        .is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
         test_duration.exit();
     }
 }
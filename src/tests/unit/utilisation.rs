@@ -0,0 +1,101 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{computer::Computer, data::parse_input::{Condition, LimitsData, StrengthLimitsData}, math::curve::Curve, utilisation::Utilisation};
+
+    #[test]
+    fn values() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Utilisation values";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let utilisation = Utilisation::new(
+            vec![-10., -5., 0., 5., 10.],
+            vec![0., 50., 100., -30., -120.],
+            Curve::new(vec![(-10., 100.), (10., 200.)]).unwrap(),
+            Curve::new(vec![(-10., 100.), (10., 100.)]).unwrap(),
+        ).unwrap();
+
+        let result = utilisation.values();
+        let target = vec![(-10., 0.), (-5., 40.), (0., 200. / 3.), (5., 30.), (10., 120.)];
+        assert!(result.iter().zip(target.iter()).all(|(r, t)| r.0 == t.0 && (r.1 - t.1).abs() < 0.000001), "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = utilisation.max();
+        let target = (10., 120.);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = Utilisation::new(
+            vec![-10., 10.],
+            vec![0.],
+            Curve::new(vec![(-10., 100.), (10., 200.)]).unwrap(),
+            Curve::new(vec![(-10., 100.), (10., 100.)]).unwrap(),
+        ).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // нулевое допустимое значение дало бы бесконечное использование
+        let result = Utilisation::new(
+            vec![-10., 10.],
+            vec![0., 50.],
+            Curve::new(vec![(-10., 100.), (10., 200.)]).unwrap(),
+            Curve::new(vec![(-10., 100.), (10., 0.)]).unwrap(),
+        ).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn computer() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Utilisation computer";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let limits = |value: f64| LimitsData {
+            sf_positive: vec![(-10., value), (10., value)],
+            sf_negative: vec![(-10., value), (10., value)],
+            bm_hogging: vec![(-10., value), (10., 2. * value)],
+            bm_sagging: vec![(-10., value), (10., value)],
+        };
+        let mut ship = crate::tests::unit::full_calc::ship::ship();
+        ship.strength_limits = Some(StrengthLimitsData { sea: limits(100.), harbor: limits(10.) });
+        let mut input = crate::tests::unit::full_calc::input_data::input_data();
+        input.condition = Condition::Harbor;
+        let computer = Computer::new(
+            9.81,
+            input,
+            ship,
+            crate::tests::unit::full_calc::frames::frames(),
+            crate::tests::unit::full_calc::loads::loads(),
+            crate::tests::unit::full_calc::tanks::tanks(),
+        );
+
+        let result = computer
+            .utilisation(&[-10., 0., 10.], &[0., 5., -20.], &[0., 30., 0.])
+            .unwrap()
+            .unwrap();
+        assert!(result.shear_force == vec![(-10., 0.), (0., 50.), (10., 200.)], "\nresult: {:?}", result);
+        assert!(result.bending_moment == vec![(-10., 0.), (0., 200.), (10., 0.)], "\nresult: {:?}", result);
+        assert!(result.shear_force_max == (10., 200.), "\nresult: {:?}", result);
+        assert!(result.bending_moment_max == (0., 200.), "\nresult: {:?}", result);
+        assert!(result.pass == false, "\nresult: {:?}", result);
+
+        // понтон в равновесии, нагрузки на корпус отсутствуют
+        let result = computer.out_data().unwrap().utilisation.unwrap().pass;
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
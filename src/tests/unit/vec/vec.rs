@@ -84,13 +84,29 @@ mod tests {
         test_duration.run().unwrap();
 
         let mut result = Vec::from([1., 0.,]);
-        result.add_vec(&Vec::from([1., 1.,]));
+        result.add_vec(&Vec::from([1., 1.,])).unwrap();
         let target = Vec::from([2., 1.,]);
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
 
         test_duration.exit();
     }
 
+    #[test]
+    pub fn add_vec_error() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Vec add_vec_error";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let result = Vec::from([1., 0.,]).add_vec(&Vec::from([1.,])).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
     #[test]
     pub fn sub_vec() {
         DebugSession::init(LogLevel::Debug, Backtrace::Short);
@@ -101,7 +117,7 @@ mod tests {
         test_duration.run().unwrap();
 
         let mut target = Vec::from([2., 1.,]);
-        target.sub_vec(&Vec::from([1., 2.,]));
+        target.sub_vec(&Vec::from([1., 2.,])).unwrap();
         let result = Vec::from([1., -1.,]);
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
 
@@ -118,7 +134,7 @@ mod tests {
         test_duration.run().unwrap();
 
         let mut target = Vec::from([2., 1.,]);
-        target.div_vec(&Vec::from([1., 2.,]));
+        target.div_vec(&Vec::from([1., 2.,])).unwrap();
         let result = Vec::from([2., 0.5,]);
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
 
@@ -135,7 +151,7 @@ mod tests {
         test_duration.run().unwrap();
 
         let mut target = Vec::from([2., 1.,]);
-        target.mul_vec(&Vec::from([1., 0.,]));
+        target.mul_vec(&Vec::from([1., 0.,])).unwrap();
         let result = Vec::from([2., 0.,]);
         assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
 
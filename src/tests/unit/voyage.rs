@@ -0,0 +1,96 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{
+        computer::Computer,
+        data::parse_input::{ConsumptionData, ParsedInputData, VoyageData},
+        voyage::Voyage,
+    };
+
+    #[test]
+    fn times() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Voyage times";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // цистерна 50 из 100 м^3, плотность 1, расход 0.5 т/ч,
+        // заполнение 30% достигается через 40 ч
+        let tanks = vec![(50., 1., 100.), (10., 0.8, 20.)];
+        let voyage = Voyage::new(60., 24., vec![30.], vec![(0, 0.5)]).unwrap();
+        let result = voyage.times(&tanks).unwrap();
+        let target = vec![0., 24., 40., 48., 60.];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = voyage.volumes(&tanks, 60.).unwrap();
+        let target = vec![20., 10.];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = Voyage::new(120., 24., vec![], vec![(0, 0.5)]).unwrap().volumes(&tanks, 120.).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = Voyage::new(60., 24., vec![], vec![(2, 0.5)]).unwrap().times(&tanks).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // два потребителя из одной цистерны: уровень 30% по суммарному расходу 0.5 т/ч
+        let voyage = Voyage::new(60., 24., vec![30.], vec![(0, 0.25), (0, 0.25)]).unwrap();
+        let result = (voyage.times(&tanks).unwrap(), voyage.volumes(&tanks, 60.).unwrap());
+        let target = (vec![0., 24., 40., 48., 60.], vec![20., 10.]);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn conditions() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Voyage conditions";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let computer = Computer::new(
+            9.81,
+            ParsedInputData {
+                voyage: Some(VoyageData {
+                    duration: 48.,
+                    step: 24.,
+                    levels: vec![],
+                    consumption: vec![ConsumptionData { tank: 0, rate: 0.5 }],
+                }),
+                ..crate::tests::unit::full_calc::input_data::input_data()
+            },
+            crate::tests::unit::full_calc::ship::ship(),
+            crate::tests::unit::full_calc::frames::frames(),
+            crate::tests::unit::full_calc::loads::loads(),
+            crate::tests::unit::full_calc::tanks::tanks(),
+        );
+        let out = computer.out_data().unwrap();
+        let voyage = out.voyage.clone().unwrap();
+        let result: Vec<(f64, f64)> = voyage.conditions.iter().map(|c| (c.time, c.consumed)).collect();
+        let target = vec![(0., 0.), (24., 12.), (48., 24.)];
+        assert!(
+            result.iter().zip(target.iter()).all(|(r, t)| r.0 == t.0 && (r.1 - t.1).abs() < 1e-9) && result.len() == target.len(),
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+
+        // отход совпадает с основным расчетом, запасы расходуются - осадка уменьшается
+        let result = voyage.conditions[0].data.shear_force == out.shear_force
+            && voyage.conditions[2].data.equilibrium.as_ref().unwrap().draught
+                < voyage.conditions[0].data.equilibrium.as_ref().unwrap().draught
+            && voyage.worst < voyage.conditions.len();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{computer::Computer, data::parse_input::WaveData, wave::WaveLoad};
+
+    fn wave() -> WaveLoad {
+        WaveLoad::new(
+            100.,
+            20.,
+            0.8,
+            WaveLoad::rule_m().unwrap(),
+            WaveLoad::rule_f1(0.8).unwrap(),
+            WaveLoad::rule_f2(0.8).unwrap(),
+        ).unwrap()
+    }
+
+    #[test]
+    fn bending_moment() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test WaveLoad bending_moment";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let wave = wave();
+        let result = wave.coefficient();
+        let target = 7.92157287525381;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let (hogging, sagging) = wave.bending_moment(&[-50., -10., 0., 25., 50.]);
+        let target = vec![0., 240815.81540771585, 240815.81540771585, 240815.81540771585 / 0.35 * 0.25, 0.];
+        assert!(hogging.iter().zip(target.iter()).all(|(r, t)| (r - t).abs() < 0.0001), "\nresult: {:?}\ntarget: {:?}", hogging, target);
+        let target = vec![0., -261411.90488337574, -261411.90488337574, -261411.90488337574 / 0.35 * 0.25, 0.];
+        assert!(sagging.iter().zip(target.iter()).all(|(r, t)| (r - t).abs() < 0.0001), "\nresult: {:?}\ntarget: {:?}", sagging, target);
+
+        let result = WaveLoad::new(
+            20.,
+            20.,
+            0.8,
+            WaveLoad::rule_m().unwrap(),
+            WaveLoad::rule_f1(0.8).unwrap(),
+            WaveLoad::rule_f2(0.8).unwrap(),
+        ).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn shear_force() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test WaveLoad shear_force";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let force = 7129.415587728428;
+        let (positive, negative) = wave().shear_force(&[-50., -25., 0., 25., 50.]);
+        let target = vec![0., force * 0.8475151515151516, force * 0.7, force, 0.];
+        assert!(positive.iter().zip(target.iter()).all(|(r, t)| (r - t).abs() < 0.0001), "\nresult: {:?}\ntarget: {:?}", positive, target);
+        let target = vec![0., -force * 0.92, -force * 0.7, -force * 0.9212121212121213, 0.];
+        assert!(negative.iter().zip(target.iter()).all(|(r, t)| (r - t).abs() < 0.0001), "\nresult: {:?}\ntarget: {:?}", negative, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn computer() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test WaveLoad computer";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        let mut ship = crate::tests::unit::full_calc::ship::ship();
+        ship.ship_length = 100.;
+        ship.wave = Some(WaveData {
+            breadth: 20.,
+            block_coefficient: 0.8,
+            m: Some(vec![(0., 1.), (1., 1.)]),
+            f1: None,
+            f2: None,
+        });
+        let computer = Computer::new(
+            9.81,
+            crate::tests::unit::full_calc::input_data::input_data(),
+            ship,
+            crate::tests::unit::full_calc::frames::frames(),
+            crate::tests::unit::full_calc::loads::loads(),
+            crate::tests::unit::full_calc::tanks::tanks(),
+        );
+
+        let result = computer.wave(&[-50., 0.], &[10., -10.], &[1000., 2000.]).unwrap().unwrap();
+        let target = vec![(-50., 241815.81540771585), (0., 242815.81540771585)];
+        assert!(result.total_bending_moment_max.iter().zip(target.iter()).all(|(r, t)| r.0 == t.0 && (r.1 - t.1).abs() < 0.0001), "\nresult: {:?}\ntarget: {:?}", result.total_bending_moment_max, target);
+        let target = vec![(-50., 10.), (0., -10. + 7129.415587728428 * 0.7)];
+        assert!(result.total_shear_force_max.iter().zip(target.iter()).all(|(r, t)| r.0 == t.0 && (r.1 - t.1).abs() < 0.0001), "\nresult: {:?}\ntarget: {:?}", result.total_shear_force_max, target);
+
+        test_duration.exit();
+    }
+}
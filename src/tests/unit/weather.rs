@@ -0,0 +1,143 @@
+#[cfg(test)]
+
+mod tests {
+    use log::{warn, info, debug};
+    use std::{rc::Rc, sync::Once, time::{Duration, Instant}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use testing::stuff::max_test_duration::TestDuration;
+    use crate::{computer::Computer, cross_curves::CrossCurves, data::parse_input::WeatherData, mass::{FakeMass, IMass}, math::position::Position, righting_lever::RightingLever, weather::{HullForm, Roll, Weather}};
+
+    // пантокарены судна с прямыми бортами, z_m = 8 м,
+    // исправленная аппликата центра масс 7 м, $l = \sin\theta$
+    fn righting_lever() -> RightingLever {
+        let kn = (0..=90).map(|i| (i as f64, 8. * (i as f64).to_radians().sin())).collect::<Vec<_>>();
+        let cross_curves = CrossCurves::new(vec![(1000., kn.clone()), (3000., kn)]).unwrap();
+        let mass: Rc<dyn IMass> = Rc::new(FakeMass::new(2050., vec![0.], Position::new(0., 0., 6.5), 0.5, 0.5));
+        RightingLever::new(1.025, cross_curves, mass).unwrap()
+    }
+
+    #[test]
+    fn roll() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Roll";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // B/d = 2.667: X1 = 0.953, Cb = 0.7: X2 = 1, r = 0.73 + 0.6 * 1 / 6 = 0.83,
+        // C = 0.391, T = 12.52: s = 0.0619, $\theta_1 = 109 X_1 \sqrt{r s} = 23.5$
+        let hull = |bilge_keel_area: f64, hard_chine: bool| HullForm {
+            ship_length: 100.,
+            breadth: 16.,
+            block_coefficient: 0.7,
+            bilge_keel_area,
+            hard_chine,
+        };
+        let roll = |bilge_keel_area: f64, hard_chine: bool| Roll::new(hull(bilge_keel_area, hard_chine), 6., 7., 1.).unwrap();
+        let result = roll(0., false).period();
+        let target = 12.522667;
+        assert!((result - target).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = (roll(0., false).angle().unwrap(), roll(24., false).angle().unwrap(), roll(0., true).angle().unwrap());
+        // k = 0.95 для A_k 100 / (L B) = 1.5, k = 0.7 для острой скулы
+        let target = (24., 22., 16.);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let result = Roll::new(hull(0., false), 6., 7., 0.).is_err();
+        let target = true;
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn areas() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Weather areas";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // $l_{w1} = 504 A Z / (1000 g \Delta) = 0.1$
+        let area = 0.1 * 1000. * 9.81 * 2050. / (504. * 5.);
+        let weather = |flooding_angle: Option<f64>| Weather::new(righting_lever(), area, 5., 2050., 20., flooding_angle).unwrap();
+        let result = (weather(None).lever_1(), weather(None).lever_2());
+        let target = (0.1, 0.15);
+        assert!((result.0 - target.0).abs() < 0.000001 && (result.1 - target.1).abs() < 0.000001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        let angle_0 = 0.1f64.asin().to_degrees();
+        let angle_c = 0.15f64.asin().to_degrees();
+        let result = weather(None).angle_0();
+        let target = angle_0;
+        assert!((result - target).abs() < 0.01, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // $a = l_{w2} (\theta_c - \theta_0 + \theta_1) - (\cos(\theta_0 - \theta_1) - \cos\theta_c)$
+        let result = weather(None).area_a();
+        let target = 0.15 * (angle_c - angle_0 + 20.).to_radians() - ((angle_0 - 20.).to_radians().cos() - angle_c.to_radians().cos());
+        assert!((result - target).abs() < 0.0001, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // $b = \cos\theta_c - \cos\theta_2 - l_{w2} (\theta_2 - \theta_c)$, $\theta_2$ = 50° или угол заливания
+        let area_b = |angle_2: f64| angle_c.to_radians().cos() - angle_2.to_radians().cos() - 0.15 * (angle_2 - angle_c).to_radians();
+        let result = (weather(None).angle_2(), weather(None).area_b(), weather(Some(40.)).angle_2(), weather(Some(40.)).area_b());
+        let target = (50., area_b(50.), 40., area_b(40.));
+        assert!(
+            result.0 == target.0 && (result.1 - target.1).abs() < 0.0001 && result.2 == target.2 && (result.3 - target.3).abs() < 0.0001,
+            "\nresult: {:?}\ntarget: {:?}", result, target
+        );
+
+        test_duration.exit();
+    }
+
+    #[test]
+    fn negative_metacentric_height() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        println!("");
+        let self_id = "test Weather negative metacentric height";
+        println!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+
+        // понтон с центром масс выше метацентра: KN = 2 sin, z_g ~ 4.9 м,
+        // качка не вычисляется, критерий погоды не выполнен
+        let kn = (0..=18).map(|i| (i as f64 * 5., 2. * (i as f64 * 5.).to_radians().sin())).collect::<Vec<_>>();
+        let mut ship = crate::tests::unit::full_calc::ship::ship();
+        ship.cross_curves = Some(vec![(0., kn.clone()), (4000., kn)]);
+        ship.weather = Some(WeatherData {
+            breadth: 10.,
+            block_coefficient: 1.,
+            bilge_keel_area: 0.,
+            hard_chine: false,
+            windage: vec![(0., 100., 5.), (20., 100., 5.)],
+        });
+        let out = Computer::new(
+            9.81,
+            crate::tests::unit::full_calc::input_data::input_data(),
+            ship,
+            crate::tests::unit::full_calc::frames::frames(),
+            crate::tests::unit::full_calc::loads::loads(),
+            crate::tests::unit::full_calc::tanks::tanks(),
+        ).out_data().unwrap();
+        let criteria = out.criteria.unwrap();
+        let weather = criteria.values.iter().filter(|v| v.name.starts_with("weather")).map(|v| (v.name.as_str(), v.pass)).collect::<Vec<_>>();
+        let result = (criteria.pass, weather, out.weather.is_none());
+        let target = (false, vec![("weather_angle_0", false), ("weather_area_b", false)], true);
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        // невычисляемые значения выводятся как null
+        let result = criteria
+            .values
+            .iter()
+            .filter(|v| v.name.starts_with("weather"))
+            .map(|v| serde_json::to_string(v).expect("serialize criterion"))
+            .collect::<Vec<_>>();
+        let target = vec![
+            r#"{"name":"weather_angle_0","actual":null,"required":16.0,"pass":false}"#,
+            r#"{"name":"weather_area_b","actual":null,"required":null,"pass":false}"#,
+        ];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+
+        test_duration.exit();
+    }
+}
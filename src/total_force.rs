@@ -1,7 +1,7 @@
 //! Результирующая нагрузка на шпацию
 use std::rc::Rc;
 
-use crate::{draught::IDraught, mass::IMass, math::vec::vec::{MultipleSingle, SubVec}};
+use crate::{equilibrium::IDraught, error::Error, mass::IMass, math::vec::vec::{MultipleSingle, SubVec}};
 
 /// Результирующей нагрузка на шпацию, вычисляется
 /// суммированием силы выталкивания воды и суммарной  
@@ -10,39 +10,43 @@ pub struct TotalForce {
     /// нагрузка на судно
     mass: Rc<dyn IMass>,
     /// масса вытесненной воды
-    draught: Box<dyn IDraught>,
+    draught: Rc<dyn IDraught>,
     /// ускорение свободного падения
     gravity_g: f64,
 }
 ///
 impl TotalForce {
     ///
-    pub fn new(mass: Rc<dyn IMass>, draught: impl IDraught + 'static, gravity_g: f64) -> Self {
-        assert!(gravity_g > 0., "gravity_g {gravity_g} > 0.");
-        Self {
+    pub fn new(mass: Rc<dyn IMass>, draught: Rc<dyn IDraught>, gravity_g: f64) -> Result<Self, Error> {
+        if gravity_g <= 0. {
+            return Err(Error::TotalForce(format!("gravity_g {gravity_g} must be greater than 0")));
+        }
+        Ok(Self {
             mass,
-            draught: Box::new(draught),
+            draught,
             gravity_g,
-        }
+        })
     }
 }
 ///
 impl ITotalForce for TotalForce {
     ///
-    fn values(&self) -> Vec<f64> {
-        let mut mass_values = self.mass.values();
-        let draught_values = self.draught.values();
-        assert!(mass_values.len() == draught_values.len(), "mass.len() {} == draught.len() {}", mass_values.len(), draught_values.len());
-        mass_values.sub_vec(&draught_values);
+    fn values(&self) -> Result<Vec<f64>, Error> {
+        let mut mass_values = self.mass.values()?;
+        let draught_values = self.draught.values()?;
+        if mass_values.len() != draught_values.len() {
+            return Err(Error::TotalForce(format!("mass.len() {} != draught.len() {}", mass_values.len(), draught_values.len())));
+        }
+        mass_values.sub_vec(&draught_values)?;
         mass_values.mul_single(self.gravity_g);
         log::debug!("\t TotalForce result:{:?}", mass_values);
-        mass_values
+        Ok(mass_values)
     }
 }
 
 #[doc(hidden)]
 pub trait ITotalForce {
-    fn values(&self) -> Vec<f64>;
+    fn values(&self) -> Result<Vec<f64>, Error>;
 }
 // заглушка для тестирования
 #[doc(hidden)]
@@ -57,7 +61,7 @@ impl FakeTotalForce {
 }
 #[doc(hidden)]
 impl ITotalForce for FakeTotalForce {
-    fn values(&self) -> Vec<f64> {
-        self.data.clone()
+    fn values(&self) -> Result<Vec<f64>, Error> {
+        Ok(self.data.clone())
     }
 }
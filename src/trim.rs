@@ -2,6 +2,7 @@
 use std::rc::Rc;
 
 use crate::{
+    error::Error,
     mass::IMass,
     math::{curve::ICurve, pos_shift::IPosShift},
 };
@@ -26,22 +27,37 @@ impl Trim {
         center_draught_shift: impl IPosShift + 'static, // отстояние центра величины погруженной части судна
         rad_long: impl ICurve + 'static,                // продольный метацентрические радиус
         mass: Rc<dyn IMass>,                            // все грузы судна
-    ) -> Self {
-        assert!(water_density > 0., "water_density {water_density} > 0.");
-        assert!(ship_length > 0., "ship_length {ship_length} > 0.");
-        Self {
+    ) -> Result<Self, Error> {
+        if water_density <= 0. {
+            return Err(Error::Trim(format!("water_density {water_density} must be greater than 0")));
+        }
+        if ship_length <= 0. {
+            return Err(Error::Trim(format!("ship_length {ship_length} must be greater than 0")));
+        }
+        Ok(Self {
             water_density,
             ship_length,
             center_draught_shift: Box::new(center_draught_shift),
             rad_long: Box::new(rad_long),
             mass,
-        }
+        })
+    }
+    /// Плотность окружающей воды
+    pub fn water_density(&self) -> f64 {
+        self.water_density
+    }
+    /// Длинна судна
+    pub fn ship_length(&self) -> f64 {
+        self.ship_length
     }
     /// Значение дифферента, коэффициент используемый при вычислении осадки носа и кормы
     #[allow(non_snake_case)]
-    pub fn value(&self) -> f64 {
+    pub fn value(&self) -> Result<f64, Error> {
         // суммарная масса судна и грузов
         let mass_sum = self.mass.sum();
+        if mass_sum <= 0. {
+            return Err(Error::Trim(format!("mass sum {mass_sum} must be greater than 0")));
+        }
         //объемное водоизмещение
         let volume = mass_sum / self.water_density;
         //отстояние центра величины погруженной части судна
@@ -54,12 +70,15 @@ impl Trim {
         //поправки на влияние свободной поверхности
         let H_0 = Z_m - center_draught_shift.z();
         //продольная исправленная метацентрическая высота
-        let H = H_0 - self.mass.delta_m_h();
+        let H = H_0 - self.mass.delta_m_h()?;
         //момент дифферентующий на 1 см осадки
         let trim_moment = (mass_sum * H) / (100. * self.ship_length);
+        if trim_moment == 0. {
+            return Err(Error::Trim(format!("trim moment is 0, mass_sum:{mass_sum} H:{H}")));
+        }
         //дифферент судна
         let value = mass_sum * (self.mass.shift().x() - center_draught_shift.x()) / (100. * trim_moment);
         log::debug!("\t Trim mass:{mass_sum} volume:{volume} center:{center_draught_shift} rad:{rad_long} Z_m:{Z_m} H_0:{H_0} H:{H} M:{trim_moment} result:{value}");
-        value
+        Ok(value)
     }
 }
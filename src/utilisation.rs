@@ -0,0 +1,62 @@
+//! Использование допустимых значений
+use crate::{
+    error::Error,
+    math::curve::{Curve, ICurve},
+};
+
+/// Использование допустимых значений эпюра в процентах:
+/// отношение значения к допустимому значению того же знака
+/// в той же точке по длине судна, $U_i = |V_i| / L(x_i) * 100$
+pub struct Utilisation {
+    /// координаты по х точек эпюра
+    x: Vec<f64>,
+    /// значения эпюра
+    values: Vec<f64>,
+    /// кривая допустимых положительных значений
+    positive: Curve,
+    /// кривая допустимых отрицательных значений, по модулю
+    negative: Curve,
+}
+///
+impl Utilisation {
+    /// Основной конструктор. Аргументы:
+    /// - x: координаты по х точек эпюра
+    /// - values: значения эпюра
+    /// - positive: кривая допустимых положительных значений
+    /// - negative: кривая допустимых отрицательных значений, по модулю
+    ///
+    /// Допустимые значения должны быть больше 0 во всех точках кривых
+    pub fn new(x: Vec<f64>, values: Vec<f64>, positive: Curve, negative: Curve) -> Result<Self, Error> {
+        if x.len() != values.len() {
+            return Err(Error::Utilisation(format!("x.len() {} != values.len() {}", x.len(), values.len())));
+        }
+        if x.is_empty() {
+            return Err(Error::Utilisation("values must not be empty".to_owned()));
+        }
+        let min = positive.min().min(negative.min());
+        if min <= 0. {
+            return Err(Error::Utilisation(format!("limit {min} must be greater than 0")));
+        }
+        Ok(Self { x, values, positive, negative })
+    }
+    /// Использование в процентах (координата по х, значение)
+    pub fn values(&self) -> Vec<(f64, f64)> {
+        let result = self
+            .x
+            .iter()
+            .zip(self.values.iter())
+            .map(|(&x, &v)| {
+                let limit = if v >= 0. { self.positive.value(x) } else { self.negative.value(x) };
+                (x, v.abs() / limit * 100.)
+            })
+            .collect();
+        log::debug!("\t Utilisation result:{:?}", result);
+        result
+    }
+    /// Максимальное использование в процентах (координата по х, значение)
+    pub fn max(&self) -> (f64, f64) {
+        self.values()
+            .into_iter()
+            .fold((self.x[0], 0.), |max, v| if v.1 > max.1 { v } else { max })
+    }
+}
@@ -0,0 +1,94 @@
+//! Расход запасов на переходе судна
+use crate::error::Error;
+
+/// Расход запасов (топлива, масла, пресной воды) из цистерн на переходе.
+/// Промежуточные состояния вычисляются через шаг по времени от отхода
+/// до прихода, а также в моменты, когда заполнение расходной цистерны
+/// достигает заданного уровня в процентах от ее вместимости.
+/// Объем жидкости в цистерне в момент $t$: $V(t) = V_0 - q t / \rho$,
+/// где $q$ - расход, т/ч, $\rho$ - плотность жидкости.
+pub struct Voyage {
+    /// продолжительность перехода, ч
+    duration: f64,
+    /// шаг по времени между промежуточными состояниями, ч
+    step: f64,
+    /// уровни заполнения расходных цистерн, % от вместимости
+    levels: Vec<f64>,
+    /// суммарный расход запасов по цистернам (индекс цистерны, расход, т/ч)
+    consumption: Vec<(usize, f64)>,
+}
+///
+impl Voyage {
+    /// Основной конструктор. Аргументы:
+    /// - duration: продолжительность перехода, ч
+    /// - step: шаг по времени между промежуточными состояниями, ч
+    /// - levels: уровни заполнения расходных цистерн, % от вместимости
+    /// - consumption: расход запасов (индекс цистерны, расход, т/ч),
+    ///   расходы из одной цистерны суммируются
+    pub fn new(duration: f64, step: f64, levels: Vec<f64>, consumption: Vec<(usize, f64)>) -> Result<Self, Error> {
+        if duration <= 0. {
+            return Err(Error::Voyage(format!("duration {duration} must be greater than 0")));
+        }
+        if step <= 0. {
+            return Err(Error::Voyage(format!("step {step} must be greater than 0")));
+        }
+        if let Some(level) = levels.iter().find(|l| !(0. ..=100.).contains(*l)) {
+            return Err(Error::Voyage(format!("level {level} must be in range 0..=100")));
+        }
+        if let Some(rate) = consumption.iter().find(|c| c.1 < 0.) {
+            return Err(Error::Voyage(format!("consumption {} of tank {} must be greater or equal to 0", rate.1, rate.0)));
+        }
+        let mut total: Vec<(usize, f64)> = Vec::new();
+        for (index, rate) in consumption {
+            match total.iter_mut().find(|c| c.0 == index) {
+                Some(c) => c.1 += rate,
+                None => total.push((index, rate)),
+            }
+        }
+        Ok(Self { duration, step, levels, consumption: total })
+    }
+    /// Моменты времени состояний от отхода до прихода, ч
+    /// - tanks: цистерны судна (объем жидкости, плотность, вместимость)
+    pub fn times(&self, tanks: &[(f64, f64, f64)]) -> Result<Vec<f64>, Error> {
+        let steps = (self.duration / self.step).ceil() as usize;
+        let mut result: Vec<f64> = (0..steps)
+            .map(|i| i as f64 * self.step)
+            .chain(std::iter::once(self.duration))
+            .collect();
+        for &(index, rate) in self.consumption.iter().filter(|c| c.1 > 0.) {
+            let (volume, density, capacity) = Self::tank(tanks, index)?;
+            for level in self.levels.iter() {
+                let time = (volume - capacity * level / 100.) * density / rate;
+                if time > 0. && time < self.duration {
+                    result.push(time);
+                }
+            }
+        }
+        result.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        result.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+        log::debug!("\t Voyage times result:{:?}", result);
+        Ok(result)
+    }
+    /// Объемы жидкости в цистернах в момент времени
+    /// - tanks: цистерны судна (объем жидкости, плотность, вместимость)
+    /// - time: время от отхода, ч
+    pub fn volumes(&self, tanks: &[(f64, f64, f64)], time: f64) -> Result<Vec<f64>, Error> {
+        let mut result: Vec<f64> = tanks.iter().map(|t| t.0).collect();
+        for &(index, rate) in self.consumption.iter() {
+            let (_, density, _) = Self::tank(tanks, index)?;
+            result[index] -= rate * time / density;
+            if result[index] < -1e-9 {
+                return Err(Error::Voyage(format!("tank {index} runs dry before {time} h")));
+            }
+            result[index] = result[index].max(0.);
+        }
+        Ok(result)
+    }
+    /// Цистерна по индексу
+    fn tank(tanks: &[(f64, f64, f64)], index: usize) -> Result<(f64, f64, f64), Error> {
+        tanks
+            .get(index)
+            .copied()
+            .ok_or(Error::Voyage(format!("tank {index} not found, number of tanks {}", tanks.len())))
+    }
+}
@@ -0,0 +1,125 @@
+//! Волновые изгибающий момент и срезающая сила
+use crate::{
+    error::Error,
+    math::curve::{Curve, ICurve},
+};
+
+/// Волновые изгибающий момент и срезающая сила по требованиям
+/// IACS UR S11, кН·м и кН. Коэффициенты распределения задаются
+/// кривыми по относительной координате от кормы $x/L$:
+/// - волновой коэффициент $C = 10.75 - ((300 - L)/100)^{1.5}, 90 \le L \le 300$,
+///   $C = 10.75, 300 < L \le 350$, $C = 10.75 - ((L - 350)/150)^{1.5}, 350 < L \le 500$;
+/// - изгибающий момент на перегиб $M_{wh} = 190 M C L^2 B C_b 10^{-3}$;
+/// - изгибающий момент на прогиб $M_{ws} = -110 M C L^2 B (C_b + 0.7) 10^{-3}$;
+/// - положительная срезающая сила $F_{w+} = 30 F_1 C L B (C_b + 0.7) 10^{-2}$;
+/// - отрицательная срезающая сила $F_{w-} = -30 F_2 C L B (C_b + 0.7) 10^{-2}$.
+pub struct WaveLoad {
+    /// длинна корпуса судна
+    ship_length: f64,
+    /// ширина корпуса судна
+    breadth: f64,
+    /// коэффициент общей полноты, не менее 0.6
+    block_coefficient: f64,
+    /// коэффициент распределения изгибающего момента M
+    m: Curve,
+    /// коэффициент распределения положительной срезающей силы F1
+    f1: Curve,
+    /// коэффициент распределения отрицательной срезающей силы F2
+    f2: Curve,
+}
+///
+impl WaveLoad {
+    /// Основной конструктор. Аргументы:
+    /// - ship_length: длинна корпуса судна, от 90 до 500 м
+    /// - breadth: ширина корпуса судна
+    /// - block_coefficient: коэффициент общей полноты
+    /// - m: коэффициент распределения изгибающего момента (x/L, значение)
+    /// - f1: коэффициент распределения положительной срезающей силы (x/L, значение)
+    /// - f2: коэффициент распределения отрицательной срезающей силы (x/L, значение)
+    pub fn new(
+        ship_length: f64,
+        breadth: f64,
+        block_coefficient: f64,
+        m: Curve,
+        f1: Curve,
+        f2: Curve,
+    ) -> Result<Self, Error> {
+        if !(90. ..=500.).contains(&ship_length) {
+            return Err(Error::Wave(format!("ship_length {ship_length} must be in range 90..=500")));
+        }
+        if breadth <= 0. {
+            return Err(Error::Wave(format!("breadth {breadth} must be positive")));
+        }
+        if block_coefficient <= 0. || block_coefficient > 1. {
+            return Err(Error::Wave(format!("block_coefficient {block_coefficient} must be in range 0..=1")));
+        }
+        Ok(Self {
+            ship_length,
+            breadth,
+            block_coefficient: block_coefficient.max(0.6),
+            m,
+            f1,
+            f2,
+        })
+    }
+    /// Коэффициент распределения изгибающего момента M по правилам
+    pub fn rule_m() -> Result<Curve, Error> {
+        Curve::new(vec![(0., 0.), (0.4, 1.), (0.65, 1.), (1., 0.)])
+    }
+    /// Коэффициент распределения положительной срезающей силы F1 по правилам
+    /// - block_coefficient: коэффициент общей полноты
+    pub fn rule_f1(block_coefficient: f64) -> Result<Curve, Error> {
+        let cb = block_coefficient.max(0.6);
+        let f = 0.92 * 190. * cb / (110. * (cb + 0.7));
+        Curve::new(vec![(0., 0.), (0.2, f), (0.3, f), (0.4, 0.7), (0.6, 0.7), (0.7, 1.), (0.85, 1.), (1., 0.)])
+    }
+    /// Коэффициент распределения отрицательной срезающей силы F2 по правилам
+    /// - block_coefficient: коэффициент общей полноты
+    pub fn rule_f2(block_coefficient: f64) -> Result<Curve, Error> {
+        let cb = block_coefficient.max(0.6);
+        let f = 190. * cb / (110. * (cb + 0.7));
+        Curve::new(vec![(0., 0.), (0.2, 0.92), (0.3, 0.92), (0.4, 0.7), (0.6, 0.7), (0.7, f), (0.85, f), (1., 0.)])
+    }
+    /// Волновой коэффициент C
+    pub fn coefficient(&self) -> f64 {
+        let l = self.ship_length;
+        if l <= 300. {
+            10.75 - ((300. - l) / 100.).powf(1.5)
+        } else if l <= 350. {
+            10.75
+        } else {
+            10.75 - ((l - 350.) / 150.).powf(1.5)
+        }
+    }
+    /// Относительная координата от кормы
+    fn relative(&self, x: f64) -> f64 {
+        (x + self.ship_length / 2.) / self.ship_length
+    }
+    /// Волновой изгибающий момент на перегиб и на прогиб в точках по длине судна
+    /// - x: координаты по х точек
+    pub fn bending_moment(&self, x: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        let (l, b, cb, c) = (self.ship_length, self.breadth, self.block_coefficient, self.coefficient());
+        let hogging = 190. * c * l * l * b * cb * 0.001;
+        let sagging = -110. * c * l * l * b * (cb + 0.7) * 0.001;
+        let result = x
+            .iter()
+            .map(|&x| self.m.value(self.relative(x)))
+            .map(|m| (hogging * m, sagging * m))
+            .unzip();
+        log::debug!("\t WaveLoad bending_moment result:{:?}", result);
+        result
+    }
+    /// Волновая срезающая сила положительная и отрицательная в точках по длине судна
+    /// - x: координаты по х точек
+    pub fn shear_force(&self, x: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        let (l, b, cb, c) = (self.ship_length, self.breadth, self.block_coefficient, self.coefficient());
+        let force = 30. * c * l * b * (cb + 0.7) * 0.01;
+        let result = x
+            .iter()
+            .map(|&x| self.relative(x))
+            .map(|x| (force * self.f1.value(x), -force * self.f2.value(x)))
+            .unzip();
+        log::debug!("\t WaveLoad shear_force result:{:?}", result);
+        result
+    }
+}
@@ -0,0 +1,207 @@
+//! Критерий погоды: остойчивость при действии ветра и бортовой качки
+use crate::{
+    error::Error,
+    math::curve::{Curve, ICurve},
+    righting_lever::RightingLever,
+};
+
+/// Элементы корпуса судна для расчета амплитуды бортовой качки
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HullForm {
+    /// длинна судна по ватерлинии
+    pub ship_length: f64,
+    /// ширина судна
+    pub breadth: f64,
+    /// коэффициент общей полноты
+    pub block_coefficient: f64,
+    /// суммарная площадь скуловых килей, 0 если их нет
+    pub bilge_keel_area: f64,
+    /// судно с острой скулой
+    pub hard_chine: bool,
+}
+/// Амплитуда бортовой качки по IS Code 2008, часть A, 2.3.4:
+/// $\theta_1 = 109 k X_1 X_2 \sqrt{r s}$, градусы, где
+/// - $X_1$ - множитель по отношению ширины к осадке $B/d$,
+/// - $X_2$ - множитель по коэффициенту общей полноты $C_b$,
+/// - $k$ - множитель по площади скуловых килей $A_k 100/(L B)$,
+///   1.0 для судна без скуловых килей, 0.7 для судна с острой скулой,
+/// - $r = 0.73 + 0.6 OG/d$, $OG = KG - d$,
+/// - $s$ - множитель по периоду качки $T = 2 C B / \sqrt{GM}$,
+///   $C = 0.373 + 0.023 B/d - 0.043 L/100$.
+pub struct Roll {
+    /// элементы корпуса судна
+    hull: HullForm,
+    /// средняя осадка
+    draught: f64,
+    /// аппликата центра масс
+    z_g: f64,
+    /// исправленная начальная метацентрическая высота
+    metacentric_height: f64,
+}
+///
+impl Roll {
+    /// Основной конструктор. Аргументы:
+    /// - hull: элементы корпуса судна
+    /// - draught: средняя осадка
+    /// - z_g: аппликата центра масс
+    /// - metacentric_height: исправленная начальная метацентрическая высота
+    pub fn new(hull: HullForm, draught: f64, z_g: f64, metacentric_height: f64) -> Result<Self, Error> {
+        let HullForm { ship_length, breadth, block_coefficient, bilge_keel_area, .. } = hull;
+        if ship_length <= 0. || breadth <= 0. || draught <= 0. {
+            return Err(Error::Weather(format!(
+                "ship_length {ship_length}, breadth {breadth} and draught {draught} must be greater than 0"
+            )));
+        }
+        if block_coefficient <= 0. || block_coefficient > 1. {
+            return Err(Error::Weather(format!("block_coefficient {block_coefficient} must be in range 0..=1")));
+        }
+        if bilge_keel_area < 0. {
+            return Err(Error::Weather(format!("bilge_keel_area {bilge_keel_area} must be greater or equal to 0")));
+        }
+        if metacentric_height <= 0. {
+            return Err(Error::Weather(format!("metacentric_height {metacentric_height} must be greater than 0")));
+        }
+        Ok(Self { hull, draught, z_g, metacentric_height })
+    }
+    /// Период бортовой качки, с
+    pub fn period(&self) -> f64 {
+        let c = 0.373 + 0.023 * self.hull.breadth / self.draught - 0.043 * self.hull.ship_length / 100.;
+        2. * c * self.hull.breadth / self.metacentric_height.sqrt()
+    }
+    /// Амплитуда бортовой качки, градусы, округленная до целого
+    pub fn angle(&self) -> Result<f64, Error> {
+        let x_1 = Curve::new(vec![
+            (2.4, 1.), (2.5, 0.98), (2.6, 0.96), (2.7, 0.95), (2.8, 0.93), (2.9, 0.91),
+            (3.0, 0.90), (3.1, 0.88), (3.2, 0.86), (3.4, 0.82), (3.5, 0.80),
+        ])?
+        .value(self.hull.breadth / self.draught);
+        let x_2 = Curve::new(vec![(0.45, 0.75), (0.5, 0.82), (0.55, 0.89), (0.6, 0.95), (0.65, 0.97), (0.7, 1.)])?
+            .value(self.hull.block_coefficient);
+        let k = if self.hull.hard_chine {
+            0.7
+        } else {
+            Curve::new(vec![
+                (0., 1.), (1., 0.98), (1.5, 0.95), (2., 0.88), (2.5, 0.79), (3., 0.74), (3.5, 0.72), (4., 0.70),
+            ])?
+            .value(self.hull.bilge_keel_area * 100. / (self.hull.ship_length * self.hull.breadth))
+        };
+        let r = 0.73 + 0.6 * (self.z_g - self.draught) / self.draught;
+        let s = Curve::new(vec![
+            (6., 0.1), (7., 0.098), (8., 0.093), (12., 0.065), (14., 0.053), (16., 0.044), (18., 0.038), (20., 0.035),
+        ])?
+        .value(self.period());
+        if r * s < 0. {
+            return Err(Error::Weather(format!("r {r} and s {s} must give a non negative product")));
+        }
+        let result = (109. * k * x_1 * x_2 * (r * s).sqrt()).round();
+        log::debug!("\t Roll angle result:{:?}", result);
+        Ok(result)
+    }
+}
+/// Критерий погоды для проверки в [crate::criteria::Criteria]
+pub enum WeatherCriterion {
+    /// критерий погоды вычислен
+    Computed(Weather),
+    /// исправленная начальная метацентрическая высота не положительна:
+    /// амплитуда качки не вычисляется, критерий не выполнен
+    Unstable,
+}
+/// Критерий погоды по IS Code 2008, часть A, 2.3: судно, накренившееся
+/// под действием постоянного ветра с плечом $l_{w1} = P A Z / (1000 g \Delta)$,
+/// $P = 504$ Па, и отклонившееся на амплитуду качки $\theta_1$ на ветер,
+/// должно выдержать порыв ветра с плечом $l_{w2} = 1.5 l_{w1}$:
+/// - угол крена от постоянного ветра $\theta_0$ не более 16°,
+/// - площадь b между диаграммой и $l_{w2}$ от угла $\theta_c$ их пересечения
+///   до $\theta_2$ - наименьшего из 50°, угла заливания и второго пересечения,
+///   не менее площади a между $l_{w2}$ и диаграммой от $\theta_0 - \theta_1$ до $\theta_c$.
+pub struct Weather {
+    /// диаграмма статической остойчивости
+    righting_lever: RightingLever,
+    /// кренящее плечо от постоянного ветра
+    lever_1: f64,
+    /// амплитуда бортовой качки, градусы
+    roll_angle: f64,
+    /// угол заливания, градусы
+    flooding_angle: Option<f64>,
+}
+///
+impl Weather {
+    /// Основной конструктор. Аргументы:
+    /// - righting_lever: диаграмма статической остойчивости
+    /// - windage_area: площадь парусности
+    /// - windage_lever: возвышение центра парусности над центром
+    ///   площади проекции подводной части на ДП
+    /// - displacement: водоизмещение судна, т
+    /// - roll_angle: амплитуда бортовой качки, градусы
+    /// - flooding_angle: угол заливания, градусы, необязательно
+    pub fn new(
+        righting_lever: RightingLever,
+        windage_area: f64,
+        windage_lever: f64,
+        displacement: f64,
+        roll_angle: f64,
+        flooding_angle: Option<f64>,
+    ) -> Result<Self, Error> {
+        if windage_area < 0. {
+            return Err(Error::Weather(format!("windage_area {windage_area} must be greater or equal to 0")));
+        }
+        if displacement <= 0. {
+            return Err(Error::Weather(format!("displacement {displacement} must be greater than 0")));
+        }
+        if roll_angle < 0. {
+            return Err(Error::Weather(format!("roll_angle {roll_angle} must be greater or equal to 0")));
+        }
+        let lever_1 = 504. * windage_area * windage_lever / (1000. * 9.81 * displacement);
+        Ok(Self { righting_lever, lever_1, roll_angle, flooding_angle })
+    }
+    /// Кренящее плечо от постоянного ветра
+    pub fn lever_1(&self) -> f64 {
+        self.lever_1
+    }
+    /// Кренящее плечо от порыва ветра
+    pub fn lever_2(&self) -> f64 {
+        1.5 * self.lever_1
+    }
+    /// Амплитуда бортовой качки, градусы
+    pub fn roll_angle(&self) -> f64 {
+        self.roll_angle
+    }
+    /// Угол крена от постоянного ветра, градусы,
+    /// наибольший угол пантокарен если диаграмма не пересекает $l_{w1}$
+    pub fn angle_0(&self) -> f64 {
+        let angle_max = self.righting_lever.angle_max();
+        self.righting_lever.angle(|_| self.lever_1, 0., angle_max).unwrap_or(angle_max)
+    }
+    /// Угол первого пересечения диаграммы с $l_{w2}$, градусы
+    fn angle_c(&self) -> f64 {
+        let angle_max = self.righting_lever.angle_max();
+        self.righting_lever.angle(|_| self.lever_2(), 0., angle_max).unwrap_or(angle_max)
+    }
+    /// Угол $\theta_2$, ограничивающий площадь b, градусы
+    pub fn angle_2(&self) -> f64 {
+        let angle_max = self.righting_lever.angle_max();
+        let angle_c = self.angle_c();
+        let angle_c2 = self
+            .righting_lever
+            .angle(|_| self.lever_2(), angle_c + 0.1, angle_max)
+            .unwrap_or(angle_max);
+        50f64.min(self.flooding_angle.unwrap_or(50.)).min(angle_c2)
+    }
+    /// Площадь a между $l_{w2}$ и диаграммой от $\theta_0 - \theta_1$ до $\theta_c$, м·рад
+    pub fn area_a(&self) -> f64 {
+        let angle_from = self.angle_0() - self.roll_angle;
+        let angle_to = self.angle_c().min(self.angle_2());
+        if angle_to <= angle_from {
+            return 0.;
+        }
+        self.lever_2() * (angle_to - angle_from).to_radians() - self.righting_lever.area(angle_from, angle_to)
+    }
+    /// Площадь b между диаграммой и $l_{w2}$ от $\theta_c$ до $\theta_2$, м·рад
+    pub fn area_b(&self) -> f64 {
+        let (angle_from, angle_to) = (self.angle_c(), self.angle_2());
+        if angle_to <= angle_from {
+            return 0.;
+        }
+        self.righting_lever.area(angle_from, angle_to) - self.lever_2() * (angle_to - angle_from).to_radians()
+    }
+}